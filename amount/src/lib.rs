@@ -0,0 +1,295 @@
+//! Allocation-free conversions between raw token amounts and their UI
+//! representation.
+//!
+//! These are the conversions performed by the `AmountToUiAmount` and
+//! `UiAmountToAmount` instructions, shared by every implementation of the
+//! Token program and its clients so that they cannot diverge.
+
+#![no_std]
+#![allow(clippy::arithmetic_side_effects)]
+
+use core::str::from_utf8_unchecked;
+
+/// Maximum length of a formatted UI amount.
+///
+/// With 255 decimals, a UI amount has a single integer digit, the decimal
+/// point and 255 fractional digits.
+pub const MAX_UI_AMOUNT_LEN: usize = u8::MAX as usize + 2;
+
+/// Formats a raw amount as its UI representation with exactly `decimals`
+/// fractional digits, using `buffer` as storage.
+///
+/// The result is left-padded with zeros so it always has an integer digit.
+pub fn format_ui_amount(amount: u64, decimals: u8, buffer: &mut [u8; MAX_UI_AMOUNT_LEN]) -> &str {
+    let mut value = amount;
+    let mut start = MAX_UI_AMOUNT_LEN;
+
+    if decimals > 0 {
+        for _ in 0..decimals {
+            start -= 1;
+            buffer[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        start -= 1;
+        buffer[start] = b'.';
+    }
+
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+
+    // SAFETY: `buffer[start..]` only contains ASCII digits and the decimal
+    // point.
+    unsafe { from_utf8_unchecked(&buffer[start..]) }
+}
+
+/// Formats a raw amount as its UI representation, trimming excess zeroes and
+/// unneeded decimal point, using `buffer` as storage.
+pub fn format_ui_amount_trimmed(
+    amount: u64,
+    decimals: u8,
+    buffer: &mut [u8; MAX_UI_AMOUNT_LEN],
+) -> &str {
+    let s = format_ui_amount(amount, decimals, buffer);
+    if decimals > 0 {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        s
+    }
+}
+
+/// Parses the UI representation of a token amount into its raw amount.
+///
+/// Trailing zeros after the decimal point are ignored, and any other
+/// fractional digit beyond `decimals` is rejected. The integer and fractional
+/// digits are otherwise accepted exactly as `u64::from_str` accepts their
+/// concatenation, so a single leading `+` is allowed. Returns `None` if the
+/// input is invalid or the amount does not fit in a `u64`.
+pub fn parse_ui_amount(ui_amount: &str, decimals: u8) -> Option<u64> {
+    let decimals = decimals as usize;
+    let mut parts = ui_amount.split('.');
+    // Splitting a string, even an empty one, will always yield an iterator of
+    // at least length == 1.
+    let integer = parts.next().unwrap_or_default();
+    // Clean up trailing zeros.
+    let fraction = parts.next().unwrap_or_default().trim_end_matches('0');
+
+    if (integer.is_empty() && fraction.is_empty())
+        || parts.next().is_some()
+        || fraction.len() > decimals
+    {
+        return None;
+    }
+
+    let mut digits = integer.bytes().chain(fraction.bytes()).peekable();
+    let mut length = integer.len() + decimals;
+    if digits.next_if_eq(&b'+').is_some() {
+        length -= 1;
+    }
+    if length == 0 {
+        return None;
+    }
+
+    let mut amount = 0u64;
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        amount = amount.checked_mul(10)?.checked_add((digit - b'0') as u64)?;
+    }
+    for _ in fraction.len()..decimals {
+        amount = amount.checked_mul(10)?;
+    }
+
+    Some(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::*,
+        std::{format, string::String, vec::Vec},
+    };
+
+    /// Reference conversion, as originally implemented with allocations by
+    /// the Token program.
+    fn reference_format(amount: u64, decimals: u8) -> String {
+        let decimals = decimals as usize;
+        if decimals > 0 {
+            let mut s = format!("{:01$}", amount, decimals + 1);
+            s.insert(s.len() - decimals, '.');
+            s
+        } else {
+            format!("{amount}")
+        }
+    }
+
+    fn reference_format_trimmed(amount: u64, decimals: u8) -> String {
+        let mut s = reference_format(amount, decimals);
+        if decimals > 0 {
+            s = String::from(s.trim_end_matches('0').trim_end_matches('.'));
+        }
+        s
+    }
+
+    fn reference_parse(ui_amount: &str, decimals: u8) -> Option<u64> {
+        let decimals = decimals as usize;
+        let mut parts = ui_amount.split('.');
+        let mut amount_str = String::from(parts.next().unwrap());
+        let after_decimal = parts.next().unwrap_or("");
+        let after_decimal = after_decimal.trim_end_matches('0');
+        if (amount_str.is_empty() && after_decimal.is_empty())
+            || parts.next().is_some()
+            || after_decimal.len() > decimals
+        {
+            return None;
+        }
+
+        amount_str.push_str(after_decimal);
+        for _ in 0..decimals.saturating_sub(after_decimal.len()) {
+            amount_str.push('0');
+        }
+        amount_str.parse::<u64>().ok()
+    }
+
+    fn boundary_amounts() -> Vec<u64> {
+        let mut amounts = Vec::from([0, 1, 2, 5, u64::MAX - 1, u64::MAX]);
+        let mut power = 1u64;
+        while let Some(next) = power.checked_mul(10) {
+            power = next;
+            amounts.extend([power - 1, power, power + 1, power / 10 * 7]);
+        }
+        amounts
+    }
+
+    fn tricky_inputs() -> Vec<String> {
+        let mut inputs: Vec<String> = [
+            "",
+            ".",
+            "..",
+            "0",
+            "0.",
+            ".0",
+            "00",
+            "+",
+            "+.",
+            ".+",
+            "+0",
+            "+.5",
+            ".+5",
+            "++1",
+            "-",
+            "-1",
+            "-0",
+            "1.-0",
+            " 1",
+            "1 ",
+            "1_000",
+            "1e3",
+            "0x10",
+            "1.2.3",
+            "1..",
+            "١",
+            "1.٠",
+            "a",
+            "1.a0",
+            "18446744073709551615",
+            "18446744073709551616",
+            "18446744073709551615.0",
+            "1.8446744073709551615",
+            "1.8446744073709551616",
+            "0.18446744073709551615",
+            "+18446744073709551615",
+            "99999999999999999999",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        inputs.push(format!("{}1", "0".repeat(300)));
+        inputs.push(format!("0.{}1", "0".repeat(254)));
+        inputs.push(format!("0.{}1", "0".repeat(255)));
+        inputs.push(format!("1.{}", "0".repeat(400)));
+        inputs.push(format!("+{}.1", "0".repeat(300)));
+        inputs
+    }
+
+    #[test]
+    fn test_format_matches_reference() {
+        let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+        for decimals in 0..=u8::MAX {
+            for amount in boundary_amounts() {
+                assert_eq!(
+                    format_ui_amount(amount, decimals, &mut buffer),
+                    reference_format(amount, decimals),
+                );
+                assert_eq!(
+                    format_ui_amount_trimmed(amount, decimals, &mut buffer),
+                    reference_format_trimmed(amount, decimals),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_matches_reference() {
+        let inputs = tricky_inputs();
+        for decimals in 0..=u8::MAX {
+            for input in &inputs {
+                assert_eq!(
+                    parse_ui_amount(input, decimals),
+                    reference_parse(input, decimals),
+                    "{input:?} with {decimals} decimals",
+                );
+            }
+
+            for amount in boundary_amounts() {
+                for input in [
+                    reference_format(amount, decimals),
+                    reference_format_trimmed(amount, decimals),
+                    format!("{}0", reference_format(amount, decimals)),
+                    format!("{}1", reference_format(amount, decimals)),
+                    format!("0{}", reference_format(amount, decimals)),
+                    format!("{}9", reference_format_trimmed(amount, decimals)),
+                ] {
+                    assert_eq!(
+                        parse_ui_amount(&input, decimals),
+                        reference_parse(&input, decimals),
+                        "{input:?} with {decimals} decimals",
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+        for decimals in 0..=u8::MAX {
+            for amount in boundary_amounts() {
+                let ui_amount = format_ui_amount(amount, decimals, &mut buffer);
+                assert_eq!(parse_ui_amount(ui_amount, decimals), Some(amount));
+                let ui_amount = format_ui_amount_trimmed(amount, decimals, &mut buffer);
+                assert_eq!(parse_ui_amount(ui_amount, decimals), Some(amount));
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_ui_amount_len() {
+        let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+        assert_eq!(
+            format_ui_amount(u64::MAX, u8::MAX, &mut buffer).len(),
+            MAX_UI_AMOUNT_LEN
+        );
+        assert_eq!(format_ui_amount(u64::MAX, 0, &mut buffer).len(), 20);
+        assert_eq!(format_ui_amount(u64::MAX, 19, &mut buffer).len(), 21);
+        assert_eq!(format_ui_amount(u64::MAX, 20, &mut buffer).len(), 22);
+    }
+}
@@ -6,6 +6,8 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './clawbackConfig';
 export * from './mint';
 export * from './multisig';
+export * from './permitNonce';
 export * from './token';
@@ -54,17 +54,28 @@ export const TOKEN_ERROR__ACCOUNT_FROZEN = 0x11; // 17
 export const TOKEN_ERROR__MINT_DECIMALS_MISMATCH = 0x12; // 18
 /** NonNativeNotSupported: Instruction does not support non-native tokens */
 export const TOKEN_ERROR__NON_NATIVE_NOT_SUPPORTED = 0x13; // 19
+/** DelegationMismatch: Current delegation does not match the expected one */
+export const TOKEN_ERROR__DELEGATION_MISMATCH = 0x14; // 20
+/** PermitExpired: Permit has expired */
+export const TOKEN_ERROR__PERMIT_EXPIRED = 0x15; // 21
+/** InvalidPermit: Invalid permit */
+export const TOKEN_ERROR__INVALID_PERMIT = 0x16; // 22
+/** ExtensionNotSupported: Extension not supported by this program */
+export const TOKEN_ERROR__EXTENSION_NOT_SUPPORTED = 0x17; // 23
 
 export type TokenError =
     | typeof TOKEN_ERROR__ACCOUNT_FROZEN
     | typeof TOKEN_ERROR__ALREADY_IN_USE
     | typeof TOKEN_ERROR__AUTHORITY_TYPE_NOT_SUPPORTED
+    | typeof TOKEN_ERROR__DELEGATION_MISMATCH
+    | typeof TOKEN_ERROR__EXTENSION_NOT_SUPPORTED
     | typeof TOKEN_ERROR__FIXED_SUPPLY
     | typeof TOKEN_ERROR__INSUFFICIENT_FUNDS
     | typeof TOKEN_ERROR__INVALID_INSTRUCTION
     | typeof TOKEN_ERROR__INVALID_MINT
     | typeof TOKEN_ERROR__INVALID_NUMBER_OF_PROVIDED_SIGNERS
     | typeof TOKEN_ERROR__INVALID_NUMBER_OF_REQUIRED_SIGNERS
+    | typeof TOKEN_ERROR__INVALID_PERMIT
     | typeof TOKEN_ERROR__INVALID_STATE
     | typeof TOKEN_ERROR__MINT_CANNOT_FREEZE
     | typeof TOKEN_ERROR__MINT_DECIMALS_MISMATCH
@@ -75,6 +86,7 @@ export type TokenError =
     | typeof TOKEN_ERROR__NOT_RENT_EXEMPT
     | typeof TOKEN_ERROR__OVERFLOW
     | typeof TOKEN_ERROR__OWNER_MISMATCH
+    | typeof TOKEN_ERROR__PERMIT_EXPIRED
     | typeof TOKEN_ERROR__UNINITIALIZED_STATE;
 
 let tokenErrorMessages: Record<TokenError, string> | undefined;
@@ -83,12 +95,15 @@ if (process.env['NODE_ENV'] !== 'production') {
         [TOKEN_ERROR__ACCOUNT_FROZEN]: `Account is frozen`,
         [TOKEN_ERROR__ALREADY_IN_USE]: `Already in use`,
         [TOKEN_ERROR__AUTHORITY_TYPE_NOT_SUPPORTED]: `Account does not support specified authority type`,
+        [TOKEN_ERROR__DELEGATION_MISMATCH]: `Current delegation does not match the expected one`,
+        [TOKEN_ERROR__EXTENSION_NOT_SUPPORTED]: `Extension not supported by this program`,
         [TOKEN_ERROR__FIXED_SUPPLY]: `Fixed supply`,
         [TOKEN_ERROR__INSUFFICIENT_FUNDS]: `Insufficient funds`,
         [TOKEN_ERROR__INVALID_INSTRUCTION]: `Invalid instruction`,
         [TOKEN_ERROR__INVALID_MINT]: `Invalid Mint`,
         [TOKEN_ERROR__INVALID_NUMBER_OF_PROVIDED_SIGNERS]: `Invalid number of provided signers`,
         [TOKEN_ERROR__INVALID_NUMBER_OF_REQUIRED_SIGNERS]: `Invalid number of required signers`,
+        [TOKEN_ERROR__INVALID_PERMIT]: `Invalid permit`,
         [TOKEN_ERROR__INVALID_STATE]: `State is invalid for requested operation`,
         [TOKEN_ERROR__MINT_CANNOT_FREEZE]: `This token mint cannot freeze accounts`,
         [TOKEN_ERROR__MINT_DECIMALS_MISMATCH]: `The provided decimals value different from the Mint decimals`,
@@ -99,6 +114,7 @@ if (process.env['NODE_ENV'] !== 'production') {
         [TOKEN_ERROR__NOT_RENT_EXEMPT]: `Lamport balance below rent-exempt threshold`,
         [TOKEN_ERROR__OVERFLOW]: `Operation overflowed`,
         [TOKEN_ERROR__OWNER_MISMATCH]: `Owner does not match`,
+        [TOKEN_ERROR__PERMIT_EXPIRED]: `Permit has expired`,
         [TOKEN_ERROR__UNINITIALIZED_STATE]: `State is unititialized`,
     };
 }
@@ -9,31 +9,50 @@
 export * from './amountToUiAmount';
 export * from './approve';
 export * from './approveChecked';
+export * from './approveIfCurrent';
+export * from './approveWithPermit';
 export * from './batch';
 export * from './burn';
+export * from './burnAll';
 export * from './burnChecked';
+export * from './clawbackBurn';
+export * from './clawbackTransfer';
 export * from './closeAccount';
+export * from './createAndInitializeAccount';
+export * from './createAndInitializeMint';
 export * from './createAssociatedToken';
 export * from './createAssociatedTokenIdempotent';
+export * from './decreaseAllowance';
 export * from './freezeAccount';
 export * from './getAccountDataSize';
+export * from './getAccountDataSizeForKind';
+export * from './getMinimumBalance';
+export * from './increaseAllowance';
 export * from './initializeAccount';
 export * from './initializeAccount2';
 export * from './initializeAccount3';
+export * from './initializeAccountIdempotent';
+export * from './initializeClawbackConfig';
 export * from './initializeImmutableOwner';
 export * from './initializeMint';
 export * from './initializeMint2';
 export * from './initializeMultisig';
 export * from './initializeMultisig2';
+export * from './initializePermitNonce';
 export * from './mintTo';
 export * from './mintToChecked';
 export * from './recoverNestedAssociatedToken';
 export * from './revoke';
 export * from './setAuthority';
+export * from './sweepAndClose';
+export * from './sweepExcessLamports';
 export * from './syncNative';
 export * from './thawAccount';
 export * from './transfer';
+export * from './transferAll';
+export * from './transferAllChecked';
 export * from './transferChecked';
 export * from './uiAmountToAmount';
 export * from './unwrapLamports';
 export * from './withdrawExcessLamports';
+export * from './withdrawExcessNativeLamports';
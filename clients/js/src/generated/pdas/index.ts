@@ -7,3 +7,4 @@
  */
 
 export * from './associatedToken';
+export * from './clawbackConfig';
@@ -33,130 +33,213 @@ import {
     type SelfPlanAndSendFunctions,
 } from '@solana/kit/program-client-core';
 import {
+    getClawbackConfigCodec,
     getMintCodec,
     getMultisigCodec,
+    getPermitNonceCodec,
     getTokenCodec,
+    type ClawbackConfig,
+    type ClawbackConfigArgs,
     type Mint,
     type MintArgs,
     type Multisig,
     type MultisigArgs,
+    type PermitNonce,
+    type PermitNonceArgs,
     type Token,
     type TokenArgs,
 } from '../accounts';
 import {
     getAmountToUiAmountInstruction,
     getApproveCheckedInstruction,
+    getApproveIfCurrentInstruction,
     getApproveInstruction,
+    getApproveWithPermitInstruction,
     getBatchInstruction,
+    getBurnAllInstruction,
     getBurnCheckedInstruction,
     getBurnInstruction,
+    getClawbackBurnInstruction,
+    getClawbackTransferInstruction,
     getCloseAccountInstruction,
+    getCreateAndInitializeAccountInstruction,
+    getCreateAndInitializeMintInstruction,
+    getDecreaseAllowanceInstruction,
     getFreezeAccountInstruction,
+    getGetAccountDataSizeForKindInstruction,
     getGetAccountDataSizeInstruction,
+    getGetMinimumBalanceInstruction,
+    getIncreaseAllowanceInstruction,
     getInitializeAccount2Instruction,
     getInitializeAccount3Instruction,
+    getInitializeAccountIdempotentInstruction,
     getInitializeAccountInstruction,
+    getInitializeClawbackConfigInstructionAsync,
     getInitializeImmutableOwnerInstruction,
     getInitializeMint2Instruction,
     getInitializeMintInstruction,
     getInitializeMultisig2Instruction,
     getInitializeMultisigInstruction,
+    getInitializePermitNonceInstruction,
     getMintToCheckedInstruction,
     getMintToInstruction,
     getRevokeInstruction,
     getSetAuthorityInstruction,
+    getSweepAndCloseInstruction,
+    getSweepExcessLamportsInstruction,
     getSyncNativeInstruction,
     getThawAccountInstruction,
+    getTransferAllCheckedInstruction,
+    getTransferAllInstruction,
     getTransferCheckedInstruction,
     getTransferInstruction,
     getUiAmountToAmountInstruction,
     getUnwrapLamportsInstruction,
     getWithdrawExcessLamportsInstruction,
+    getWithdrawExcessNativeLamportsInstruction,
     parseAmountToUiAmountInstruction,
     parseApproveCheckedInstruction,
+    parseApproveIfCurrentInstruction,
     parseApproveInstruction,
+    parseApproveWithPermitInstruction,
     parseBatchInstruction,
+    parseBurnAllInstruction,
     parseBurnCheckedInstruction,
     parseBurnInstruction,
+    parseClawbackBurnInstruction,
+    parseClawbackTransferInstruction,
     parseCloseAccountInstruction,
+    parseCreateAndInitializeAccountInstruction,
+    parseCreateAndInitializeMintInstruction,
+    parseDecreaseAllowanceInstruction,
     parseFreezeAccountInstruction,
+    parseGetAccountDataSizeForKindInstruction,
     parseGetAccountDataSizeInstruction,
+    parseGetMinimumBalanceInstruction,
+    parseIncreaseAllowanceInstruction,
     parseInitializeAccount2Instruction,
     parseInitializeAccount3Instruction,
+    parseInitializeAccountIdempotentInstruction,
     parseInitializeAccountInstruction,
+    parseInitializeClawbackConfigInstruction,
     parseInitializeImmutableOwnerInstruction,
     parseInitializeMint2Instruction,
     parseInitializeMintInstruction,
     parseInitializeMultisig2Instruction,
     parseInitializeMultisigInstruction,
+    parseInitializePermitNonceInstruction,
     parseMintToCheckedInstruction,
     parseMintToInstruction,
     parseRevokeInstruction,
     parseSetAuthorityInstruction,
+    parseSweepAndCloseInstruction,
+    parseSweepExcessLamportsInstruction,
     parseSyncNativeInstruction,
     parseThawAccountInstruction,
+    parseTransferAllCheckedInstruction,
+    parseTransferAllInstruction,
     parseTransferCheckedInstruction,
     parseTransferInstruction,
     parseUiAmountToAmountInstruction,
     parseUnwrapLamportsInstruction,
     parseWithdrawExcessLamportsInstruction,
+    parseWithdrawExcessNativeLamportsInstruction,
     type AmountToUiAmountInput,
     type ApproveCheckedInput,
+    type ApproveIfCurrentInput,
     type ApproveInput,
+    type ApproveWithPermitInput,
     type BatchInput,
+    type BurnAllInput,
     type BurnCheckedInput,
     type BurnInput,
+    type ClawbackBurnInput,
+    type ClawbackTransferInput,
     type CloseAccountInput,
+    type CreateAndInitializeAccountInput,
+    type CreateAndInitializeMintInput,
+    type DecreaseAllowanceInput,
     type FreezeAccountInput,
+    type GetAccountDataSizeForKindInput,
     type GetAccountDataSizeInput,
+    type GetMinimumBalanceInput,
+    type IncreaseAllowanceInput,
     type InitializeAccount2Input,
     type InitializeAccount3Input,
+    type InitializeAccountIdempotentInput,
     type InitializeAccountInput,
+    type InitializeClawbackConfigAsyncInput,
     type InitializeImmutableOwnerInput,
     type InitializeMint2Input,
     type InitializeMintInput,
     type InitializeMultisig2Input,
     type InitializeMultisigInput,
+    type InitializePermitNonceInput,
     type MintToCheckedInput,
     type MintToInput,
     type ParsedAmountToUiAmountInstruction,
     type ParsedApproveCheckedInstruction,
+    type ParsedApproveIfCurrentInstruction,
     type ParsedApproveInstruction,
+    type ParsedApproveWithPermitInstruction,
     type ParsedBatchInstruction,
+    type ParsedBurnAllInstruction,
     type ParsedBurnCheckedInstruction,
     type ParsedBurnInstruction,
+    type ParsedClawbackBurnInstruction,
+    type ParsedClawbackTransferInstruction,
     type ParsedCloseAccountInstruction,
+    type ParsedCreateAndInitializeAccountInstruction,
+    type ParsedCreateAndInitializeMintInstruction,
+    type ParsedDecreaseAllowanceInstruction,
     type ParsedFreezeAccountInstruction,
+    type ParsedGetAccountDataSizeForKindInstruction,
     type ParsedGetAccountDataSizeInstruction,
+    type ParsedGetMinimumBalanceInstruction,
+    type ParsedIncreaseAllowanceInstruction,
     type ParsedInitializeAccount2Instruction,
     type ParsedInitializeAccount3Instruction,
+    type ParsedInitializeAccountIdempotentInstruction,
     type ParsedInitializeAccountInstruction,
+    type ParsedInitializeClawbackConfigInstruction,
     type ParsedInitializeImmutableOwnerInstruction,
     type ParsedInitializeMint2Instruction,
     type ParsedInitializeMintInstruction,
     type ParsedInitializeMultisig2Instruction,
     type ParsedInitializeMultisigInstruction,
+    type ParsedInitializePermitNonceInstruction,
     type ParsedMintToCheckedInstruction,
     type ParsedMintToInstruction,
     type ParsedRevokeInstruction,
     type ParsedSetAuthorityInstruction,
+    type ParsedSweepAndCloseInstruction,
+    type ParsedSweepExcessLamportsInstruction,
     type ParsedSyncNativeInstruction,
     type ParsedThawAccountInstruction,
+    type ParsedTransferAllCheckedInstruction,
+    type ParsedTransferAllInstruction,
     type ParsedTransferCheckedInstruction,
     type ParsedTransferInstruction,
     type ParsedUiAmountToAmountInstruction,
     type ParsedUnwrapLamportsInstruction,
     type ParsedWithdrawExcessLamportsInstruction,
+    type ParsedWithdrawExcessNativeLamportsInstruction,
     type RevokeInput,
     type SetAuthorityInput,
+    type SweepAndCloseInput,
+    type SweepExcessLamportsInput,
     type SyncNativeInput,
     type ThawAccountInput,
+    type TransferAllCheckedInput,
+    type TransferAllInput,
     type TransferCheckedInput,
     type TransferInput,
     type UiAmountToAmountInput,
     type UnwrapLamportsInput,
     type WithdrawExcessLamportsInput,
+    type WithdrawExcessNativeLamportsInput,
 } from '../instructions';
+import { findClawbackConfigPda } from '../pdas';
 
 export const TOKEN_PROGRAM_ADDRESS =
     'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -165,6 +248,8 @@ export enum TokenAccount {
     Mint,
     Token,
     Multisig,
+    PermitNonce,
+    ClawbackConfig,
 }
 
 export function identifyTokenAccount(account: { data: ReadonlyUint8Array } | ReadonlyUint8Array): TokenAccount {
@@ -178,6 +263,12 @@ export function identifyTokenAccount(account: { data: ReadonlyUint8Array } | Rea
     if (data.length === 355) {
         return TokenAccount.Multisig;
     }
+    if (data.length === 41) {
+        return TokenAccount.PermitNonce;
+    }
+    if (data.length === 66) {
+        return TokenAccount.ClawbackConfig;
+    }
     throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__FAILED_TO_IDENTIFY_ACCOUNT, {
         accountData: data,
         programName: 'token',
@@ -212,6 +303,25 @@ export enum TokenInstruction {
     UiAmountToAmount,
     WithdrawExcessLamports,
     UnwrapLamports,
+    SweepAndClose,
+    BurnAll,
+    TransferAll,
+    TransferAllChecked,
+    ApproveIfCurrent,
+    IncreaseAllowance,
+    DecreaseAllowance,
+    InitializePermitNonce,
+    ApproveWithPermit,
+    GetMinimumBalance,
+    CreateAndInitializeMint,
+    CreateAndInitializeAccount,
+    InitializeAccountIdempotent,
+    SweepExcessLamports,
+    InitializeClawbackConfig,
+    ClawbackTransfer,
+    ClawbackBurn,
+    GetAccountDataSizeForKind,
+    WithdrawExcessNativeLamports,
     Batch,
 }
 
@@ -300,6 +410,63 @@ export function identifyTokenInstruction(
     if (containsBytes(data, getU8Encoder().encode(45), 0)) {
         return TokenInstruction.UnwrapLamports;
     }
+    if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+        return TokenInstruction.SweepAndClose;
+    }
+    if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+        return TokenInstruction.BurnAll;
+    }
+    if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+        return TokenInstruction.TransferAll;
+    }
+    if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+        return TokenInstruction.TransferAllChecked;
+    }
+    if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+        return TokenInstruction.ApproveIfCurrent;
+    }
+    if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+        return TokenInstruction.IncreaseAllowance;
+    }
+    if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+        return TokenInstruction.DecreaseAllowance;
+    }
+    if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+        return TokenInstruction.InitializePermitNonce;
+    }
+    if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+        return TokenInstruction.ApproveWithPermit;
+    }
+    if (containsBytes(data, getU8Encoder().encode(55), 0)) {
+        return TokenInstruction.GetMinimumBalance;
+    }
+    if (containsBytes(data, getU8Encoder().encode(56), 0)) {
+        return TokenInstruction.CreateAndInitializeMint;
+    }
+    if (containsBytes(data, getU8Encoder().encode(57), 0)) {
+        return TokenInstruction.CreateAndInitializeAccount;
+    }
+    if (containsBytes(data, getU8Encoder().encode(58), 0)) {
+        return TokenInstruction.InitializeAccountIdempotent;
+    }
+    if (containsBytes(data, getU8Encoder().encode(59), 0)) {
+        return TokenInstruction.SweepExcessLamports;
+    }
+    if (containsBytes(data, getU8Encoder().encode(60), 0)) {
+        return TokenInstruction.InitializeClawbackConfig;
+    }
+    if (containsBytes(data, getU8Encoder().encode(61), 0)) {
+        return TokenInstruction.ClawbackTransfer;
+    }
+    if (containsBytes(data, getU8Encoder().encode(62), 0)) {
+        return TokenInstruction.ClawbackBurn;
+    }
+    if (containsBytes(data, getU8Encoder().encode(63), 0)) {
+        return TokenInstruction.GetAccountDataSizeForKind;
+    }
+    if (containsBytes(data, getU8Encoder().encode(64), 0)) {
+        return TokenInstruction.WithdrawExcessNativeLamports;
+    }
     if (containsBytes(data, getU8Encoder().encode(255), 0)) {
         return TokenInstruction.Batch;
     }
@@ -339,6 +506,37 @@ export type ParsedTokenInstruction<TProgram extends string = 'TokenkegQfeZyiNwAJ
     | ({ instructionType: TokenInstruction.UiAmountToAmount } & ParsedUiAmountToAmountInstruction<TProgram>)
     | ({ instructionType: TokenInstruction.WithdrawExcessLamports } & ParsedWithdrawExcessLamportsInstruction<TProgram>)
     | ({ instructionType: TokenInstruction.UnwrapLamports } & ParsedUnwrapLamportsInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.SweepAndClose } & ParsedSweepAndCloseInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.BurnAll } & ParsedBurnAllInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.TransferAll } & ParsedTransferAllInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.TransferAllChecked } & ParsedTransferAllCheckedInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.ApproveIfCurrent } & ParsedApproveIfCurrentInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.IncreaseAllowance } & ParsedIncreaseAllowanceInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.DecreaseAllowance } & ParsedDecreaseAllowanceInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.InitializePermitNonce } & ParsedInitializePermitNonceInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.ApproveWithPermit } & ParsedApproveWithPermitInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.GetMinimumBalance } & ParsedGetMinimumBalanceInstruction<TProgram>)
+    | ({
+          instructionType: TokenInstruction.CreateAndInitializeMint;
+      } & ParsedCreateAndInitializeMintInstruction<TProgram>)
+    | ({
+          instructionType: TokenInstruction.CreateAndInitializeAccount;
+      } & ParsedCreateAndInitializeAccountInstruction<TProgram>)
+    | ({
+          instructionType: TokenInstruction.InitializeAccountIdempotent;
+      } & ParsedInitializeAccountIdempotentInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.SweepExcessLamports } & ParsedSweepExcessLamportsInstruction<TProgram>)
+    | ({
+          instructionType: TokenInstruction.InitializeClawbackConfig;
+      } & ParsedInitializeClawbackConfigInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.ClawbackTransfer } & ParsedClawbackTransferInstruction<TProgram>)
+    | ({ instructionType: TokenInstruction.ClawbackBurn } & ParsedClawbackBurnInstruction<TProgram>)
+    | ({
+          instructionType: TokenInstruction.GetAccountDataSizeForKind;
+      } & ParsedGetAccountDataSizeForKindInstruction<TProgram>)
+    | ({
+          instructionType: TokenInstruction.WithdrawExcessNativeLamports;
+      } & ParsedWithdrawExcessNativeLamportsInstruction<TProgram>)
     | ({ instructionType: TokenInstruction.Batch } & ParsedBatchInstruction<TProgram>);
 
 export function parseTokenInstruction<TProgram extends string>(
@@ -490,6 +688,125 @@ export function parseTokenInstruction<TProgram extends string>(
             assertIsInstructionWithAccounts(instruction);
             return { instructionType: TokenInstruction.UnwrapLamports, ...parseUnwrapLamportsInstruction(instruction) };
         }
+        case TokenInstruction.SweepAndClose: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: TokenInstruction.SweepAndClose, ...parseSweepAndCloseInstruction(instruction) };
+        }
+        case TokenInstruction.BurnAll: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: TokenInstruction.BurnAll, ...parseBurnAllInstruction(instruction) };
+        }
+        case TokenInstruction.TransferAll: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: TokenInstruction.TransferAll, ...parseTransferAllInstruction(instruction) };
+        }
+        case TokenInstruction.TransferAllChecked: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.TransferAllChecked,
+                ...parseTransferAllCheckedInstruction(instruction),
+            };
+        }
+        case TokenInstruction.ApproveIfCurrent: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.ApproveIfCurrent,
+                ...parseApproveIfCurrentInstruction(instruction),
+            };
+        }
+        case TokenInstruction.IncreaseAllowance: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.IncreaseAllowance,
+                ...parseIncreaseAllowanceInstruction(instruction),
+            };
+        }
+        case TokenInstruction.DecreaseAllowance: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.DecreaseAllowance,
+                ...parseDecreaseAllowanceInstruction(instruction),
+            };
+        }
+        case TokenInstruction.InitializePermitNonce: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.InitializePermitNonce,
+                ...parseInitializePermitNonceInstruction(instruction),
+            };
+        }
+        case TokenInstruction.ApproveWithPermit: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.ApproveWithPermit,
+                ...parseApproveWithPermitInstruction(instruction),
+            };
+        }
+        case TokenInstruction.GetMinimumBalance: {
+            return {
+                instructionType: TokenInstruction.GetMinimumBalance,
+                ...parseGetMinimumBalanceInstruction(instruction),
+            };
+        }
+        case TokenInstruction.CreateAndInitializeMint: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.CreateAndInitializeMint,
+                ...parseCreateAndInitializeMintInstruction(instruction),
+            };
+        }
+        case TokenInstruction.CreateAndInitializeAccount: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.CreateAndInitializeAccount,
+                ...parseCreateAndInitializeAccountInstruction(instruction),
+            };
+        }
+        case TokenInstruction.InitializeAccountIdempotent: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.InitializeAccountIdempotent,
+                ...parseInitializeAccountIdempotentInstruction(instruction),
+            };
+        }
+        case TokenInstruction.SweepExcessLamports: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.SweepExcessLamports,
+                ...parseSweepExcessLamportsInstruction(instruction),
+            };
+        }
+        case TokenInstruction.InitializeClawbackConfig: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.InitializeClawbackConfig,
+                ...parseInitializeClawbackConfigInstruction(instruction),
+            };
+        }
+        case TokenInstruction.ClawbackTransfer: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.ClawbackTransfer,
+                ...parseClawbackTransferInstruction(instruction),
+            };
+        }
+        case TokenInstruction.ClawbackBurn: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: TokenInstruction.ClawbackBurn, ...parseClawbackBurnInstruction(instruction) };
+        }
+        case TokenInstruction.GetAccountDataSizeForKind: {
+            return {
+                instructionType: TokenInstruction.GetAccountDataSizeForKind,
+                ...parseGetAccountDataSizeForKindInstruction(instruction),
+            };
+        }
+        case TokenInstruction.WithdrawExcessNativeLamports: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: TokenInstruction.WithdrawExcessNativeLamports,
+                ...parseWithdrawExcessNativeLamportsInstruction(instruction),
+            };
+        }
         case TokenInstruction.Batch: {
             return { instructionType: TokenInstruction.Batch, ...parseBatchInstruction(instruction) };
         }
@@ -504,6 +821,7 @@ export function parseTokenInstruction<TProgram extends string>(
 export type TokenPlugin = {
     accounts: TokenPluginAccounts;
     instructions: TokenPluginInstructions;
+    pdas: TokenPluginPdas;
     identifyAccount: typeof identifyTokenAccount;
     identifyInstruction: typeof identifyTokenInstruction;
     parseInstruction: typeof parseTokenInstruction;
@@ -513,6 +831,8 @@ export type TokenPluginAccounts = {
     mint: ReturnType<typeof getMintCodec> & SelfFetchFunctions<MintArgs, Mint>;
     token: ReturnType<typeof getTokenCodec> & SelfFetchFunctions<TokenArgs, Token>;
     multisig: ReturnType<typeof getMultisigCodec> & SelfFetchFunctions<MultisigArgs, Multisig>;
+    permitNonce: ReturnType<typeof getPermitNonceCodec> & SelfFetchFunctions<PermitNonceArgs, PermitNonce>;
+    clawbackConfig: ReturnType<typeof getClawbackConfigCodec> & SelfFetchFunctions<ClawbackConfigArgs, ClawbackConfig>;
 };
 
 export type TokenPluginInstructions = {
@@ -581,9 +901,64 @@ export type TokenPluginInstructions = {
     unwrapLamports: (
         input: UnwrapLamportsInput,
     ) => ReturnType<typeof getUnwrapLamportsInstruction> & SelfPlanAndSendFunctions;
+    sweepAndClose: (
+        input: SweepAndCloseInput,
+    ) => ReturnType<typeof getSweepAndCloseInstruction> & SelfPlanAndSendFunctions;
+    burnAll: (input: BurnAllInput) => ReturnType<typeof getBurnAllInstruction> & SelfPlanAndSendFunctions;
+    transferAll: (input: TransferAllInput) => ReturnType<typeof getTransferAllInstruction> & SelfPlanAndSendFunctions;
+    transferAllChecked: (
+        input: TransferAllCheckedInput,
+    ) => ReturnType<typeof getTransferAllCheckedInstruction> & SelfPlanAndSendFunctions;
+    approveIfCurrent: (
+        input: ApproveIfCurrentInput,
+    ) => ReturnType<typeof getApproveIfCurrentInstruction> & SelfPlanAndSendFunctions;
+    increaseAllowance: (
+        input: IncreaseAllowanceInput,
+    ) => ReturnType<typeof getIncreaseAllowanceInstruction> & SelfPlanAndSendFunctions;
+    decreaseAllowance: (
+        input: DecreaseAllowanceInput,
+    ) => ReturnType<typeof getDecreaseAllowanceInstruction> & SelfPlanAndSendFunctions;
+    initializePermitNonce: (
+        input: InitializePermitNonceInput,
+    ) => ReturnType<typeof getInitializePermitNonceInstruction> & SelfPlanAndSendFunctions;
+    approveWithPermit: (
+        input: ApproveWithPermitInput,
+    ) => ReturnType<typeof getApproveWithPermitInstruction> & SelfPlanAndSendFunctions;
+    getMinimumBalance: (
+        input: GetMinimumBalanceInput,
+    ) => ReturnType<typeof getGetMinimumBalanceInstruction> & SelfPlanAndSendFunctions;
+    createAndInitializeMint: (
+        input: CreateAndInitializeMintInput,
+    ) => ReturnType<typeof getCreateAndInitializeMintInstruction> & SelfPlanAndSendFunctions;
+    createAndInitializeAccount: (
+        input: CreateAndInitializeAccountInput,
+    ) => ReturnType<typeof getCreateAndInitializeAccountInstruction> & SelfPlanAndSendFunctions;
+    initializeAccountIdempotent: (
+        input: InitializeAccountIdempotentInput,
+    ) => ReturnType<typeof getInitializeAccountIdempotentInstruction> & SelfPlanAndSendFunctions;
+    sweepExcessLamports: (
+        input: SweepExcessLamportsInput,
+    ) => ReturnType<typeof getSweepExcessLamportsInstruction> & SelfPlanAndSendFunctions;
+    initializeClawbackConfig: (
+        input: InitializeClawbackConfigAsyncInput,
+    ) => ReturnType<typeof getInitializeClawbackConfigInstructionAsync> & SelfPlanAndSendFunctions;
+    clawbackTransfer: (
+        input: ClawbackTransferInput,
+    ) => ReturnType<typeof getClawbackTransferInstruction> & SelfPlanAndSendFunctions;
+    clawbackBurn: (
+        input: ClawbackBurnInput,
+    ) => ReturnType<typeof getClawbackBurnInstruction> & SelfPlanAndSendFunctions;
+    getAccountDataSizeForKind: (
+        input: GetAccountDataSizeForKindInput,
+    ) => ReturnType<typeof getGetAccountDataSizeForKindInstruction> & SelfPlanAndSendFunctions;
+    withdrawExcessNativeLamports: (
+        input: WithdrawExcessNativeLamportsInput,
+    ) => ReturnType<typeof getWithdrawExcessNativeLamportsInstruction> & SelfPlanAndSendFunctions;
     batch: (input: BatchInput) => ReturnType<typeof getBatchInstruction> & SelfPlanAndSendFunctions;
 };
 
+export type TokenPluginPdas = { clawbackConfig: typeof findClawbackConfigPda };
+
 export type TokenPluginRequirements = ClientWithRpc<GetAccountInfoApi & GetMultipleAccountsApi> &
     ClientWithTransactionPlanning &
     ClientWithTransactionSending;
@@ -596,6 +971,8 @@ export function tokenProgram() {
                     mint: addSelfFetchFunctions(client, getMintCodec()),
                     token: addSelfFetchFunctions(client, getTokenCodec()),
                     multisig: addSelfFetchFunctions(client, getMultisigCodec()),
+                    permitNonce: addSelfFetchFunctions(client, getPermitNonceCodec()),
+                    clawbackConfig: addSelfFetchFunctions(client, getClawbackConfigCodec()),
                 },
                 instructions: {
                     initializeMint: input => addSelfPlanAndSendFunctions(client, getInitializeMintInstruction(input)),
@@ -635,8 +1012,43 @@ export function tokenProgram() {
                     withdrawExcessLamports: input =>
                         addSelfPlanAndSendFunctions(client, getWithdrawExcessLamportsInstruction(input)),
                     unwrapLamports: input => addSelfPlanAndSendFunctions(client, getUnwrapLamportsInstruction(input)),
+                    sweepAndClose: input => addSelfPlanAndSendFunctions(client, getSweepAndCloseInstruction(input)),
+                    burnAll: input => addSelfPlanAndSendFunctions(client, getBurnAllInstruction(input)),
+                    transferAll: input => addSelfPlanAndSendFunctions(client, getTransferAllInstruction(input)),
+                    transferAllChecked: input =>
+                        addSelfPlanAndSendFunctions(client, getTransferAllCheckedInstruction(input)),
+                    approveIfCurrent: input =>
+                        addSelfPlanAndSendFunctions(client, getApproveIfCurrentInstruction(input)),
+                    increaseAllowance: input =>
+                        addSelfPlanAndSendFunctions(client, getIncreaseAllowanceInstruction(input)),
+                    decreaseAllowance: input =>
+                        addSelfPlanAndSendFunctions(client, getDecreaseAllowanceInstruction(input)),
+                    initializePermitNonce: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializePermitNonceInstruction(input)),
+                    approveWithPermit: input =>
+                        addSelfPlanAndSendFunctions(client, getApproveWithPermitInstruction(input)),
+                    getMinimumBalance: input =>
+                        addSelfPlanAndSendFunctions(client, getGetMinimumBalanceInstruction(input)),
+                    createAndInitializeMint: input =>
+                        addSelfPlanAndSendFunctions(client, getCreateAndInitializeMintInstruction(input)),
+                    createAndInitializeAccount: input =>
+                        addSelfPlanAndSendFunctions(client, getCreateAndInitializeAccountInstruction(input)),
+                    initializeAccountIdempotent: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeAccountIdempotentInstruction(input)),
+                    sweepExcessLamports: input =>
+                        addSelfPlanAndSendFunctions(client, getSweepExcessLamportsInstruction(input)),
+                    initializeClawbackConfig: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeClawbackConfigInstructionAsync(input)),
+                    clawbackTransfer: input =>
+                        addSelfPlanAndSendFunctions(client, getClawbackTransferInstruction(input)),
+                    clawbackBurn: input => addSelfPlanAndSendFunctions(client, getClawbackBurnInstruction(input)),
+                    getAccountDataSizeForKind: input =>
+                        addSelfPlanAndSendFunctions(client, getGetAccountDataSizeForKindInstruction(input)),
+                    withdrawExcessNativeLamports: input =>
+                        addSelfPlanAndSendFunctions(client, getWithdrawExcessNativeLamportsInstruction(input)),
                     batch: input => addSelfPlanAndSendFunctions(client, getBatchInstruction(input)),
                 },
+                pdas: { clawbackConfig: findClawbackConfigPda },
                 identifyAccount: identifyTokenAccount,
                 identifyInstruction: identifyTokenInstruction,
                 parseInstruction: parseTokenInstruction,
@@ -6,5 +6,6 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './accountKind';
 export * from './accountState';
 export * from './authorityType';
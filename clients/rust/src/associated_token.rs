@@ -0,0 +1,292 @@
+//! Associated Token Account program addresses and instructions.
+
+use {
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+/// Associated Token Account program ID.
+pub const ID: Pubkey = solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Instructions supported by the Associated Token Account program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AssociatedTokenInstruction {
+    /// Creates an associated token account for the given wallet address and
+    /// token mint, failing if the account exists.
+    Create,
+    /// Creates an associated token account for the given wallet address and
+    /// token mint, if it does not already exist.
+    CreateIdempotent,
+    /// Transfers from and closes a nested associated token account: an
+    /// associated token account owned by an associated token account.
+    RecoverNested,
+}
+
+/// Derives the associated token account address and bump seed for the given
+/// wallet address, token mint and token program ID.
+pub fn find_associated_token_address(
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            wallet_address.as_ref(),
+            token_program_id.as_ref(),
+            token_mint_address.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+/// Derives the associated token account address for the given wallet
+/// address and token mint of the Token program.
+pub fn get_associated_token_address(
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+) -> Pubkey {
+    get_associated_token_address_with_program_id(
+        wallet_address,
+        token_mint_address,
+        &spl_token_interface::id(),
+    )
+}
+
+/// Derives the associated token account address for the given wallet
+/// address, token mint and token program ID.
+pub fn get_associated_token_address_with_program_id(
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Pubkey {
+    find_associated_token_address(wallet_address, token_mint_address, token_program_id).0
+}
+
+/// Creates a `Create` or `CreateIdempotent` instruction for an already
+/// derived associated token account address.
+pub(crate) fn build_create_instruction(
+    funding_address: &Pubkey,
+    associated_account_address: &Pubkey,
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+    instruction: AssociatedTokenInstruction,
+) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(*funding_address, true),
+            AccountMeta::new(*associated_account_address, false),
+            AccountMeta::new_readonly(*wallet_address, false),
+            AccountMeta::new_readonly(*token_mint_address, false),
+            AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            AccountMeta::new_readonly(*token_program_id, false),
+        ],
+        data: vec![instruction as u8],
+    }
+}
+
+/// Creates a `Create` instruction.
+pub fn create_associated_token_account(
+    funding_address: &Pubkey,
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    build_create_instruction(
+        funding_address,
+        &get_associated_token_address_with_program_id(
+            wallet_address,
+            token_mint_address,
+            token_program_id,
+        ),
+        wallet_address,
+        token_mint_address,
+        token_program_id,
+        AssociatedTokenInstruction::Create,
+    )
+}
+
+/// Creates a `CreateIdempotent` instruction.
+pub fn create_associated_token_account_idempotent(
+    funding_address: &Pubkey,
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    build_create_instruction(
+        funding_address,
+        &get_associated_token_address_with_program_id(
+            wallet_address,
+            token_mint_address,
+            token_program_id,
+        ),
+        wallet_address,
+        token_mint_address,
+        token_program_id,
+        AssociatedTokenInstruction::CreateIdempotent,
+    )
+}
+
+/// Creates a `RecoverNested` instruction.
+///
+/// The nested associated token account of `nested_token_mint_address`, owned
+/// by the associated token account of `owner_token_mint_address`, is
+/// transferred to the associated token account of the wallet and closed.
+pub fn recover_nested(
+    wallet_address: &Pubkey,
+    owner_token_mint_address: &Pubkey,
+    nested_token_mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let owner_associated_account_address = get_associated_token_address_with_program_id(
+        wallet_address,
+        owner_token_mint_address,
+        token_program_id,
+    );
+    let destination_associated_account_address = get_associated_token_address_with_program_id(
+        wallet_address,
+        nested_token_mint_address,
+        token_program_id,
+    );
+    let nested_associated_account_address = get_associated_token_address_with_program_id(
+        &owner_associated_account_address,
+        nested_token_mint_address,
+        token_program_id,
+    );
+
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(nested_associated_account_address, false),
+            AccountMeta::new_readonly(*nested_token_mint_address, false),
+            AccountMeta::new(destination_associated_account_address, false),
+            AccountMeta::new_readonly(owner_associated_account_address, false),
+            AccountMeta::new_readonly(*owner_token_mint_address, false),
+            AccountMeta::new(*wallet_address, true),
+            AccountMeta::new_readonly(*token_program_id, false),
+        ],
+        data: vec![AssociatedTokenInstruction::RecoverNested as u8],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::str::FromStr};
+
+    const TOKEN_2022_PROGRAM_ID: Pubkey =
+        solana_pubkey::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+    #[test]
+    fn test_find_associated_token_address() {
+        let vectors = [
+            (
+                Pubkey::new_from_array([1; 32]),
+                spl_token_interface::native_mint::id(),
+                spl_token_interface::id(),
+                "7i4VVk55NzhtekVjPg7EZzoSGznZYixPyd5cCeDxi7rW",
+                254,
+            ),
+            (
+                Pubkey::new_from_array([2; 32]),
+                Pubkey::new_from_array([3; 32]),
+                spl_token_interface::id(),
+                "BKbxqhBJfLZNgac5dEUesF1V5xRZSzxDkcpQBAy4c8sw",
+                254,
+            ),
+            (
+                Pubkey::new_from_array([2; 32]),
+                Pubkey::new_from_array([3; 32]),
+                TOKEN_2022_PROGRAM_ID,
+                "DB2rSUqWCtYs8BwzuLqJZK1DfqQ22eeudg4LBS1X6Urh",
+                255,
+            ),
+        ];
+
+        for (wallet, mint, token_program_id, address, bump) in vectors {
+            let address = Pubkey::from_str(address).unwrap();
+            assert_eq!(
+                find_associated_token_address(&wallet, &mint, &token_program_id),
+                (address, bump)
+            );
+            assert_eq!(
+                get_associated_token_address_with_program_id(&wallet, &mint, &token_program_id),
+                address
+            );
+        }
+
+        assert_eq!(
+            get_associated_token_address(
+                &Pubkey::new_from_array([1; 32]),
+                &spl_token_interface::native_mint::id()
+            ),
+            Pubkey::from_str("7i4VVk55NzhtekVjPg7EZzoSGznZYixPyd5cCeDxi7rW").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_create_associated_token_account() {
+        let funding = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let address = get_associated_token_address(&wallet, &mint);
+
+        let instruction =
+            create_associated_token_account(&funding, &wallet, &mint, &spl_token_interface::id());
+        assert_eq!(instruction.program_id, ID);
+        assert_eq!(instruction.data, vec![0]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(funding, true),
+                AccountMeta::new(address, false),
+                AccountMeta::new_readonly(wallet, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+                AccountMeta::new_readonly(spl_token_interface::id(), false),
+            ]
+        );
+
+        let idempotent = create_associated_token_account_idempotent(
+            &funding,
+            &wallet,
+            &mint,
+            &spl_token_interface::id(),
+        );
+        assert_eq!(idempotent.data, vec![1]);
+        assert_eq!(idempotent.accounts, instruction.accounts);
+    }
+
+    #[test]
+    fn test_recover_nested() {
+        let wallet = Pubkey::new_unique();
+        let owner_mint = Pubkey::new_unique();
+        let nested_mint = Pubkey::new_unique();
+        let owner_address = get_associated_token_address(&wallet, &owner_mint);
+
+        let instruction = recover_nested(
+            &wallet,
+            &owner_mint,
+            &nested_mint,
+            &spl_token_interface::id(),
+        );
+        assert_eq!(instruction.program_id, ID);
+        assert_eq!(instruction.data, vec![2]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(
+                    get_associated_token_address(&owner_address, &nested_mint),
+                    false
+                ),
+                AccountMeta::new_readonly(nested_mint, false),
+                AccountMeta::new(get_associated_token_address(&wallet, &nested_mint), false),
+                AccountMeta::new_readonly(owner_address, false),
+                AccountMeta::new_readonly(owner_mint, false),
+                AccountMeta::new(wallet, true),
+                AccountMeta::new_readonly(spl_token_interface::id(), false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,403 @@
+//! Display formatting of token amounts.
+//!
+//! Renders a [`TokenAmount`] for user interfaces, with locale separators,
+//! compact or scientific notation and limits on the number of digits shown.
+//! Digits are only ever truncated, never rounded up, so a displayed balance is
+//! never larger than the actual one.
+//!
+//! ```
+//! use {
+//!     spl_token_client::format::{AmountFormatter, Locale, Notation},
+//!     spl_token_interface::amount::TokenAmount,
+//! };
+//!
+//! let amount = TokenAmount::new(1_299_999_999, 3);
+//! let formatter = AmountFormatter::new().locale(Locale::EN);
+//! assert_eq!(formatter.format(&amount), "1,299,999.999");
+//!
+//! let formatter = formatter.notation(Notation::Compact).significant_digits(2);
+//! assert_eq!(formatter.format(&amount), "1.2M");
+//! ```
+
+use spl_token_interface::amount::TokenAmount;
+
+/// Separators used to render a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// Separator between the integer and fractional digits.
+    pub decimal_separator: char,
+    /// Separator between groups of integer digits, if any.
+    pub group_separator: Option<char>,
+    /// Number of integer digits in each group.
+    pub group_size: u8,
+}
+
+impl Locale {
+    /// No grouping and a `.` decimal separator, as returned by the
+    /// `AmountToUiAmount` instruction.
+    pub const PLAIN: Locale = Locale {
+        decimal_separator: '.',
+        group_separator: None,
+        group_size: 3,
+    };
+
+    /// English: `1,234.5`.
+    pub const EN: Locale = Locale {
+        decimal_separator: '.',
+        group_separator: Some(','),
+        group_size: 3,
+    };
+
+    /// German: `1.234,5`.
+    pub const DE: Locale = Locale {
+        decimal_separator: ',',
+        group_separator: Some('.'),
+        group_size: 3,
+    };
+
+    /// French: `1 234,5`, grouped with a narrow no-break space.
+    pub const FR: Locale = Locale {
+        decimal_separator: ',',
+        group_separator: Some('\u{202f}'),
+        group_size: 3,
+    };
+
+    /// Swiss: `1'234.5`.
+    pub const CH: Locale = Locale {
+        decimal_separator: '.',
+        group_separator: Some('\''),
+        group_size: 3,
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+/// Notation used to render a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// All integer digits are rendered: `1234567`.
+    #[default]
+    Standard,
+    /// Thousands, millions, billions and trillions are abbreviated with a
+    /// suffix: `1.234567M`.
+    Compact,
+    /// A single integer digit followed by a power of ten: `1.234567e6`.
+    Scientific,
+}
+
+/// Compact notation suffixes, by power of ten.
+const COMPACT_SUFFIXES: [(usize, &str); 4] = [(12, "T"), (9, "B"), (6, "M"), (3, "K")];
+
+/// Formatter of token amounts for display.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AmountFormatter {
+    locale: Locale,
+    notation: Notation,
+    significant_digits: Option<u8>,
+    max_fraction_digits: Option<u8>,
+}
+
+impl AmountFormatter {
+    /// Creates a formatter rendering every digit of the amount, with no
+    /// grouping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the separators used to render the amount.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the notation used to render the amount.
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Limits the number of significant digits rendered, truncating the
+    /// remaining ones. At least one significant digit is always rendered.
+    pub fn significant_digits(mut self, digits: u8) -> Self {
+        self.significant_digits = Some(digits.max(1));
+        self
+    }
+
+    /// Limits the number of fractional digits rendered, truncating the
+    /// remaining ones.
+    ///
+    /// A non-zero amount smaller than the smallest rendered digit is
+    /// rendered as zero.
+    pub fn max_fraction_digits(mut self, digits: u8) -> Self {
+        self.max_fraction_digits = Some(digits);
+        self
+    }
+
+    /// Renders the amount.
+    pub fn format(&self, amount: &TokenAmount) -> String {
+        let ui_amount = amount.to_ui_amount_string();
+        let (integer, fraction) = ui_amount
+            .split_once('.')
+            .unwrap_or((ui_amount.as_str(), ""));
+
+        let mut digits = integer.as_bytes().to_vec();
+        digits.extend_from_slice(fraction.as_bytes());
+        // Position of the decimal point in `digits`.
+        let mut point = integer.len();
+
+        let mut suffix = String::new();
+        match self.notation {
+            Notation::Standard => {}
+            Notation::Compact => {
+                if let Some((exponent, compact)) = COMPACT_SUFFIXES
+                    .into_iter()
+                    .find(|(exponent, _)| integer.len() > *exponent)
+                {
+                    point = point.saturating_sub(exponent);
+                    suffix.push_str(compact);
+                }
+            }
+            Notation::Scientific => {
+                // Zero is rendered without an exponent.
+                if let Some(first) = digits.iter().position(|digit| *digit != b'0') {
+                    let exponent = (point as isize)
+                        .saturating_sub(first as isize)
+                        .saturating_sub(1);
+                    digits.drain(..first);
+                    point = 1;
+                    suffix = format!("e{exponent}");
+                }
+            }
+        }
+
+        if let Some(significant_digits) = self.significant_digits {
+            if let Some(first) = digits.iter().position(|digit| *digit != b'0') {
+                let end = first.saturating_add(significant_digits as usize);
+                digits.iter_mut().skip(end).for_each(|digit| *digit = b'0');
+            }
+        }
+        if let Some(max_fraction_digits) = self.max_fraction_digits {
+            let end = point.saturating_add(max_fraction_digits as usize);
+            digits.truncate(end);
+        }
+
+        let (integer, fraction) = digits.split_at(point);
+        let fraction = fraction
+            .iter()
+            .rposition(|digit| *digit != b'0')
+            .map_or(&fraction[..0], |last| &fraction[..=last]);
+
+        let mut s = String::with_capacity(digits.len().saturating_mul(2));
+        let group_size = self.locale.group_size.max(1) as usize;
+        for (i, digit) in integer.iter().enumerate() {
+            if i > 0 && integer.len().saturating_sub(i).is_multiple_of(group_size) {
+                if let Some(separator) = self.locale.group_separator {
+                    s.push(separator);
+                }
+            }
+            s.push(*digit as char);
+        }
+        if !fraction.is_empty() {
+            s.push(self.locale.decimal_separator);
+            fraction.iter().for_each(|digit| s.push(*digit as char));
+        }
+        s.push_str(&suffix);
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_standard() {
+        let formatter = AmountFormatter::new();
+        assert_eq!(formatter.format(&TokenAmount::new(0, 0)), "0");
+        assert_eq!(formatter.format(&TokenAmount::new(0, 9)), "0");
+        assert_eq!(formatter.format(&TokenAmount::new(1, 9)), "0.000000001");
+        assert_eq!(formatter.format(&TokenAmount::new(1_500, 3)), "1.5");
+        assert_eq!(
+            formatter.format(&TokenAmount::new(u64::MAX, 0)),
+            "18446744073709551615"
+        );
+
+        // The plain locale matches the `AmountToUiAmount` instruction.
+        for decimals in [0, 1, 6, 9, 19, 20, 255] {
+            for raw in [0, 1, 10, 1_000_000, u64::MAX] {
+                let amount = TokenAmount::new(raw, decimals);
+                assert_eq!(
+                    formatter.format(&amount),
+                    amount.to_ui_amount_string_trimmed()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_locale() {
+        let amount = TokenAmount::new(1_234_567_891, 3);
+        let format = |locale| AmountFormatter::new().locale(locale).format(&amount);
+        assert_eq!(format(Locale::PLAIN), "1234567.891");
+        assert_eq!(format(Locale::EN), "1,234,567.891");
+        assert_eq!(format(Locale::DE), "1.234.567,891");
+        assert_eq!(format(Locale::FR), "1\u{202f}234\u{202f}567,891");
+        assert_eq!(format(Locale::CH), "1'234'567.891");
+
+        let formatter = AmountFormatter::new().locale(Locale::EN);
+        assert_eq!(formatter.format(&TokenAmount::new(123, 0)), "123");
+        assert_eq!(formatter.format(&TokenAmount::new(1_234, 0)), "1,234");
+        assert_eq!(formatter.format(&TokenAmount::new(123_456, 0)), "123,456");
+        assert_eq!(formatter.format(&TokenAmount::new(12, 3)), "0.012");
+
+        let formatter = formatter.locale(Locale {
+            group_size: 4,
+            ..Locale::EN
+        });
+        assert_eq!(formatter.format(&TokenAmount::new(123_456, 0)), "12,3456");
+    }
+
+    #[test]
+    fn test_format_compact() {
+        let formatter = AmountFormatter::new().notation(Notation::Compact);
+        assert_eq!(formatter.format(&TokenAmount::new(999, 0)), "999");
+        assert_eq!(formatter.format(&TokenAmount::new(1_000, 0)), "1K");
+        assert_eq!(
+            formatter.format(&TokenAmount::new(1_234_567, 0)),
+            "1.234567M"
+        );
+        assert_eq!(
+            formatter.format(&TokenAmount::new(1_234_567_000, 3)),
+            "1.234567M"
+        );
+        assert_eq!(formatter.format(&TokenAmount::new(5, 0)), "5");
+        assert_eq!(formatter.format(&TokenAmount::new(5, 4)), "0.0005");
+        assert_eq!(
+            formatter.format(&TokenAmount::new(u64::MAX, 0)),
+            "18446744.073709551615T"
+        );
+
+        let formatter = formatter.significant_digits(2).locale(Locale::DE);
+        assert_eq!(formatter.format(&TokenAmount::new(1_299_999, 0)), "1,2M");
+        assert_eq!(formatter.format(&TokenAmount::new(999_999, 0)), "990K");
+        assert_eq!(
+            formatter.format(&TokenAmount::new(u64::MAX, 0)),
+            "18.000.000T"
+        );
+    }
+
+    #[test]
+    fn test_format_scientific() {
+        let formatter = AmountFormatter::new().notation(Notation::Scientific);
+        assert_eq!(formatter.format(&TokenAmount::new(0, 9)), "0");
+        assert_eq!(formatter.format(&TokenAmount::new(5, 0)), "5e0");
+        assert_eq!(formatter.format(&TokenAmount::new(1_500, 3)), "1.5e0");
+        assert_eq!(formatter.format(&TokenAmount::new(5, 4)), "5e-4");
+        assert_eq!(
+            formatter.format(&TokenAmount::new(1_234_567, 0)),
+            "1.234567e6"
+        );
+        assert_eq!(
+            formatter.format(&TokenAmount::new(1_234_567_000, 3)),
+            "1.234567e6"
+        );
+        assert_eq!(
+            formatter.format(&TokenAmount::new(u64::MAX, 0)),
+            "1.8446744073709551615e19"
+        );
+
+        let formatter = formatter.significant_digits(2).locale(Locale::DE);
+        assert_eq!(formatter.format(&TokenAmount::new(1_299_999, 0)), "1,2e6");
+        assert_eq!(formatter.format(&TokenAmount::new(999_999, 0)), "9,9e5");
+        assert_eq!(formatter.format(&TokenAmount::new(1_987, 9)), "1,9e-6");
+
+        let formatter = AmountFormatter::new()
+            .notation(Notation::Scientific)
+            .max_fraction_digits(1);
+        assert_eq!(formatter.format(&TokenAmount::new(1_299_999, 0)), "1.2e6");
+    }
+
+    #[test]
+    fn test_format_truncates() {
+        let amount = TokenAmount::new(1_999_999, 6);
+
+        let formatter = AmountFormatter::new().significant_digits(3);
+        assert_eq!(formatter.format(&amount), "1.99");
+        assert_eq!(formatter.format(&TokenAmount::new(987_654, 0)), "987000");
+        assert_eq!(formatter.format(&TokenAmount::new(1_987, 9)), "0.00000198");
+        assert_eq!(formatter.format(&TokenAmount::new(0, 9)), "0");
+        assert_eq!(
+            AmountFormatter::new().significant_digits(0).format(&amount),
+            "1"
+        );
+
+        let formatter = AmountFormatter::new().max_fraction_digits(2);
+        assert_eq!(formatter.format(&amount), "1.99");
+        assert_eq!(formatter.format(&TokenAmount::new(1, 6)), "0");
+        assert_eq!(formatter.format(&TokenAmount::new(1_000_000, 6)), "1");
+        assert_eq!(
+            AmountFormatter::new()
+                .max_fraction_digits(0)
+                .format(&amount),
+            "1"
+        );
+    }
+
+    /// Converts a plain formatted amount back to its raw value.
+    fn parse(formatted: &str, decimals: u8) -> u64 {
+        let (number, exponent) = if let Some((number, exponent)) = formatted.split_once('e') {
+            (number, exponent.parse::<i16>().unwrap())
+        } else {
+            COMPACT_SUFFIXES
+                .into_iter()
+                .find_map(|(exponent, suffix)| {
+                    formatted
+                        .strip_suffix(suffix)
+                        .map(|number| (number, exponent as i16))
+                })
+                .unwrap_or((formatted, 0))
+        };
+        let decimals = u8::try_from(i16::from(decimals).saturating_add(exponent)).unwrap();
+        TokenAmount::from_ui_amount_str(number, decimals)
+            .unwrap()
+            .raw
+    }
+
+    #[test]
+    fn test_format_never_overstates() {
+        let mut amounts = vec![0, 1, 9, u64::MAX - 1, u64::MAX];
+        let mut power = 1u64;
+        while let Some(next) = power.checked_mul(10) {
+            power = next;
+            amounts.extend([power - 1, power + 1, power / 7 * 6]);
+        }
+
+        for decimals in [0, 1, 2, 6, 9, 12, 18, 19, 20, 24] {
+            for raw in amounts.iter().copied() {
+                for notation in [Notation::Standard, Notation::Compact, Notation::Scientific] {
+                    for significant_digits in [None, Some(1), Some(2), Some(5)] {
+                        for max_fraction_digits in [None, Some(0), Some(2)] {
+                            let mut formatter = AmountFormatter::new().notation(notation);
+                            if let Some(digits) = significant_digits {
+                                formatter = formatter.significant_digits(digits);
+                            }
+                            if let Some(digits) = max_fraction_digits {
+                                formatter = formatter.max_fraction_digits(digits);
+                            }
+                            let formatted = formatter.format(&TokenAmount::new(raw, decimals));
+                            let displayed = parse(&formatted, decimals);
+                            assert!(displayed <= raw, "{formatted} overstates {raw}");
+                            if significant_digits.is_none() && max_fraction_digits.is_none() {
+                                assert_eq!(displayed, raw);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,5 @@
-/*
-mod generated;
+//! Client helpers for the Token program.
 
-pub use generated::programs::TOKEN_ID as ID;
-pub use generated::*;
-*/
+pub mod associated_token;
+pub mod format;
+pub mod plan;
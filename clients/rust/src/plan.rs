@@ -0,0 +1,536 @@
+//! Instruction plans combining account creation and token instructions.
+//!
+//! These mirror the `createMint`, `mintToATA` and `transferToATA` helpers of
+//! the JS client. Several plans can be combined into a single transaction,
+//! each plan executing after the previous one. When plans do not depend on
+//! each other, the instructions creating accounts are kept apart from the
+//! token instructions so that, when targeting p-token, the token instructions
+//! can be sent as a single `Batch` instruction.
+
+use {
+    crate::associated_token::{build_create_instruction, AssociatedTokenInstruction},
+    solana_instruction::Instruction,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_system_interface::instruction::create_account,
+    spl_token_interface::{
+        instruction::{batch, initialize_mint2, mint_to_checked, transfer_checked},
+        state::Mint,
+    },
+};
+
+/// Token program implementation targeted by a plan.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TokenProgram {
+    /// The SPL Token program.
+    #[default]
+    SplToken,
+    /// The p-token program, which supports `Batch` instructions.
+    PToken,
+}
+
+/// Configuration of a plan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlanConfig {
+    /// The token program ID.
+    pub token_program_id: Pubkey,
+    /// The token program implementation.
+    pub token_program: TokenProgram,
+}
+
+impl Default for PlanConfig {
+    fn default() -> Self {
+        Self {
+            token_program_id: spl_token_interface::id(),
+            token_program: TokenProgram::SplToken,
+        }
+    }
+}
+
+/// Instructions of a plan, with the instructions creating accounts kept
+/// apart from the token instructions depending on them.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Step {
+    /// Instructions creating accounts, executed first.
+    setup: Vec<Instruction>,
+    /// Token program instructions.
+    token: Vec<Instruction>,
+}
+
+/// An ordered list of instructions to be included in a transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstructionPlan {
+    config: PlanConfig,
+    /// Steps of the plan, executed in order.
+    steps: Vec<Step>,
+}
+
+impl InstructionPlan {
+    /// Creates an empty plan.
+    pub fn new(config: PlanConfig) -> Self {
+        Self {
+            config,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Returns the configuration of the plan.
+    pub fn config(&self) -> &PlanConfig {
+        &self.config
+    }
+
+    /// Appends the instructions of another plan, executed after the
+    /// instructions of this plan.
+    ///
+    /// Fails with [`ProgramError::InvalidArgument`] if the plans target
+    /// different token programs.
+    pub fn extend(&mut self, other: InstructionPlan) -> Result<(), ProgramError> {
+        self.check_config(&other)?;
+        self.steps.extend(other.steps);
+        Ok(())
+    }
+
+    /// Appends the instructions of another plan that does not depend on the
+    /// instructions of this plan.
+    ///
+    /// The account creation instructions of the other plan are executed
+    /// before the token instructions of this plan, so the token instructions
+    /// of both plans can be combined in a single `Batch` instruction.
+    ///
+    /// Fails with [`ProgramError::InvalidArgument`] if the plans target
+    /// different token programs.
+    pub fn extend_independent(&mut self, other: InstructionPlan) -> Result<(), ProgramError> {
+        self.check_config(&other)?;
+        for step in other.steps {
+            match self.steps.last_mut() {
+                Some(last) => {
+                    last.setup.extend(step.setup);
+                    last.token.extend(step.token);
+                }
+                None => self.steps.push(step),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the instructions of the plan, in order.
+    ///
+    /// When the plan targets p-token and a step includes more than one token
+    /// instruction, these are combined in a single `Batch` instruction.
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ProgramError> {
+        let mut instructions = Vec::new();
+        for step in &self.steps {
+            instructions.extend_from_slice(&step.setup);
+            if self.config.token_program == TokenProgram::PToken && step.token.len() > 1 {
+                instructions.push(batch(&self.config.token_program_id, &step.token)?);
+            } else {
+                instructions.extend_from_slice(&step.token);
+            }
+        }
+        Ok(instructions)
+    }
+
+    /// Returns the accounts required to sign the transaction, in order of
+    /// appearance.
+    pub fn signers(&self) -> Vec<Pubkey> {
+        let mut signers = Vec::new();
+        for meta in self
+            .steps
+            .iter()
+            .flat_map(|step| step.setup.iter().chain(&step.token))
+            .flat_map(|instruction| &instruction.accounts)
+        {
+            if meta.is_signer && !signers.contains(&meta.pubkey) {
+                signers.push(meta.pubkey);
+            }
+        }
+        signers
+    }
+
+    fn check_config(&self, other: &InstructionPlan) -> Result<(), ProgramError> {
+        if self.config != other.config {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    fn with_step(config: PlanConfig, setup: Instruction, token: Instruction) -> Self {
+        Self {
+            config,
+            steps: vec![Step {
+                setup: vec![setup],
+                token: vec![token],
+            }],
+        }
+    }
+}
+
+/// Input of [`create_mint_plan`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateMintInput {
+    /// Funding account (must be a system account).
+    pub payer: Pubkey,
+    /// New mint account to create.
+    pub new_mint: Pubkey,
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// The authority/multisignature to mint tokens.
+    pub mint_authority: Pubkey,
+    /// The optional freeze authority/multisignature of the mint.
+    pub freeze_authority: Option<Pubkey>,
+    /// Amount of lamports to fund the mint account with, usually the
+    /// rent-exempt minimum balance of [`Mint::LEN`] bytes returned by the
+    /// `getMinimumBalanceForRentExemption` RPC method.
+    pub mint_account_lamports: u64,
+}
+
+/// Creates the plan creating and initializing a new mint.
+pub fn create_mint_plan(
+    input: &CreateMintInput,
+    config: PlanConfig,
+) -> Result<InstructionPlan, ProgramError> {
+    Ok(InstructionPlan::with_step(
+        config,
+        create_account(
+            &input.payer,
+            &input.new_mint,
+            input.mint_account_lamports,
+            Mint::LEN as u64,
+            &config.token_program_id,
+        ),
+        initialize_mint2(
+            &config.token_program_id,
+            &input.new_mint,
+            &input.mint_authority,
+            input.freeze_authority.as_ref(),
+            input.decimals,
+        )?,
+    ))
+}
+
+/// Input of [`mint_to_ata_plan`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintToAtaInput {
+    /// Funding account (must be a system account).
+    pub payer: Pubkey,
+    /// Associated token account address to mint to, created if it does not
+    /// already exist.
+    ///
+    /// See [`get_associated_token_address_with_program_id`].
+    ///
+    /// [`get_associated_token_address_with_program_id`]: crate::associated_token::get_associated_token_address_with_program_id
+    pub ata: Pubkey,
+    /// Wallet address for the associated token account.
+    pub owner: Pubkey,
+    /// The token mint for the associated token account.
+    pub mint: Pubkey,
+    /// The mint's minting authority or its multisignature account.
+    pub mint_authority: Pubkey,
+    /// The amount of new tokens to mint.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// Signer accounts when the mint authority is a multisignature account.
+    pub multi_signers: Vec<Pubkey>,
+}
+
+/// Creates the plan minting tokens to an associated token account, creating
+/// the account if needed.
+pub fn mint_to_ata_plan(
+    input: &MintToAtaInput,
+    config: PlanConfig,
+) -> Result<InstructionPlan, ProgramError> {
+    let multi_signers = input.multi_signers.iter().collect::<Vec<_>>();
+
+    Ok(InstructionPlan::with_step(
+        config,
+        build_create_instruction(
+            &input.payer,
+            &input.ata,
+            &input.owner,
+            &input.mint,
+            &config.token_program_id,
+            AssociatedTokenInstruction::CreateIdempotent,
+        ),
+        mint_to_checked(
+            &config.token_program_id,
+            &input.mint,
+            &input.ata,
+            &input.mint_authority,
+            &multi_signers,
+            input.amount,
+            input.decimals,
+        )?,
+    ))
+}
+
+/// Input of [`transfer_to_ata_plan`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferToAtaInput {
+    /// Funding account (must be a system account).
+    pub payer: Pubkey,
+    /// The token mint to transfer.
+    pub mint: Pubkey,
+    /// The source account for the transfer.
+    pub source: Pubkey,
+    /// The source account's owner/delegate or its multisignature account.
+    pub authority: Pubkey,
+    /// Associated token account address to transfer to, created if it does
+    /// not already exist.
+    ///
+    /// See [`get_associated_token_address_with_program_id`].
+    ///
+    /// [`get_associated_token_address_with_program_id`]: crate::associated_token::get_associated_token_address_with_program_id
+    pub destination: Pubkey,
+    /// Wallet address for the destination.
+    pub recipient: Pubkey,
+    /// The amount of tokens to transfer.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// Signer accounts when the authority is a multisignature account.
+    pub multi_signers: Vec<Pubkey>,
+}
+
+/// Creates the plan transferring tokens to an associated token account,
+/// creating the account if needed.
+pub fn transfer_to_ata_plan(
+    input: &TransferToAtaInput,
+    config: PlanConfig,
+) -> Result<InstructionPlan, ProgramError> {
+    let multi_signers = input.multi_signers.iter().collect::<Vec<_>>();
+
+    Ok(InstructionPlan::with_step(
+        config,
+        build_create_instruction(
+            &input.payer,
+            &input.destination,
+            &input.recipient,
+            &input.mint,
+            &config.token_program_id,
+            AssociatedTokenInstruction::CreateIdempotent,
+        ),
+        transfer_checked(
+            &config.token_program_id,
+            &input.source,
+            &input.mint,
+            &input.destination,
+            &input.authority,
+            &multi_signers,
+            input.amount,
+            input.decimals,
+        )?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        spl_token_interface::{decode::decode, instruction::TokenInstruction},
+    };
+
+    fn p_token() -> PlanConfig {
+        PlanConfig {
+            token_program: TokenProgram::PToken,
+            ..PlanConfig::default()
+        }
+    }
+
+    fn transfer_input(recipient: Pubkey) -> TransferToAtaInput {
+        TransferToAtaInput {
+            payer: Pubkey::new_from_array([1; 32]),
+            mint: Pubkey::new_from_array([2; 32]),
+            source: Pubkey::new_from_array([3; 32]),
+            authority: Pubkey::new_from_array([4; 32]),
+            destination: Pubkey::new_unique(),
+            recipient,
+            amount: 100,
+            decimals: 2,
+            multi_signers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_create_mint_plan() {
+        let input = CreateMintInput {
+            payer: Pubkey::new_unique(),
+            new_mint: Pubkey::new_unique(),
+            decimals: 6,
+            mint_authority: Pubkey::new_unique(),
+            freeze_authority: None,
+            mint_account_lamports: 1_461_600,
+        };
+        let plan = create_mint_plan(&input, PlanConfig::default()).unwrap();
+
+        let instructions = plan.instructions().unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(
+            instructions[0],
+            create_account(
+                &input.payer,
+                &input.new_mint,
+                1_461_600,
+                Mint::LEN as u64,
+                &spl_token_interface::id(),
+            )
+        );
+        assert_eq!(
+            decode(&instructions[1]).unwrap().instruction,
+            TokenInstruction::InitializeMint2 {
+                decimals: 6,
+                mint_authority: input.mint_authority,
+                freeze_authority: None.into(),
+            }
+        );
+        assert_eq!(plan.signers(), vec![input.payer, input.new_mint]);
+
+        // A single token instruction is never batched.
+        let plan = create_mint_plan(&input, p_token()).unwrap();
+        assert_eq!(plan.instructions().unwrap(), instructions);
+    }
+
+    #[test]
+    fn test_mint_to_ata_plan() {
+        let signer = Pubkey::new_unique();
+        let input = MintToAtaInput {
+            payer: Pubkey::new_unique(),
+            ata: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            mint_authority: Pubkey::new_unique(),
+            amount: 10,
+            decimals: 0,
+            multi_signers: vec![signer],
+        };
+        let plan = mint_to_ata_plan(&input, PlanConfig::default()).unwrap();
+
+        let instructions = plan.instructions().unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].program_id, crate::associated_token::ID);
+        assert_eq!(instructions[0].accounts[1].pubkey, input.ata);
+        let decoded = decode(&instructions[1]).unwrap();
+        assert_eq!(decoded.name, "mintToChecked");
+        assert_eq!(decoded.signers, vec![signer]);
+        // The multisig authority itself does not sign.
+        assert_eq!(plan.signers(), vec![input.payer, signer]);
+    }
+
+    #[test]
+    fn test_transfer_to_ata_plans_batched() {
+        let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let mut plan = InstructionPlan::new(p_token());
+        for recipient in recipients {
+            plan.extend_independent(
+                transfer_to_ata_plan(&transfer_input(recipient), p_token()).unwrap(),
+            )
+            .unwrap();
+        }
+
+        let instructions = plan.instructions().unwrap();
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(instructions[0].accounts[2].pubkey, recipients[0]);
+        assert_eq!(instructions[1].accounts[2].pubkey, recipients[1]);
+        let decoded = decode(&instructions[2]).unwrap();
+        assert_eq!(decoded.name, "batch");
+        assert_eq!(decoded.instructions.len(), 2);
+        assert!(decoded
+            .instructions
+            .iter()
+            .all(|instruction| instruction.name == "transferChecked"));
+        assert_eq!(
+            plan.signers(),
+            vec![
+                Pubkey::new_from_array([1; 32]),
+                Pubkey::new_from_array([4; 32])
+            ]
+        );
+
+        // SPL Token does not support batching.
+        let mut plan = InstructionPlan::new(PlanConfig::default());
+        for recipient in recipients {
+            plan.extend_independent(
+                transfer_to_ata_plan(&transfer_input(recipient), PlanConfig::default()).unwrap(),
+            )
+            .unwrap();
+        }
+        let instructions = plan.instructions().unwrap();
+        assert_eq!(instructions.len(), 4);
+        assert_eq!(instructions[2].program_id, spl_token_interface::id());
+        assert_eq!(instructions[3].program_id, spl_token_interface::id());
+    }
+
+    #[test]
+    fn test_extend_keeps_plan_order() {
+        let mint = Pubkey::new_unique();
+        let mint_authority = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut plan = create_mint_plan(
+            &CreateMintInput {
+                payer,
+                new_mint: mint,
+                decimals: 0,
+                mint_authority,
+                freeze_authority: None,
+                mint_account_lamports: 1_461_600,
+            },
+            p_token(),
+        )
+        .unwrap();
+        plan.extend(
+            mint_to_ata_plan(
+                &MintToAtaInput {
+                    payer,
+                    ata: Pubkey::new_unique(),
+                    owner: Pubkey::new_unique(),
+                    mint,
+                    mint_authority,
+                    amount: 10,
+                    decimals: 0,
+                    multi_signers: Vec::new(),
+                },
+                p_token(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        // The mint is initialized before the associated token account is
+        // created, and the dependent token instructions are not batched.
+        let instructions = plan.instructions().unwrap();
+        assert_eq!(instructions.len(), 4);
+        assert_eq!(
+            instructions[0].program_id,
+            solana_system_interface::program::ID
+        );
+        assert_eq!(decode(&instructions[1]).unwrap().name, "initializeMint2");
+        assert_eq!(instructions[2].program_id, crate::associated_token::ID);
+        assert_eq!(decode(&instructions[3]).unwrap().name, "mintToChecked");
+        assert_eq!(plan.signers(), vec![payer, mint, mint_authority]);
+    }
+
+    #[test]
+    fn test_extend_with_mismatched_config() {
+        let other = transfer_to_ata_plan(&transfer_input(Pubkey::new_unique()), p_token()).unwrap();
+
+        let mut plan = InstructionPlan::new(PlanConfig::default());
+        assert_eq!(
+            plan.extend(other.clone()),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            plan.extend_independent(other.clone()),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        let mut plan = InstructionPlan::new(PlanConfig {
+            token_program_id: Pubkey::new_unique(),
+            ..p_token()
+        });
+        assert_eq!(plan.extend(other), Err(ProgramError::InvalidArgument));
+        assert!(plan.instructions().unwrap().is_empty());
+    }
+}
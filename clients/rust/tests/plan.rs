@@ -0,0 +1,104 @@
+use {
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest},
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    spl_token_client::{
+        associated_token::get_associated_token_address_with_program_id,
+        plan::{create_mint_plan, mint_to_ata_plan, CreateMintInput, MintToAtaInput, PlanConfig},
+    },
+    spl_token_interface::state::{Account, Mint},
+};
+
+#[tokio::test]
+async fn create_mint_and_mint_to_ata() {
+    let context = ProgramTest::default().start_with_context().await;
+    let config = PlanConfig::default();
+
+    // Given a plan creating a mint, followed by a plan minting tokens to an
+    // associated token account of the new mint.
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+    let owner = Keypair::new();
+    let ata = get_associated_token_address_with_program_id(
+        &owner.pubkey(),
+        &mint.pubkey(),
+        &config.token_program_id,
+    );
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut plan = create_mint_plan(
+        &CreateMintInput {
+            payer: context.payer.pubkey(),
+            new_mint: mint.pubkey(),
+            decimals: 6,
+            mint_authority: mint_authority.pubkey(),
+            freeze_authority: None,
+            mint_account_lamports: rent.minimum_balance(Mint::LEN),
+        },
+        config,
+    )
+    .unwrap();
+    plan.extend(
+        mint_to_ata_plan(
+            &MintToAtaInput {
+                payer: context.payer.pubkey(),
+                ata,
+                owner: owner.pubkey(),
+                mint: mint.pubkey(),
+                mint_authority: mint_authority.pubkey(),
+                amount: 1_000,
+                decimals: 6,
+                multi_signers: Vec::new(),
+            },
+            config,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        plan.signers(),
+        vec![
+            context.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority.pubkey()
+        ]
+    );
+
+    // When the plan is sent in a single transaction.
+
+    let tx = Transaction::new_signed_with_payer(
+        &plan.instructions().unwrap(),
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint, &mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the mint and the associated token account are created and the
+    // tokens are minted.
+
+    let account = context
+        .banks_client
+        .get_account(ata)
+        .await
+        .unwrap()
+        .unwrap();
+    let account = Account::unpack(&account.data).unwrap();
+    assert_eq!(account.mint, mint.pubkey());
+    assert_eq!(account.owner, owner.pubkey());
+    assert_eq!(account.amount, 1_000);
+
+    let account = context
+        .banks_client
+        .get_account(mint.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let mint = Mint::unpack(&account.data).unwrap();
+    assert_eq!(mint.supply, 1_000);
+    assert_eq!(mint.decimals, 6);
+}
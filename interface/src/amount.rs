@@ -0,0 +1,265 @@
+//! Exact token amounts.
+
+use {
+    solana_program_error::ProgramError,
+    spl_token_ui_amount::{
+        format_ui_amount, format_ui_amount_trimmed, parse_ui_amount, MAX_UI_AMOUNT_LEN,
+    },
+    std::{fmt, str::FromStr},
+};
+
+/// A raw token amount together with the decimals of its mint.
+///
+/// Conversions to and from the UI representation are exact and produce the
+/// same results as the `AmountToUiAmount` and `UiAmountToAmount`
+/// instructions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TokenAmount {
+    /// The amount in base units.
+    pub raw: u64,
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    /// Creates a token amount from its raw value.
+    pub const fn new(raw: u64, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Parses the UI representation of a token amount using the given
+    /// decimals.
+    ///
+    /// This accepts the same input as the `UiAmountToAmount` instruction:
+    /// trailing zeros after the decimal point are ignored, and any other
+    /// fractional digit beyond the decimals of the mint is rejected.
+    pub fn from_ui_amount_str(ui_amount: &str, decimals: u8) -> Result<Self, ProgramError> {
+        parse_ui_amount(ui_amount, decimals)
+            .map(|raw| Self::new(raw, decimals))
+            .ok_or(ProgramError::InvalidArgument)
+    }
+
+    /// Returns the UI representation of the amount, with exactly `decimals`
+    /// fractional digits.
+    pub fn to_ui_amount_string(&self) -> String {
+        let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+        format_ui_amount(self.raw, self.decimals, &mut buffer).to_string()
+    }
+
+    /// Returns the UI representation of the amount, trimming excess zeroes
+    /// and unneeded decimal point.
+    ///
+    /// This is the value returned by the `AmountToUiAmount` instruction.
+    pub fn to_ui_amount_string_trimmed(&self) -> String {
+        let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+        format_ui_amount_trimmed(self.raw, self.decimals, &mut buffer).to_string()
+    }
+
+    /// Adds two amounts with the same decimals, returning `None` on overflow
+    /// or if the decimals differ.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        Some(Self::new(self.raw.checked_add(other.raw)?, self.decimals))
+    }
+
+    /// Subtracts two amounts with the same decimals, returning `None` on
+    /// underflow or if the decimals differ.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        Some(Self::new(self.raw.checked_sub(other.raw)?, self.decimals))
+    }
+
+    /// Multiplies the amount by a scalar, returning `None` on overflow.
+    pub fn checked_mul(self, factor: u64) -> Option<Self> {
+        Some(Self::new(self.raw.checked_mul(factor)?, self.decimals))
+    }
+
+    /// Converts the amount to the given decimals, returning `None` if the
+    /// value cannot be represented exactly.
+    pub fn rescale(self, decimals: u8) -> Option<Self> {
+        let rescaled = self.rescale_floor(decimals)?;
+        (rescaled.rescale_floor(self.decimals)? == self).then_some(rescaled)
+    }
+
+    /// Converts the amount to the given decimals, rounding down when
+    /// decimals are removed and returning `None` on overflow.
+    pub fn rescale_floor(self, decimals: u8) -> Option<Self> {
+        let raw = if decimals >= self.decimals {
+            match 10u64.checked_pow((decimals - self.decimals) as u32) {
+                Some(factor) => self.raw.checked_mul(factor)?,
+                None if self.raw == 0 => 0,
+                None => return None,
+            }
+        } else {
+            match 10u64.checked_pow((self.decimals - decimals) as u32) {
+                Some(divisor) => self.raw / divisor,
+                // the divisor is larger than any amount
+                None => 0,
+            }
+        };
+        Some(Self::new(raw, decimals))
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    /// Formats the amount with exactly `decimals` fractional digits, so it
+    /// can be parsed back into the same amount.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_ui_amount_string())
+    }
+}
+
+impl FromStr for TokenAmount {
+    type Err = ProgramError;
+
+    /// Parses a UI amount, using the number of fractional digits as the
+    /// decimals.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decimals = s.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+        let decimals = u8::try_from(decimals).map_err(|_| ProgramError::InvalidArgument)?;
+        Self::from_ui_amount_str(s, decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, proptest::prelude::*};
+
+    #[test]
+    fn test_ui_amount_string() {
+        let cases = [
+            (0, 0, "0", "0"),
+            (0, 6, "0.000000", "0"),
+            (1, 6, "0.000001", "0.000001"),
+            (1_500_000, 6, "1.500000", "1.5"),
+            (100, 2, "1.00", "1"),
+            (u64::MAX, 0, "18446744073709551615", "18446744073709551615"),
+            (
+                u64::MAX,
+                19,
+                "1.8446744073709551615",
+                "1.8446744073709551615",
+            ),
+            (
+                u64::MAX,
+                20,
+                "0.18446744073709551615",
+                "0.18446744073709551615",
+            ),
+        ];
+        for (raw, decimals, untrimmed, trimmed) in cases {
+            let amount = TokenAmount::new(raw, decimals);
+            assert_eq!(amount.to_ui_amount_string(), untrimmed);
+            assert_eq!(amount.to_ui_amount_string_trimmed(), trimmed);
+            assert_eq!(amount.to_string(), untrimmed);
+        }
+    }
+
+    #[test]
+    fn test_from_ui_amount_str() {
+        let parse = TokenAmount::from_ui_amount_str;
+        assert_eq!(parse("1.5", 6), Ok(TokenAmount::new(1_500_000, 6)));
+        assert_eq!(parse(".5", 1), Ok(TokenAmount::new(5, 1)));
+        assert_eq!(parse("5.", 1), Ok(TokenAmount::new(50, 1)));
+        assert_eq!(parse("1.2300", 2), Ok(TokenAmount::new(123, 2)));
+        assert_eq!(parse("0", 255), Ok(TokenAmount::new(0, 255)));
+        assert_eq!(
+            parse("18446744073709551615", 0),
+            Ok(TokenAmount::new(u64::MAX, 0))
+        );
+        // Exceeds u64::MAX, which f64 conversions silently round.
+        assert_eq!(
+            parse("18446744073709551616", 0),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            parse("9007199254740993", 0),
+            Ok(TokenAmount::new(9_007_199_254_740_993, 0))
+        );
+        assert_eq!(parse("1.234", 2), Err(ProgramError::InvalidArgument));
+        assert_eq!(parse("", 2), Err(ProgramError::InvalidArgument));
+        assert_eq!(parse(".", 2), Err(ProgramError::InvalidArgument));
+        assert_eq!(parse("1.2.3", 2), Err(ProgramError::InvalidArgument));
+        assert_eq!(parse("-1", 2), Err(ProgramError::InvalidArgument));
+        assert_eq!(parse("1", 20), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1.50".parse(), Ok(TokenAmount::new(150, 2)));
+        assert_eq!("150".parse(), Ok(TokenAmount::new(150, 0)));
+        assert_eq!("1.".parse(), Ok(TokenAmount::new(1, 0)));
+        assert_eq!(
+            "abc".parse::<TokenAmount>(),
+            Err(ProgramError::InvalidArgument)
+        );
+        let long = format!("0.{}", "0".repeat(256));
+        assert_eq!(
+            long.parse::<TokenAmount>(),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = TokenAmount::new(150, 2);
+        let b = TokenAmount::new(50, 2);
+        assert_eq!(a.checked_add(b), Some(TokenAmount::new(200, 2)));
+        assert_eq!(a.checked_sub(b), Some(TokenAmount::new(100, 2)));
+        assert_eq!(b.checked_sub(a), None);
+        assert_eq!(a.checked_mul(3), Some(TokenAmount::new(450, 2)));
+        assert_eq!(TokenAmount::new(u64::MAX, 2).checked_add(b), None);
+        assert_eq!(TokenAmount::new(u64::MAX, 2).checked_mul(2), None);
+        assert_eq!(a.checked_add(TokenAmount::new(1, 3)), None);
+        assert_eq!(a.checked_sub(TokenAmount::new(1, 3)), None);
+    }
+
+    #[test]
+    fn test_rescale() {
+        let amount = TokenAmount::new(1_500, 3);
+        assert_eq!(amount.rescale(6), Some(TokenAmount::new(1_500_000, 6)));
+        assert_eq!(amount.rescale(2), Some(TokenAmount::new(150, 2)));
+        assert_eq!(amount.rescale(0), None);
+        assert_eq!(amount.rescale_floor(0), Some(TokenAmount::new(1, 0)));
+        assert_eq!(amount.rescale(3), Some(amount));
+        assert_eq!(amount.rescale(255), None);
+        assert_eq!(amount.rescale_floor(255), None);
+        assert_eq!(
+            TokenAmount::new(0, 0).rescale(255),
+            Some(TokenAmount::new(0, 255))
+        );
+        assert_eq!(
+            TokenAmount::new(u64::MAX, 255).rescale_floor(0),
+            Some(TokenAmount::new(0, 0))
+        );
+        assert_eq!(TokenAmount::new(u64::MAX, 255).rescale(0), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_roundtrip(raw in any::<u64>(), decimals in any::<u8>()) {
+            let amount = TokenAmount::new(raw, decimals);
+            prop_assert_eq!(amount.to_string().parse::<TokenAmount>(), Ok(amount));
+            prop_assert_eq!(
+                TokenAmount::from_ui_amount_str(&amount.to_ui_amount_string_trimmed(), decimals),
+                Ok(amount)
+            );
+        }
+
+        #[test]
+        fn test_rescale_roundtrip(raw in any::<u64>(), from in 0u8..=20, to in 0u8..=20) {
+            let amount = TokenAmount::new(raw, from);
+            if let Some(rescaled) = amount.rescale(to) {
+                prop_assert_eq!(rescaled.rescale(from), Some(amount));
+                prop_assert_eq!(
+                    rescaled.to_ui_amount_string_trimmed(),
+                    amount.to_ui_amount_string_trimmed()
+                );
+            }
+        }
+    }
+}
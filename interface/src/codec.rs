@@ -0,0 +1,496 @@
+//! Borsh encoding of token instructions.
+//!
+//! The encoding follows the types of the program IDL used to generate the
+//! Codama clients: `COption` arguments are encoded as Borsh `Option` values
+//! with a `u8` prefix and `UiAmountToAmount` strings take the remainder of
+//! the instruction data, without a length prefix. The `u16` extension types
+//! of `GetAccountDataSizeForKind` also take the remainder of the instruction
+//! data. The output is identical to [`TokenInstruction::pack`], and [`decode`]
+//! accepts exactly the same inputs as [`TokenInstruction::unpack`].
+
+use {
+    crate::{
+        error::TokenError,
+        instruction::{AccountKind, AuthorityType, TokenInstruction},
+    },
+    borsh::{
+        io::{Result as IoResult, Write},
+        BorshDeserialize, BorshSerialize,
+    },
+    solana_program_error::ProgramError,
+    solana_pubkey::Pubkey,
+};
+
+impl BorshSerialize for TokenInstruction<'_> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        use TokenInstruction::*;
+
+        match self {
+            InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                0u8.serialize(writer)?;
+                decimals.serialize(writer)?;
+                mint_authority.serialize(writer)?;
+                Option::<Pubkey>::from(*freeze_authority).serialize(writer)
+            }
+            InitializeAccount => 1u8.serialize(writer),
+            InitializeMultisig { m } => (2u8, m).serialize(writer),
+            Transfer { amount } => (3u8, amount).serialize(writer),
+            Approve { amount } => (4u8, amount).serialize(writer),
+            Revoke => 5u8.serialize(writer),
+            SetAuthority {
+                authority_type,
+                new_authority,
+            } => {
+                6u8.serialize(writer)?;
+                authority_type.serialize(writer)?;
+                Option::<Pubkey>::from(*new_authority).serialize(writer)
+            }
+            MintTo { amount } => (7u8, amount).serialize(writer),
+            Burn { amount } => (8u8, amount).serialize(writer),
+            CloseAccount => 9u8.serialize(writer),
+            FreezeAccount => 10u8.serialize(writer),
+            ThawAccount => 11u8.serialize(writer),
+            TransferChecked { amount, decimals } => (12u8, amount, decimals).serialize(writer),
+            ApproveChecked { amount, decimals } => (13u8, amount, decimals).serialize(writer),
+            MintToChecked { amount, decimals } => (14u8, amount, decimals).serialize(writer),
+            BurnChecked { amount, decimals } => (15u8, amount, decimals).serialize(writer),
+            InitializeAccount2 { owner } => (16u8, owner).serialize(writer),
+            SyncNative => 17u8.serialize(writer),
+            InitializeAccount3 { owner } => (18u8, owner).serialize(writer),
+            InitializeMultisig2 { m } => (19u8, m).serialize(writer),
+            InitializeMint2 {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                20u8.serialize(writer)?;
+                decimals.serialize(writer)?;
+                mint_authority.serialize(writer)?;
+                Option::<Pubkey>::from(*freeze_authority).serialize(writer)
+            }
+            GetAccountDataSize => 21u8.serialize(writer),
+            InitializeImmutableOwner => 22u8.serialize(writer),
+            AmountToUiAmount { amount } => (23u8, amount).serialize(writer),
+            UiAmountToAmount { ui_amount } => {
+                24u8.serialize(writer)?;
+                writer.write_all(ui_amount.as_bytes())
+            }
+            WithdrawExcessLamports => 38u8.serialize(writer),
+            UnwrapLamports { amount } => (45u8, Option::<u64>::from(*amount)).serialize(writer),
+            SweepAndClose { burn } => (46u8, burn).serialize(writer),
+            BurnAll => 47u8.serialize(writer),
+            TransferAll => 48u8.serialize(writer),
+            TransferAllChecked { decimals } => (49u8, decimals).serialize(writer),
+            ApproveIfCurrent {
+                expected_delegate,
+                expected_amount,
+                new_amount,
+            } => (
+                50u8,
+                Option::<Pubkey>::from(*expected_delegate),
+                expected_amount,
+                new_amount,
+            )
+                .serialize(writer),
+            IncreaseAllowance { amount } => (51u8, amount).serialize(writer),
+            DecreaseAllowance { amount } => (52u8, amount).serialize(writer),
+            InitializePermitNonce => 53u8.serialize(writer),
+            ApproveWithPermit {
+                amount,
+                nonce,
+                expiry,
+            } => (54u8, amount, nonce, expiry).serialize(writer),
+            GetMinimumBalance { kind } => (55u8, kind).serialize(writer),
+            CreateAndInitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                56u8.serialize(writer)?;
+                decimals.serialize(writer)?;
+                mint_authority.serialize(writer)?;
+                Option::<Pubkey>::from(*freeze_authority).serialize(writer)
+            }
+            CreateAndInitializeAccount { owner } => (57u8, owner).serialize(writer),
+            InitializeAccountIdempotent { owner } => (58u8, owner).serialize(writer),
+            SweepExcessLamports { source_count } => (59u8, source_count).serialize(writer),
+            InitializeClawbackConfig { authority } => (60u8, authority).serialize(writer),
+            ClawbackTransfer { amount } => (61u8, amount).serialize(writer),
+            ClawbackBurn { amount } => (62u8, amount).serialize(writer),
+            GetAccountDataSizeForKind {
+                kind,
+                extension_types,
+            } => {
+                (63u8, kind).serialize(writer)?;
+                extension_types
+                    .iter()
+                    .try_for_each(|extension_type| extension_type.serialize(writer))
+            }
+            WithdrawExcessNativeLamports => 64u8.serialize(writer),
+            Batch => 255u8.serialize(writer),
+        }
+    }
+}
+
+/// Decodes a [`TokenInstruction`] from its Borsh encoding.
+///
+/// As with [`TokenInstruction::unpack`], any bytes following the encoded
+/// instruction arguments are ignored.
+pub fn decode(input: &[u8]) -> Result<TokenInstruction<'_>, ProgramError> {
+    use TokenInstruction::*;
+
+    let (&discriminator, rest) = input.split_first().ok_or(TokenError::InvalidInstruction)?;
+    let input = &mut &*rest;
+
+    Ok(match discriminator {
+        0 => InitializeMint {
+            decimals: read(input)?,
+            mint_authority: read(input)?,
+            freeze_authority: read::<Option<Pubkey>>(input)?.into(),
+        },
+        1 => InitializeAccount,
+        2 => InitializeMultisig { m: read(input)? },
+        3 => Transfer {
+            amount: read(input)?,
+        },
+        4 => Approve {
+            amount: read(input)?,
+        },
+        5 => Revoke,
+        6 => SetAuthority {
+            authority_type: read(input)?,
+            new_authority: read::<Option<Pubkey>>(input)?.into(),
+        },
+        7 => MintTo {
+            amount: read(input)?,
+        },
+        8 => Burn {
+            amount: read(input)?,
+        },
+        9 => CloseAccount,
+        10 => FreezeAccount,
+        11 => ThawAccount,
+        12 => TransferChecked {
+            amount: read(input)?,
+            decimals: read(input)?,
+        },
+        13 => ApproveChecked {
+            amount: read(input)?,
+            decimals: read(input)?,
+        },
+        14 => MintToChecked {
+            amount: read(input)?,
+            decimals: read(input)?,
+        },
+        15 => BurnChecked {
+            amount: read(input)?,
+            decimals: read(input)?,
+        },
+        16 => InitializeAccount2 {
+            owner: read(input)?,
+        },
+        17 => SyncNative,
+        18 => InitializeAccount3 {
+            owner: read(input)?,
+        },
+        19 => InitializeMultisig2 { m: read(input)? },
+        20 => InitializeMint2 {
+            decimals: read(input)?,
+            mint_authority: read(input)?,
+            freeze_authority: read::<Option<Pubkey>>(input)?.into(),
+        },
+        21 => GetAccountDataSize,
+        22 => InitializeImmutableOwner,
+        23 => AmountToUiAmount {
+            amount: read(input)?,
+        },
+        24 => UiAmountToAmount {
+            ui_amount: std::str::from_utf8(rest).map_err(|_| TokenError::InvalidInstruction)?,
+        },
+        38 => WithdrawExcessLamports,
+        45 => UnwrapLamports {
+            amount: read::<Option<u64>>(input)?.into(),
+        },
+        46 => SweepAndClose { burn: read(input)? },
+        47 => BurnAll,
+        48 => TransferAll,
+        49 => TransferAllChecked {
+            decimals: read(input)?,
+        },
+        50 => ApproveIfCurrent {
+            expected_delegate: read::<Option<Pubkey>>(input)?.into(),
+            expected_amount: read(input)?,
+            new_amount: read(input)?,
+        },
+        51 => IncreaseAllowance {
+            amount: read(input)?,
+        },
+        52 => DecreaseAllowance {
+            amount: read(input)?,
+        },
+        53 => InitializePermitNonce,
+        54 => ApproveWithPermit {
+            amount: read(input)?,
+            nonce: read(input)?,
+            expiry: read(input)?,
+        },
+        55 => GetMinimumBalance { kind: read(input)? },
+        56 => CreateAndInitializeMint {
+            decimals: read(input)?,
+            mint_authority: read(input)?,
+            freeze_authority: read::<Option<Pubkey>>(input)?.into(),
+        },
+        57 => CreateAndInitializeAccount {
+            owner: read(input)?,
+        },
+        58 => InitializeAccountIdempotent {
+            owner: read(input)?,
+        },
+        59 => SweepExcessLamports {
+            source_count: read(input)?,
+        },
+        60 => InitializeClawbackConfig {
+            authority: read(input)?,
+        },
+        61 => ClawbackTransfer {
+            amount: read(input)?,
+        },
+        62 => ClawbackBurn {
+            amount: read(input)?,
+        },
+        63 => {
+            let kind = read(input)?;
+            if input.len() % 2 != 0 {
+                return Err(TokenError::InvalidInstruction.into());
+            }
+            let mut extension_types = Vec::new();
+            while !input.is_empty() {
+                extension_types.push(read(input)?);
+            }
+            GetAccountDataSizeForKind {
+                kind,
+                extension_types,
+            }
+        }
+        64 => WithdrawExcessNativeLamports,
+        255 => Batch,
+        _ => return Err(TokenError::InvalidInstruction.into()),
+    })
+}
+
+fn read<T: BorshDeserialize>(input: &mut &[u8]) -> Result<T, ProgramError> {
+    T::deserialize(input).map_err(|_| TokenError::InvalidInstruction.into())
+}
+
+impl BorshSerialize for AuthorityType {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        self.into().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for AuthorityType {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> IoResult<Self> {
+        AuthorityType::from(u8::deserialize_reader(reader)?).map_err(|_| {
+            borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, "invalid authority type")
+        })
+    }
+}
+
+impl BorshSerialize for AccountKind {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        self.into().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for AccountKind {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> IoResult<Self> {
+        AccountKind::from(u8::deserialize_reader(reader)?).map_err(|_| {
+            borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, "invalid account kind")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, proptest::prelude::*, solana_program_option::COption};
+
+    fn pubkey() -> impl Strategy<Value = Pubkey> {
+        any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+    }
+
+    fn pubkey_option() -> impl Strategy<Value = COption<Pubkey>> {
+        proptest::option::of(pubkey()).prop_map(COption::from)
+    }
+
+    fn authority_type() -> impl Strategy<Value = AuthorityType> {
+        (0u8..4).prop_map(|index| AuthorityType::from(index).unwrap())
+    }
+
+    fn account_kind() -> impl Strategy<Value = AccountKind> {
+        (0u8..3).prop_map(|index| AccountKind::from(index).unwrap())
+    }
+
+    fn instruction() -> impl Strategy<Value = TokenInstruction<'static>> {
+        use TokenInstruction::*;
+
+        prop_oneof![
+            (any::<u8>(), pubkey(), pubkey_option()).prop_map(
+                |(decimals, mint_authority, freeze_authority)| InitializeMint {
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                }
+            ),
+            Just(InitializeAccount),
+            any::<u8>().prop_map(|m| InitializeMultisig { m }),
+            any::<u64>().prop_map(|amount| Transfer { amount }),
+            any::<u64>().prop_map(|amount| Approve { amount }),
+            Just(Revoke),
+            (authority_type(), pubkey_option()).prop_map(|(authority_type, new_authority)| {
+                SetAuthority {
+                    authority_type,
+                    new_authority,
+                }
+            }),
+            any::<u64>().prop_map(|amount| MintTo { amount }),
+            any::<u64>().prop_map(|amount| Burn { amount }),
+            Just(CloseAccount),
+            Just(FreezeAccount),
+            Just(ThawAccount),
+            (any::<u64>(), any::<u8>())
+                .prop_map(|(amount, decimals)| TransferChecked { amount, decimals }),
+            (any::<u64>(), any::<u8>())
+                .prop_map(|(amount, decimals)| ApproveChecked { amount, decimals }),
+            (any::<u64>(), any::<u8>())
+                .prop_map(|(amount, decimals)| MintToChecked { amount, decimals }),
+            (any::<u64>(), any::<u8>())
+                .prop_map(|(amount, decimals)| BurnChecked { amount, decimals }),
+            pubkey().prop_map(|owner| InitializeAccount2 { owner }),
+            Just(SyncNative),
+            pubkey().prop_map(|owner| InitializeAccount3 { owner }),
+            any::<u8>().prop_map(|m| InitializeMultisig2 { m }),
+            (any::<u8>(), pubkey(), pubkey_option()).prop_map(
+                |(decimals, mint_authority, freeze_authority)| InitializeMint2 {
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                }
+            ),
+            Just(GetAccountDataSize),
+            Just(InitializeImmutableOwner),
+            any::<u64>().prop_map(|amount| AmountToUiAmount { amount }),
+            ".*".prop_map(|ui_amount| UiAmountToAmount {
+                ui_amount: Box::leak(ui_amount.into_boxed_str()),
+            }),
+            Just(WithdrawExcessLamports),
+            proptest::option::of(any::<u64>()).prop_map(|amount| UnwrapLamports {
+                amount: amount.into()
+            }),
+            any::<bool>().prop_map(|burn| SweepAndClose { burn }),
+            Just(BurnAll),
+            Just(TransferAll),
+            any::<u8>().prop_map(|decimals| TransferAllChecked { decimals }),
+            (pubkey_option(), any::<u64>(), any::<u64>()).prop_map(
+                |(expected_delegate, expected_amount, new_amount)| ApproveIfCurrent {
+                    expected_delegate,
+                    expected_amount,
+                    new_amount,
+                }
+            ),
+            any::<u64>().prop_map(|amount| IncreaseAllowance { amount }),
+            any::<u64>().prop_map(|amount| DecreaseAllowance { amount }),
+            Just(InitializePermitNonce),
+            (any::<u64>(), any::<u64>(), any::<i64>()).prop_map(|(amount, nonce, expiry)| {
+                ApproveWithPermit {
+                    amount,
+                    nonce,
+                    expiry,
+                }
+            }),
+            account_kind().prop_map(|kind| GetMinimumBalance { kind }),
+            (any::<u8>(), pubkey(), pubkey_option()).prop_map(
+                |(decimals, mint_authority, freeze_authority)| CreateAndInitializeMint {
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                }
+            ),
+            pubkey().prop_map(|owner| CreateAndInitializeAccount { owner }),
+            pubkey().prop_map(|owner| InitializeAccountIdempotent { owner }),
+            any::<u8>().prop_map(|source_count| SweepExcessLamports { source_count }),
+            pubkey().prop_map(|authority| InitializeClawbackConfig { authority }),
+            any::<u64>().prop_map(|amount| ClawbackTransfer { amount }),
+            any::<u64>().prop_map(|amount| ClawbackBurn { amount }),
+            (
+                account_kind(),
+                proptest::collection::vec(any::<u16>(), 0..4)
+            )
+                .prop_map(|(kind, extension_types)| GetAccountDataSizeForKind {
+                    kind,
+                    extension_types,
+                }),
+            Just(WithdrawExcessNativeLamports),
+            Just(Batch),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_encoding_matches_pack(
+            instruction in instruction(),
+            trailing in proptest::collection::vec(any::<u8>(), 0..8),
+        ) {
+            let packed = instruction.pack();
+            prop_assert_eq!(&borsh::to_vec(&instruction).unwrap(), &packed);
+            prop_assert_eq!(decode(&packed), Ok(instruction.clone()));
+
+            // Trailing bytes are ignored by both, except in strings and
+            // extension types which take the remainder of the data.
+            let mut extended = packed;
+            extended.extend_from_slice(&trailing);
+            prop_assert_eq!(decode(&extended), TokenInstruction::unpack(&extended));
+        }
+
+        #[test]
+        fn test_decode_matches_unpack(data in proptest::collection::vec(any::<u8>(), 0..100)) {
+            prop_assert_eq!(decode(&data), TokenInstruction::unpack(&data));
+        }
+
+        #[test]
+        fn test_decode_matches_unpack_for_each_discriminator(
+            discriminator in any::<u8>(),
+            data in proptest::collection::vec(any::<u8>(), 0..80),
+        ) {
+            let mut input = vec![discriminator];
+            input.extend_from_slice(&data);
+            prop_assert_eq!(decode(&input), TokenInstruction::unpack(&input));
+        }
+    }
+
+    #[test]
+    fn test_coption_encoding() {
+        let none = TokenInstruction::SetAuthority {
+            authority_type: AuthorityType::CloseAccount,
+            new_authority: COption::None,
+        };
+        assert_eq!(borsh::to_vec(&none).unwrap(), vec![6, 3, 0]);
+
+        let some = TokenInstruction::UnwrapLamports {
+            amount: COption::Some(1),
+        };
+        assert_eq!(
+            borsh::to_vec(&some).unwrap(),
+            vec![45, 1, 1, 0, 0, 0, 0, 0, 0, 0]
+        );
+
+        // Option tags other than 0 and 1 are rejected.
+        assert_eq!(
+            decode(&[45, 2, 1, 0, 0, 0, 0, 0, 0, 0]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+    }
+}
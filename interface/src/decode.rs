@@ -0,0 +1,795 @@
+//! Human-readable decoding of token instructions.
+//!
+//! Maps the raw instruction data and account metas of a token instruction to
+//! its [`TokenInstruction`] variant, labeling each account with the name used
+//! in the program IDL. `Batch` instructions are expanded recursively into
+//! their inner instructions.
+//!
+//! With the `serde` feature, decoded instructions can be serialized to JSON.
+
+#[cfg(feature = "serde")]
+use {
+    crate::{
+        amount::TokenAmount,
+        instruction::{AccountKind, AuthorityType},
+    },
+    solana_program_option::COption,
+};
+use {
+    crate::{check_program_account, error::TokenError, instruction::TokenInstruction},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_error::ProgramError,
+    solana_pubkey::Pubkey,
+};
+
+/// An account of a decoded instruction, labeled with its role.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedAccount {
+    /// The name of the account in the instruction.
+    pub label: &'static str,
+    /// The address of the account.
+    pub pubkey: Pubkey,
+    /// Whether the account signed the instruction.
+    pub is_signer: bool,
+    /// Whether the account is writable.
+    pub is_writable: bool,
+}
+
+/// A decoded token instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedInstruction<'a> {
+    /// The name of the instruction.
+    pub name: &'static str,
+    /// The unpacked instruction data.
+    pub instruction: TokenInstruction<'a>,
+    /// The labeled accounts of the instruction.
+    pub accounts: Vec<DecodedAccount>,
+    /// The signer accounts following the labeled accounts, either the signers
+    /// of a multisignature authority or the signers of a multisignature
+    /// account being initialized.
+    pub signers: Vec<Pubkey>,
+    /// The inner instructions of a `Batch` instruction.
+    pub instructions: Vec<DecodedInstruction<'a>>,
+}
+
+/// Decodes a token instruction.
+pub fn decode(instruction: &Instruction) -> Result<DecodedInstruction<'_>, ProgramError> {
+    check_program_account(&instruction.program_id)?;
+    decode_instruction(&instruction.data, &instruction.accounts)
+}
+
+/// Decodes a token instruction from its data and account metas.
+pub fn decode_instruction<'a>(
+    data: &'a [u8],
+    accounts: &[AccountMeta],
+) -> Result<DecodedInstruction<'a>, ProgramError> {
+    let instruction = TokenInstruction::unpack(data)?;
+    let layout = Layout::of(&instruction);
+
+    if let TokenInstruction::Batch = instruction {
+        return Ok(DecodedInstruction {
+            name: layout.name,
+            instruction,
+            accounts: Vec::new(),
+            signers: Vec::new(),
+            instructions: decode_batch(&data[1..], accounts)?,
+        });
+    }
+
+    if accounts.len() < layout.accounts.len() - layout.optional + layout.repeated {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let repeated = layout
+        .accounts
+        .last()
+        .into_iter()
+        .cycle()
+        .take(layout.repeated);
+    let decoded_accounts = layout
+        .accounts
+        .iter()
+        .chain(repeated)
+        .zip(accounts)
+        .map(|(label, meta)| DecodedAccount {
+            label,
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect::<Vec<_>>();
+
+    let signers = if layout.signers {
+        accounts[decoded_accounts.len()..]
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok(DecodedInstruction {
+        name: layout.name,
+        instruction,
+        accounts: decoded_accounts,
+        signers,
+        instructions: Vec::new(),
+    })
+}
+
+/// Decodes the inner instructions of a `Batch` instruction, where each
+/// instruction is prefixed by its number of accounts and data length.
+fn decode_batch<'a>(
+    mut data: &'a [u8],
+    mut accounts: &[AccountMeta],
+) -> Result<Vec<DecodedInstruction<'a>>, ProgramError> {
+    // An empty batch is rejected by the program.
+    if data.is_empty() {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
+    let mut instructions = Vec::new();
+
+    while let [account_count, data_len, rest @ ..] = data {
+        let (account_count, data_len) = (*account_count as usize, *data_len as usize);
+
+        if rest.len() < data_len {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if accounts.len() < account_count {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let (instruction_data, rest) = rest.split_at(data_len);
+        let (instruction_accounts, remaining) = accounts.split_at(account_count);
+
+        instructions.push(decode_instruction(instruction_data, instruction_accounts)?);
+
+        data = rest;
+        accounts = remaining;
+    }
+
+    if !data.is_empty() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(instructions)
+}
+
+/// The name and account labels of an instruction.
+struct Layout {
+    name: &'static str,
+    accounts: &'static [&'static str],
+    /// Number of trailing accounts in `accounts` that are optional.
+    optional: usize,
+    /// Number of additional accounts labeled as the last of `accounts`.
+    repeated: usize,
+    /// Whether remaining accounts are signers.
+    signers: bool,
+}
+
+impl Layout {
+    const fn new(name: &'static str, accounts: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            accounts,
+            optional: 0,
+            repeated: 0,
+            signers: false,
+        }
+    }
+
+    const fn with_signers(name: &'static str, accounts: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            accounts,
+            optional: 0,
+            repeated: 0,
+            signers: true,
+        }
+    }
+
+    fn of(instruction: &TokenInstruction) -> Self {
+        use TokenInstruction::*;
+
+        match instruction {
+            InitializeMint { .. } => Self::new("initializeMint", &["mint", "rent"]),
+            InitializeAccount => {
+                Self::new("initializeAccount", &["account", "mint", "owner", "rent"])
+            }
+            InitializeMultisig { .. } => {
+                Self::with_signers("initializeMultisig", &["multisig", "rent"])
+            }
+            Transfer { .. } => {
+                Self::with_signers("transfer", &["source", "destination", "authority"])
+            }
+            Approve { .. } => Self::with_signers("approve", &["source", "delegate", "owner"]),
+            Revoke => Self::with_signers("revoke", &["source", "owner"]),
+            SetAuthority { .. } => Self::with_signers("setAuthority", &["owned", "owner"]),
+            MintTo { .. } => Self::with_signers("mintTo", &["mint", "token", "mintAuthority"]),
+            Burn { .. } => Self::with_signers("burn", &["account", "mint", "authority"]),
+            CloseAccount => {
+                Self::with_signers("closeAccount", &["account", "destination", "owner"])
+            }
+            FreezeAccount => Self::with_signers("freezeAccount", &["account", "mint", "owner"]),
+            ThawAccount => Self::with_signers("thawAccount", &["account", "mint", "owner"]),
+            TransferChecked { .. } => Self::with_signers(
+                "transferChecked",
+                &["source", "mint", "destination", "authority"],
+            ),
+            ApproveChecked { .. } => {
+                Self::with_signers("approveChecked", &["source", "mint", "delegate", "owner"])
+            }
+            MintToChecked { .. } => {
+                Self::with_signers("mintToChecked", &["mint", "token", "mintAuthority"])
+            }
+            BurnChecked { .. } => {
+                Self::with_signers("burnChecked", &["account", "mint", "authority"])
+            }
+            InitializeAccount2 { .. } => {
+                Self::new("initializeAccount2", &["account", "mint", "rent"])
+            }
+            SyncNative => Self {
+                optional: 1,
+                ..Self::new("syncNative", &["account", "rent"])
+            },
+            InitializeAccount3 { .. } => Self::new("initializeAccount3", &["account", "mint"]),
+            InitializeMultisig2 { .. } => Self::with_signers("initializeMultisig2", &["multisig"]),
+            InitializeMint2 { .. } => Self::new("initializeMint2", &["mint"]),
+            GetAccountDataSize => Self::new("getAccountDataSize", &["mint"]),
+            InitializeImmutableOwner => Self::new("initializeImmutableOwner", &["account"]),
+            AmountToUiAmount { .. } => Self::new("amountToUiAmount", &["mint"]),
+            UiAmountToAmount { .. } => Self::new("uiAmountToAmount", &["mint"]),
+            WithdrawExcessLamports => Self::with_signers(
+                "withdrawExcessLamports",
+                &["source", "destination", "authority"],
+            ),
+            UnwrapLamports { .. } => {
+                Self::with_signers("unwrapLamports", &["source", "destination", "authority"])
+            }
+            SweepAndClose { .. } => Self::with_signers(
+                "sweepAndClose",
+                &["account", "destination", "lamportsDestination", "owner"],
+            ),
+            BurnAll => Self::with_signers("burnAll", &["account", "mint", "authority"]),
+            TransferAll => {
+                Self::with_signers("transferAll", &["source", "destination", "authority"])
+            }
+            TransferAllChecked { .. } => Self::with_signers(
+                "transferAllChecked",
+                &["source", "mint", "destination", "authority"],
+            ),
+            ApproveIfCurrent { .. } => {
+                Self::with_signers("approveIfCurrent", &["source", "delegate", "owner"])
+            }
+            IncreaseAllowance { .. } => {
+                Self::with_signers("increaseAllowance", &["source", "delegate", "owner"])
+            }
+            DecreaseAllowance { .. } => {
+                Self::with_signers("decreaseAllowance", &["source", "delegate", "owner"])
+            }
+            InitializePermitNonce => Self::new("initializePermitNonce", &["permitNonce", "owner"]),
+            ApproveWithPermit { .. } => Self::new(
+                "approveWithPermit",
+                &["source", "delegate", "permitNonce", "instructionsSysvar"],
+            ),
+            GetMinimumBalance { .. } => Self::new("getMinimumBalance", &[]),
+            CreateAndInitializeMint { .. } => Self::new(
+                "createAndInitializeMint",
+                &["mint", "payer", "systemProgram"],
+            ),
+            InitializeAccountIdempotent { .. } => {
+                Self::new("initializeAccountIdempotent", &["account", "mint"])
+            }
+            CreateAndInitializeAccount { .. } => Self::new(
+                "createAndInitializeAccount",
+                &["account", "mint", "payer", "systemProgram"],
+            ),
+            InitializeClawbackConfig { .. } => Self::new(
+                "initializeClawbackConfig",
+                &["clawbackConfig", "mint", "payer", "systemProgram"],
+            ),
+            ClawbackTransfer { .. } => Self::with_signers(
+                "clawbackTransfer",
+                &["source", "destination", "clawbackConfig", "authority"],
+            ),
+            ClawbackBurn { .. } => Self::with_signers(
+                "clawbackBurn",
+                &["account", "mint", "clawbackConfig", "authority"],
+            ),
+            SweepExcessLamports { source_count } => Self {
+                repeated: source_count.saturating_sub(1) as usize,
+                ..Self::with_signers(
+                    "sweepExcessLamports",
+                    &["destination", "authority", "source"],
+                )
+            },
+            GetAccountDataSizeForKind { .. } => Self::new("getAccountDataSizeForKind", &[]),
+            WithdrawExcessNativeLamports => Self::with_signers(
+                "withdrawExcessNativeLamports",
+                &["source", "destination", "owner"],
+            ),
+            Batch => Self::new("batch", &[]),
+        }
+    }
+}
+
+/// A JSON value of an instruction data field.
+#[cfg(feature = "serde")]
+enum Value<'a> {
+    Null,
+    Bool(bool),
+    /// A JSON number, only used for `u8` and `u16` values.
+    Number(u64),
+    /// A JSON string, used for `u64` and `i64` values to avoid losing
+    /// precision.
+    String(String),
+    Str(&'a str),
+    Pubkey(Pubkey),
+    /// A JSON array, used for lists of `u16` values.
+    Array(Vec<Value<'a>>),
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Value<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_none(),
+            Value::Bool(value) => serializer.serialize_bool(*value),
+            Value::Number(value) => serializer.serialize_u64(*value),
+            Value::String(value) => serializer.serialize_str(value),
+            Value::Str(value) => serializer.serialize_str(value),
+            Value::Pubkey(value) => serializer.collect_str(value),
+            Value::Array(values) => serializer.collect_seq(values),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DecodedAccount {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut account = serializer.serialize_struct("DecodedAccount", 3)?;
+        account.serialize_field("address", &Value::Pubkey(self.pubkey))?;
+        account.serialize_field("isSigner", &self.is_signer)?;
+        account.serialize_field("isWritable", &self.is_writable)?;
+        account.end()
+    }
+}
+
+/// The labeled accounts of an instruction, where repeated accounts, such as
+/// the sources of `sweepExcessLamports`, are serialized as an array under
+/// their shared label.
+#[cfg(feature = "serde")]
+struct LabeledAccounts<'a>(&'a [DecodedAccount]);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LabeledAccounts<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        for group in self.0.chunk_by(|a, b| a.label == b.label) {
+            match group {
+                [account] => map.serialize_entry(account.label, account)?,
+                _ => map.serialize_entry(group[0].label, group)?,
+            }
+        }
+        map.end()
+    }
+}
+
+/// The named data fields of an instruction.
+#[cfg(feature = "serde")]
+struct DataFields<'a>(Vec<(&'static str, Value<'a>)>);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DataFields<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(name, value)| (name, value)))
+    }
+}
+
+/// Serializes the instruction in the shape of the parsed instructions of the
+/// JS client: the instruction type, the accounts keyed by label and the
+/// instruction data. Amounts are represented as strings and, when the
+/// instruction includes the decimals of the mint, the UI amount is included
+/// as `uiAmountString`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for DecodedInstruction<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("instructionType", self.name)?;
+        map.serialize_entry("accounts", &LabeledAccounts(&self.accounts))?;
+        map.serialize_entry("data", &DataFields(self.data_fields()))?;
+
+        if Layout::of(&self.instruction).signers {
+            let signers = self.signers.iter().map(|signer| Value::Pubkey(*signer));
+            map.serialize_entry("signers", &Value::Array(signers.collect()))?;
+        }
+
+        if let TokenInstruction::Batch = self.instruction {
+            map.serialize_entry("instructions", &self.instructions)?;
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl DecodedInstruction<'_> {
+    /// Returns the JSON representation of the instruction.
+    ///
+    /// See the [`Serialize`](serde::Serialize) implementation for the shape
+    /// of the output.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("decoded instructions are valid JSON")
+    }
+
+    fn data_fields(&self) -> Vec<(&'static str, Value<'_>)> {
+        use TokenInstruction::*;
+
+        let pubkey_option = |value: &COption<Pubkey>| match value {
+            COption::Some(pubkey) => Value::Pubkey(*pubkey),
+            COption::None => Value::Null,
+        };
+        let account_kind = |value: &AccountKind| {
+            Value::Str(match value {
+                AccountKind::Account => "account",
+                AccountKind::Mint => "mint",
+                AccountKind::Multisig => "multisig",
+            })
+        };
+        let amount = |value: u64| Value::String(value.to_string());
+        let ui_amount = |value: u64, decimals: u8| {
+            Value::String(TokenAmount::new(value, decimals).to_ui_amount_string_trimmed())
+        };
+
+        match &self.instruction {
+            InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            }
+            | InitializeMint2 {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            }
+            | CreateAndInitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => vec![
+                ("decimals", Value::Number(*decimals as u64)),
+                ("mintAuthority", Value::Pubkey(*mint_authority)),
+                ("freezeAuthority", pubkey_option(freeze_authority)),
+            ],
+            InitializeMultisig { m } | InitializeMultisig2 { m } => {
+                vec![("m", Value::Number(*m as u64))]
+            }
+            Transfer { amount: value }
+            | Approve { amount: value }
+            | MintTo { amount: value }
+            | Burn { amount: value }
+            | AmountToUiAmount { amount: value }
+            | IncreaseAllowance { amount: value }
+            | DecreaseAllowance { amount: value }
+            | ClawbackTransfer { amount: value }
+            | ClawbackBurn { amount: value } => vec![("amount", amount(*value))],
+            TransferChecked {
+                amount: value,
+                decimals,
+            }
+            | ApproveChecked {
+                amount: value,
+                decimals,
+            }
+            | MintToChecked {
+                amount: value,
+                decimals,
+            }
+            | BurnChecked {
+                amount: value,
+                decimals,
+            } => vec![
+                ("amount", amount(*value)),
+                ("decimals", Value::Number(*decimals as u64)),
+                ("uiAmountString", ui_amount(*value, *decimals)),
+            ],
+            SetAuthority {
+                authority_type,
+                new_authority,
+            } => vec![
+                (
+                    "authorityType",
+                    Value::Str(match authority_type {
+                        AuthorityType::MintTokens => "mintTokens",
+                        AuthorityType::FreezeAccount => "freezeAccount",
+                        AuthorityType::AccountOwner => "accountOwner",
+                        AuthorityType::CloseAccount => "closeAccount",
+                    }),
+                ),
+                ("newAuthority", pubkey_option(new_authority)),
+            ],
+            InitializeAccount2 { owner }
+            | InitializeAccount3 { owner }
+            | CreateAndInitializeAccount { owner }
+            | InitializeAccountIdempotent { owner } => vec![("owner", Value::Pubkey(*owner))],
+            UiAmountToAmount { ui_amount } => vec![("uiAmount", Value::Str(ui_amount))],
+            UnwrapLamports { amount: value } => vec![(
+                "amount",
+                match value {
+                    COption::Some(value) => amount(*value),
+                    COption::None => Value::Null,
+                },
+            )],
+            SweepAndClose { burn } => vec![("burn", Value::Bool(*burn))],
+            TransferAllChecked { decimals } => {
+                vec![("decimals", Value::Number(*decimals as u64))]
+            }
+            ApproveIfCurrent {
+                expected_delegate,
+                expected_amount,
+                new_amount,
+            } => vec![
+                ("expectedDelegate", pubkey_option(expected_delegate)),
+                ("expectedAmount", amount(*expected_amount)),
+                ("newAmount", amount(*new_amount)),
+            ],
+            ApproveWithPermit {
+                amount: value,
+                nonce,
+                expiry,
+            } => vec![
+                ("amount", amount(*value)),
+                ("nonce", amount(*nonce)),
+                ("expiry", Value::String(expiry.to_string())),
+            ],
+            GetAccountDataSizeForKind {
+                kind,
+                extension_types,
+            } => vec![
+                ("kind", account_kind(kind)),
+                (
+                    "extensionTypes",
+                    Value::Array(
+                        extension_types
+                            .iter()
+                            .map(|extension_type| Value::Number(*extension_type as u64))
+                            .collect(),
+                    ),
+                ),
+            ],
+            GetMinimumBalance { kind } => vec![("kind", account_kind(kind))],
+            InitializeClawbackConfig { authority } => {
+                vec![("authority", Value::Pubkey(*authority))]
+            }
+            SweepExcessLamports { source_count } => {
+                vec![("sourceCount", Value::Number(*source_count as u64))]
+            }
+            InitializeAccount
+            | Revoke
+            | CloseAccount
+            | FreezeAccount
+            | ThawAccount
+            | SyncNative
+            | GetAccountDataSize
+            | InitializeImmutableOwner
+            | WithdrawExcessLamports
+            | BurnAll
+            | InitializePermitNonce
+            | TransferAll
+            | WithdrawExcessNativeLamports
+            | Batch => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::instruction::{
+            batch, initialize_account3, sweep_excess_lamports, transfer_checked,
+            ui_amount_to_amount,
+        },
+    };
+
+    #[test]
+    fn test_decode_transfer_checked() {
+        let source = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let destination = Pubkey::new_from_array([3; 32]);
+        let multisig = Pubkey::new_from_array([4; 32]);
+        let signer = Pubkey::new_from_array([5; 32]);
+
+        let instruction = transfer_checked(
+            &crate::id(),
+            &source,
+            &mint,
+            &destination,
+            &multisig,
+            &[&signer],
+            1_500_000,
+            6,
+        )
+        .unwrap();
+        let decoded = decode(&instruction).unwrap();
+
+        assert_eq!(decoded.name, "transferChecked");
+        assert_eq!(
+            decoded.instruction,
+            TokenInstruction::TransferChecked {
+                amount: 1_500_000,
+                decimals: 6
+            }
+        );
+        assert_eq!(
+            decoded
+                .accounts
+                .iter()
+                .map(|account| (account.label, account.pubkey))
+                .collect::<Vec<_>>(),
+            vec![
+                ("source", source),
+                ("mint", mint),
+                ("destination", destination),
+                ("authority", multisig),
+            ]
+        );
+        assert_eq!(decoded.signers, vec![signer]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_transfer_checked_to_json() {
+        let source = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let destination = Pubkey::new_from_array([3; 32]);
+        let multisig = Pubkey::new_from_array([4; 32]);
+        let signer = Pubkey::new_from_array([5; 32]);
+
+        let instruction = transfer_checked(
+            &crate::id(),
+            &source,
+            &mint,
+            &destination,
+            &multisig,
+            &[&signer],
+            1_500_000,
+            6,
+        )
+        .unwrap();
+        let decoded = decode(&instruction).unwrap();
+
+        assert_eq!(
+            decoded.to_json(),
+            format!(
+                "{{\"instructionType\":\"transferChecked\",\"accounts\":{{\
+                 \"source\":{{\"address\":\"{source}\",\"isSigner\":false,\"isWritable\":true}},\
+                 \"mint\":{{\"address\":\"{mint}\",\"isSigner\":false,\"isWritable\":false}},\
+                 \"destination\":{{\"address\":\"{destination}\",\"isSigner\":false,\"isWritable\":true}},\
+                 \"authority\":{{\"address\":\"{multisig}\",\"isSigner\":false,\"isWritable\":false}}}},\
+                 \"data\":{{\"amount\":\"1500000\",\"decimals\":6,\"uiAmountString\":\"1.5\"}},\
+                 \"signers\":[\"{signer}\"]}}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_batch() {
+        let account = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let owner = Pubkey::new_from_array([3; 32]);
+
+        let initialize = initialize_account3(&crate::id(), &account, &mint, &owner).unwrap();
+        let inner = batch(&crate::id(), std::slice::from_ref(&initialize)).unwrap();
+        let instruction = batch(&crate::id(), &[initialize, inner]).unwrap();
+
+        let decoded = decode(&instruction).unwrap();
+
+        assert_eq!(decoded.name, "batch");
+        assert!(decoded.accounts.is_empty());
+        assert_eq!(decoded.instructions.len(), 2);
+        assert_eq!(decoded.instructions[0].name, "initializeAccount3");
+        assert_eq!(decoded.instructions[0].accounts[1].label, "mint");
+        assert_eq!(decoded.instructions[1].name, "batch");
+        assert_eq!(decoded.instructions[1].instructions.len(), 1);
+        assert_eq!(
+            decoded.instructions[1].instructions[0],
+            decoded.instructions[0]
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let json = decoded.to_json();
+            assert!(json.starts_with("{\"instructionType\":\"batch\",\"accounts\":{},\"data\":{}"));
+            assert_eq!(json.matches("\"initializeAccount3\"").count(), 2);
+        }
+
+        // empty batch, rejected by the program
+        let empty = batch(&crate::id(), &[]).unwrap();
+        assert_eq!(decode(&empty), Err(TokenError::InvalidInstruction.into()));
+
+        // missing accounts
+        let mut truncated = instruction.clone();
+        truncated.accounts.pop();
+        assert_eq!(decode(&truncated), Err(ProgramError::NotEnoughAccountKeys));
+
+        // truncated data
+        let mut truncated = instruction;
+        truncated.data.pop();
+        assert_eq!(
+            decode(&truncated),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_decode_sweep_excess_lamports() {
+        let [destination, multisig, source1, source2, signer] =
+            [1, 2, 3, 4, 5].map(|byte| Pubkey::new_from_array([byte; 32]));
+
+        let instruction = sweep_excess_lamports(
+            &crate::id(),
+            &[&source1, &source2],
+            &destination,
+            &multisig,
+            &[&signer],
+        )
+        .unwrap();
+        let decoded = decode(&instruction).unwrap();
+
+        assert_eq!(decoded.name, "sweepExcessLamports");
+        assert_eq!(
+            decoded
+                .accounts
+                .iter()
+                .map(|account| (account.label, account.pubkey))
+                .collect::<Vec<_>>(),
+            vec![
+                ("destination", destination),
+                ("authority", multisig),
+                ("source", source1),
+                ("source", source2),
+            ]
+        );
+        assert_eq!(decoded.signers, vec![signer]);
+        #[cfg(feature = "serde")]
+        assert!(decoded.to_json().contains(&format!(
+            "\"source\":[{{\"address\":\"{source1}\",\"isSigner\":false,\"isWritable\":true}},\
+             {{\"address\":\"{source2}\",\"isSigner\":false,\"isWritable\":true}}]"
+        )));
+
+        // missing sources
+        let mut truncated = instruction;
+        truncated.accounts.truncate(3);
+        assert_eq!(decode(&truncated), Err(ProgramError::NotEnoughAccountKeys));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let mint = Pubkey::new_unique();
+
+        let mut instruction = ui_amount_to_amount(&crate::id(), &mint, "1\"\n").unwrap();
+        let decoded = decode(&instruction).unwrap();
+        assert_eq!(decoded.name, "uiAmountToAmount");
+        #[cfg(feature = "serde")]
+        assert!(decoded
+            .to_json()
+            .ends_with("\"data\":{\"uiAmount\":\"1\\\"\\n\"}}"));
+
+        instruction.accounts.clear();
+        assert_eq!(
+            decode(&instruction),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        instruction.program_id = Pubkey::new_unique();
+        assert_eq!(decode(&instruction), Err(ProgramError::IncorrectProgramId));
+    }
+}
@@ -77,6 +77,20 @@ pub enum TokenError {
     /// Instruction does not support non-native tokens
     #[error("Instruction does not support non-native tokens")]
     NonNativeNotSupported,
+
+    // 20
+    /// Current delegation does not match the expected one
+    #[error("Current delegation does not match the expected one")]
+    DelegationMismatch,
+    /// Permit has expired
+    #[error("Permit has expired")]
+    PermitExpired,
+    /// Permit signature, message or nonce is invalid
+    #[error("Invalid permit")]
+    InvalidPermit,
+    /// Requested extension is not supported by this program
+    #[error("Extension not supported by this program")]
+    ExtensionNotSupported,
 }
 impl From<TokenError> for ProgramError {
     fn from(e: TokenError) -> Self {
@@ -108,6 +122,10 @@ impl TryFrom<u32> for TokenError {
             17 => Ok(TokenError::AccountFrozen),
             18 => Ok(TokenError::MintDecimalsMismatch),
             19 => Ok(TokenError::NonNativeNotSupported),
+            20 => Ok(TokenError::DelegationMismatch),
+            21 => Ok(TokenError::PermitExpired),
+            22 => Ok(TokenError::InvalidPermit),
+            23 => Ok(TokenError::ExtensionNotSupported),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -146,6 +164,12 @@ impl ToStr for TokenError {
             TokenError::NonNativeNotSupported => {
                 "Error: Instruction does not support non-native tokens"
             }
+            TokenError::DelegationMismatch => {
+                "Error: Current delegation does not match the expected one"
+            }
+            TokenError::PermitExpired => "Error: Permit has expired",
+            TokenError::InvalidPermit => "Error: Invalid permit",
+            TokenError::ExtensionNotSupported => "Error: Extension not supported by this program",
         }
     }
 }
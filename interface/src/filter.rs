@@ -0,0 +1,225 @@
+//! Account filters for `getProgramAccounts` queries.
+//!
+//! The filters are derived from the token account layout and can be
+//! converted to the RPC filter types of a client, or applied to account data
+//! directly with [`Filter::matches`].
+
+use {
+    crate::state::{
+        Account, AccountState, ACCOUNT_INITIALIZED_INDEX, SPL_TOKEN_ACCOUNT_DELEGATE_OFFSET,
+        SPL_TOKEN_ACCOUNT_MINT_OFFSET, SPL_TOKEN_ACCOUNT_OWNER_OFFSET,
+    },
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+};
+
+/// A filter on the data of an account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Filter {
+    /// Matches accounts with exactly this data length.
+    DataSize(u64),
+    /// Matches accounts whose data contains `bytes` at `offset`.
+    Memcmp {
+        /// Offset into the account data.
+        offset: usize,
+        /// Bytes to compare.
+        bytes: Vec<u8>,
+    },
+}
+
+impl Filter {
+    /// Checks if the account data matches the filter.
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match self {
+            Filter::DataSize(size) => data.len() as u64 == *size,
+            Filter::Memcmp { offset, bytes } => data
+                .get(*offset..)
+                .and_then(|data| data.get(..bytes.len()))
+                .is_some_and(|data| data == bytes.as_slice()),
+        }
+    }
+}
+
+/// Builder of the filters for a query of token accounts.
+///
+/// Every query is restricted to the token account data length, so mints and
+/// multisig accounts are never matched.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenAccountFilters {
+    filters: Vec<Filter>,
+}
+
+impl Default for TokenAccountFilters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenAccountFilters {
+    /// Creates the filters matching all token accounts.
+    pub fn new() -> Self {
+        Self {
+            filters: vec![Filter::DataSize(Account::LEN as u64)],
+        }
+    }
+
+    fn memcmp(mut self, offset: usize, bytes: Vec<u8>) -> Self {
+        self.filters.push(Filter::Memcmp { offset, bytes });
+        self
+    }
+
+    /// Matches token accounts owned by `owner`.
+    pub fn owner(self, owner: &Pubkey) -> Self {
+        self.memcmp(SPL_TOKEN_ACCOUNT_OWNER_OFFSET, owner.to_bytes().to_vec())
+    }
+
+    /// Matches token accounts of `mint`.
+    pub fn mint(self, mint: &Pubkey) -> Self {
+        self.memcmp(SPL_TOKEN_ACCOUNT_MINT_OFFSET, mint.to_bytes().to_vec())
+    }
+
+    /// Matches token accounts in the given state.
+    pub fn state(self, state: AccountState) -> Self {
+        self.memcmp(ACCOUNT_INITIALIZED_INDEX, vec![state as u8])
+    }
+
+    /// Matches frozen token accounts.
+    pub fn frozen(self) -> Self {
+        self.state(AccountState::Frozen)
+    }
+
+    /// Matches token accounts delegated to `delegate`.
+    pub fn delegate(self, delegate: &Pubkey) -> Self {
+        let mut bytes = vec![1, 0, 0, 0];
+        bytes.extend_from_slice(delegate.as_ref());
+        self.memcmp(SPL_TOKEN_ACCOUNT_DELEGATE_OFFSET, bytes)
+    }
+
+    /// Checks if the account data matches all the filters.
+    pub fn matches(&self, data: &[u8]) -> bool {
+        self.filters.iter().all(|filter| filter.matches(data))
+    }
+
+    /// Returns the filters.
+    pub fn build(self) -> Vec<Filter> {
+        self.filters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::state::{Mint, Multisig},
+        solana_program_option::COption,
+    };
+
+    fn pack(account: Account) -> Vec<u8> {
+        let mut data = vec![0; Account::LEN];
+        Account::pack(account, &mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_token_account_filters() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let account = Account {
+            mint,
+            owner,
+            amount: 10,
+            delegate: COption::Some(delegate),
+            state: AccountState::Frozen,
+            is_native: COption::None,
+            delegated_amount: 5,
+            close_authority: COption::None,
+        };
+        let data = pack(account);
+        let other = Pubkey::new_unique();
+
+        assert!(TokenAccountFilters::new().matches(&data));
+        assert!(TokenAccountFilters::new().owner(&owner).matches(&data));
+        assert!(!TokenAccountFilters::new().owner(&other).matches(&data));
+        assert!(!TokenAccountFilters::new().owner(&mint).matches(&data));
+        assert!(TokenAccountFilters::new().mint(&mint).matches(&data));
+        assert!(!TokenAccountFilters::new().mint(&owner).matches(&data));
+        assert!(TokenAccountFilters::new().frozen().matches(&data));
+        assert!(!TokenAccountFilters::new()
+            .state(AccountState::Initialized)
+            .matches(&data));
+        assert!(TokenAccountFilters::new()
+            .delegate(&delegate)
+            .matches(&data));
+        assert!(!TokenAccountFilters::new().delegate(&other).matches(&data));
+        assert!(TokenAccountFilters::new()
+            .owner(&owner)
+            .mint(&mint)
+            .frozen()
+            .delegate(&delegate)
+            .matches(&data));
+
+        let data = pack(Account {
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            ..account
+        });
+        assert!(!TokenAccountFilters::new().frozen().matches(&data));
+        assert!(TokenAccountFilters::new()
+            .state(AccountState::Initialized)
+            .matches(&data));
+        // The delegate key is not cleared when the delegate is removed.
+        let mut data = data;
+        data[SPL_TOKEN_ACCOUNT_DELEGATE_OFFSET + 4..][..32].copy_from_slice(delegate.as_ref());
+        assert!(!TokenAccountFilters::new()
+            .delegate(&delegate)
+            .matches(&data));
+    }
+
+    #[test]
+    fn test_token_account_filters_data_size() {
+        let owner = Pubkey::new_unique();
+
+        let mut mint = vec![0; Mint::LEN];
+        Mint::pack(
+            Mint {
+                mint_authority: COption::Some(owner),
+                is_initialized: true,
+                ..Mint::default()
+            },
+            &mut mint,
+        )
+        .unwrap();
+        assert!(!TokenAccountFilters::new().matches(&mint));
+
+        let mut multisig = vec![0; Multisig::LEN];
+        multisig[32..64].copy_from_slice(owner.as_ref());
+        assert!(!TokenAccountFilters::new().owner(&owner).matches(&multisig));
+
+        assert_eq!(
+            TokenAccountFilters::new().owner(&owner).build(),
+            vec![
+                Filter::DataSize(165),
+                Filter::Memcmp {
+                    offset: 32,
+                    bytes: owner.to_bytes().to_vec(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let filter = Filter::Memcmp {
+            offset: 2,
+            bytes: vec![1, 2],
+        };
+        assert!(filter.matches(&[0, 0, 1, 2]));
+        assert!(filter.matches(&[0, 0, 1, 2, 3]));
+        assert!(!filter.matches(&[0, 0, 1]));
+        assert!(!filter.matches(&[0]));
+        assert!(!filter.matches(&[0, 1, 2, 0]));
+        assert!(Filter::DataSize(1).matches(&[0]));
+        assert!(!Filter::DataSize(1).matches(&[]));
+    }
+}
@@ -1,12 +1,17 @@
 //! Instruction types
 
 use {
-    crate::{check_program_account, error::TokenError},
+    crate::{
+        check_program_account,
+        error::TokenError,
+        state::{Account, Mint, Multisig},
+    },
     solana_instruction::{AccountMeta, Instruction},
     solana_program_error::ProgramError,
     solana_program_option::COption,
+    solana_program_pack::Pack,
     solana_pubkey::Pubkey,
-    solana_sdk_ids::sysvar,
+    solana_sdk_ids::{system_program, sysvar},
     std::{convert::TryInto, mem::size_of},
 };
 
@@ -478,6 +483,9 @@ pub enum TokenInstruction<'a> {
     /// owned account by sending them to any other account, leaving behind only
     /// lamports for rent exemption.
     ///
+    /// Native accounts are not supported, use `WithdrawExcessNativeLamports`
+    /// instead.
+    ///
     /// Accounts expected by this instruction:
     ///
     ///   * Single owner/delegate
@@ -517,6 +525,429 @@ pub enum TokenInstruction<'a> {
         /// The amount of lamports to transfer.
         amount: COption<u64>,
     } = 45,
+    /// Transfers or burns the entire token balance of an account and closes
+    /// it, sending its lamports to the given destination.
+    ///
+    /// This is equivalent to a `Transfer` (or `Burn`) of the full balance
+    /// followed by a `CloseAccount`, validated against a single authority:
+    /// the account owner. If the account has a close authority, it must be
+    /// the same as the owner. Native accounts are not supported, use
+    /// `CloseAccount` instead.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The account to sweep and close.
+    ///   1. `[writable]` The destination token account or, when burning, the
+    ///      token mint.
+    ///   2. `[writable]` The destination account for the lamports.
+    ///   3. `[signer]` The account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The account to sweep and close.
+    ///   1. `[writable]` The destination token account or, when burning, the
+    ///      token mint.
+    ///   2. `[writable]` The destination account for the lamports.
+    ///   3. `[]` The account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    SweepAndClose {
+        /// Burn the remaining balance instead of transferring it.
+        burn: bool,
+    } = 46,
+    /// Burns the entire token balance of an account. When signed by the
+    /// delegate, the amount burned is the delegated amount, capped by the
+    /// account balance.
+    ///
+    /// This is equivalent to a `Burn` of the amount read at execution time
+    /// instead of being provided by the caller. To also close the account,
+    /// use `SweepAndClose` with `burn` set to `true`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[signer]` The account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The account's multisignature owner/delegate.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    BurnAll = 47,
+    /// Transfers the entire token balance of an account to another account
+    /// either directly or via a delegate. When signed by the delegate, the
+    /// amount transferred is the delegated amount, capped by the account
+    /// balance. If this account is associated with the native mint then equal
+    /// amounts of SOL and Tokens will be transferred to the destination
+    /// account.
+    ///
+    /// This is equivalent to a `Transfer` of the amount read at execution
+    /// time instead of being provided by the caller.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The source account's multisignature owner/delegate.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    TransferAll = 48,
+    /// Transfers the entire token balance of an account to another account
+    /// either directly or via a delegate. When signed by the delegate, the
+    /// amount transferred is the delegated amount, capped by the account
+    /// balance.
+    ///
+    /// This instruction differs from `TransferAll` in that the token mint and
+    /// decimals value is checked by the caller.  This may be useful when
+    /// creating transactions offline or within a hardware wallet.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The source account's multisignature owner/delegate.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    TransferAllChecked {
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+    } = 49,
+    /// Approves a delegate only if the current delegation matches the expected
+    /// one, failing with `DelegationMismatch` otherwise. A delegate is given
+    /// the authority over tokens on behalf of the source account's owner.
+    ///
+    /// This avoids the race where the current delegate spends tokens between
+    /// the owner reading the allowance and approving a new one. When no
+    /// delegate is expected, the current delegated amount must also match
+    /// `expected_amount` (usually `0`).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The new delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The new delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    ApproveIfCurrent {
+        /// The delegate expected to be currently set on the account.
+        expected_delegate: COption<Pubkey>,
+        /// The amount expected to be currently delegated.
+        expected_amount: u64,
+        /// The new amount of tokens the delegate is approved for.
+        new_amount: u64,
+    } = 50,
+    /// Increases the amount of tokens the current delegate is approved for.
+    ///
+    /// The delegate account must match the delegate currently set on the
+    /// source account, otherwise the instruction fails with
+    /// `DelegationMismatch`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    IncreaseAllowance {
+        /// The amount of tokens to add to the delegated amount.
+        amount: u64,
+    } = 51,
+    /// Decreases the amount of tokens the current delegate is approved for.
+    ///
+    /// The delegate account must match the delegate currently set on the
+    /// source account, otherwise the instruction fails with
+    /// `DelegationMismatch`. When the delegated amount
+    /// reaches zero, the delegate is revoked.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    DecreaseAllowance {
+        /// The amount of tokens to subtract from the delegated amount.
+        amount: u64,
+    } = 52,
+    /// Initializes a permit nonce account, used by `ApproveWithPermit` to
+    /// prevent permits of the signing owner from being replayed.
+    ///
+    /// The `InitializePermitNonce` instruction MUST be included within the
+    /// same Transaction as the system program's `CreateAccount` instruction
+    /// that creates the account being initialized. Otherwise another party can
+    /// acquire ownership of the uninitialized account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The permit nonce account to initialize.
+    ///   1. `[signer]` The owner whose permits are tracked by the account.
+    InitializePermitNonce = 53,
+    /// Approves a delegate using a permit signed off-chain by the source
+    /// account's owner.
+    ///
+    /// The permit is verified by introspecting the ed25519 program
+    /// instruction that must immediately precede this instruction in the
+    /// transaction. That instruction must verify a single signature of the
+    /// owner over the packed permit message, with the public key, signature
+    /// and message all contained in its own instruction data. The permit
+    /// message includes the address of the owner's permit nonce account,
+    /// whose nonce is incremented on success, so each permit can only be used
+    /// once.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[writable]` The owner's permit nonce account.
+    ///   3. `[]` Instructions sysvar.
+    ApproveWithPermit {
+        /// The amount of tokens the delegate is approved for.
+        amount: u64,
+        /// The expected nonce of the permit nonce account.
+        nonce: u64,
+        /// The unix timestamp after which the permit expires.
+        expiry: i64,
+    } = 54,
+    /// Gets the minimum balance for an account of the given kind to be
+    /// rent-exempt, as a little-endian `u64`.
+    ///
+    /// Return data can be fetched using `sol_get_return_data` and deserializing
+    /// the return data as a little-endian `u64`.
+    ///
+    /// No accounts are expected by this instruction.
+    GetMinimumBalance {
+        /// The kind of account.
+        kind: AccountKind,
+    } = 55,
+    /// Creates and initializes a new mint in a single instruction.
+    ///
+    /// The mint account is funded by the payer with the rent-exempt minimum
+    /// balance, allocated and assigned to the token program through the system
+    /// program, then initialized as with `InitializeMint2`. Since the mint must
+    /// sign, it cannot be initialized by another party in between.
+    ///
+    /// Lamports already held by the mint account are kept, and only the
+    /// remaining balance is transferred from the payer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable, signer]` The mint to create.
+    ///   1. `[writable, signer]` The payer.
+    ///   2. `[]` System program.
+    CreateAndInitializeMint {
+        /// Number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+        /// The authority/multisignature to mint tokens.
+        mint_authority: Pubkey,
+        /// The freeze authority/multisignature of the mint.
+        freeze_authority: COption<Pubkey>,
+    } = 56,
+    /// Creates and initializes a new token account in a single instruction.
+    ///
+    /// The account is funded by the payer with the rent-exempt minimum
+    /// balance, allocated and assigned to the token program through the system
+    /// program, then initialized as with `InitializeAccount3`. Since the account
+    /// must sign, it cannot be initialized by another party in between.
+    ///
+    /// Lamports already held by the account are kept, and only the remaining
+    /// balance is transferred from the payer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable, signer]` The account to create.
+    ///   1. `[]` The mint this account will be associated with.
+    ///   2. `[writable, signer]` The payer.
+    ///   3. `[]` System program.
+    CreateAndInitializeAccount {
+        /// The new account's owner/multisignature.
+        owner: Pubkey,
+    } = 57,
+    /// Like [`TokenInstruction::InitializeAccount3`], but succeeds without any
+    /// change if the account is already initialized for the same mint and
+    /// owner.
+    ///
+    /// An existing account must be owned by the token program and not be
+    /// frozen. An account for a different mint fails with `MintMismatch`, and
+    /// one for a different owner fails with `OwnerMismatch`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]`  The account to initialize.
+    ///   1. `[]` The mint this account will be associated with.
+    InitializeAccountIdempotent {
+        /// The new account's owner/multisignature.
+        owner: Pubkey,
+    } = 58,
+    /// Like [`TokenInstruction::WithdrawExcessLamports`], but withdraws the
+    /// excess lamports of several source accounts of the same kind (token
+    /// accounts, mints or multisigs) into a single destination.
+    ///
+    /// A single authority is accepted: it must be the owner expected by every
+    /// source account, and its signature is only validated once. Since a
+    /// multisig account is the authority of its own excess lamports, only one
+    /// multisig can be swept at a time; the signers of a multisignature
+    /// authority follow the source accounts.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The destination account.
+    ///   1. `[signer]` The source accounts' owner.
+    ///   2. `..+N` `[writable]` N source accounts.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The destination account.
+    ///   1. `[]` The source accounts' multisignature owner.
+    ///   2. `..+N` `[writable]` N source accounts.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    SweepExcessLamports {
+        /// The number of source accounts.
+        source_count: u8,
+    } = 59,
+    /// Initializes a clawback configuration account, allowing the given
+    /// authority to transfer or burn tokens from any account of the mint with
+    /// `ClawbackTransfer` and `ClawbackBurn`.
+    ///
+    /// The mint must sign and not be initialized yet, so the capability can
+    /// only be opted into when the mint is created. This instruction MUST be
+    /// included before the instruction initializing the mint.
+    ///
+    /// The configuration account is the program derived address of the mint
+    /// (see `find_clawback_config_address`), created by the token program
+    /// through the system program and funded by the payer with the
+    /// rent-exempt minimum balance.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The clawback configuration account to create.
+    ///   1. `[signer]` The mint, not yet initialized.
+    ///   2. `[writable, signer]` The payer.
+    ///   3. `[]` System program.
+    InitializeClawbackConfig {
+        /// The authority allowed to transfer or burn tokens from any account
+        /// of the mint.
+        authority: Pubkey,
+    } = 60,
+    /// Transfers tokens from any account of a mint with a clawback
+    /// configuration, signed by the clawback authority.
+    ///
+    /// The source account may be frozen, but the destination account must not
+    /// be. The delegation of the source account is left unchanged.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[signer]` The clawback authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[]` The clawback multisignature authority.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ClawbackTransfer {
+        /// The amount of tokens to transfer.
+        amount: u64,
+    } = 61,
+    /// Burns tokens from any account of a mint with a clawback configuration,
+    /// signed by the clawback authority.
+    ///
+    /// The source account may be frozen. The delegation of the source account
+    /// is left unchanged.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[signer]` The clawback authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[]` The clawback multisignature authority.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ClawbackBurn {
+        /// The amount of tokens to burn.
+        amount: u64,
+    } = 62,
+    /// Gets the required size of an account of the given kind as a
+    /// little-endian `u64`.
+    ///
+    /// Return data can be fetched using `sol_get_return_data` and deserializing
+    /// the return data as a little-endian `u64`.
+    ///
+    /// Token-2022 extension types are accepted for compatibility. Only
+    /// `ImmutableOwner` is supported, for token accounts, and does not change
+    /// their size; any other extension fails with `ExtensionNotSupported`.
+    ///
+    /// No accounts are expected by this instruction.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` The kind of account.
+    ///   - `[u16]` Token-2022 extension types of the account, using the
+    ///     remaining data.
+    GetAccountDataSizeForKind {
+        /// The kind of account.
+        kind: AccountKind,
+        /// Token-2022 extension types of the account.
+        extension_types: Vec<u16>,
+    } = 63,
+    /// Withdraws the lamports of a native account above its rent-exempt
+    /// reserve and token amount, instead of wrapping them with `SyncNative`.
+    ///
+    /// Non-native accounts are not supported, use `WithdrawExcessLamports`
+    /// instead.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The native account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The native account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The native account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The native account's multisignature owner.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    WithdrawExcessNativeLamports = 64,
     /// Executes a batch of instructions. The instructions to be executed are
     /// specified in sequence on the instruction data. Each instruction
     /// provides:
@@ -657,6 +1088,100 @@ impl<'a> TokenInstruction<'a> {
                 let (amount, _rest) = Self::unpack_u64_option(rest)?;
                 Self::UnwrapLamports { amount }
             }
+            46 => {
+                let (burn, _rest) = Self::unpack_bool(rest)?;
+                Self::SweepAndClose { burn }
+            }
+            47 => Self::BurnAll,
+            48 => Self::TransferAll,
+            49 => {
+                let (&decimals, _rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                Self::TransferAllChecked { decimals }
+            }
+            50 => {
+                let (expected_delegate, rest) = Self::unpack_pubkey_option(rest)?;
+                let (expected_amount, rest) = Self::unpack_u64(rest)?;
+                let (new_amount, _rest) = Self::unpack_u64(rest)?;
+                Self::ApproveIfCurrent {
+                    expected_delegate,
+                    expected_amount,
+                    new_amount,
+                }
+            }
+            51 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::IncreaseAllowance { amount }
+            }
+            52 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::DecreaseAllowance { amount }
+            }
+            53 => Self::InitializePermitNonce,
+            54 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (nonce, rest) = Self::unpack_u64(rest)?;
+                let (expiry, _rest) = Self::unpack_u64(rest)?;
+                Self::ApproveWithPermit {
+                    amount,
+                    nonce,
+                    expiry: expiry as i64,
+                }
+            }
+            55 => {
+                let &kind = rest.first().ok_or(InvalidInstruction)?;
+                Self::GetMinimumBalance {
+                    kind: AccountKind::from(kind)?,
+                }
+            }
+            56 => {
+                let (&decimals, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                let (mint_authority, rest) = Self::unpack_pubkey(rest)?;
+                let (freeze_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::CreateAndInitializeMint {
+                    mint_authority,
+                    freeze_authority,
+                    decimals,
+                }
+            }
+            57 => {
+                let (owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::CreateAndInitializeAccount { owner }
+            }
+            58 => {
+                let (owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitializeAccountIdempotent { owner }
+            }
+            59 => {
+                let &source_count = rest.first().ok_or(InvalidInstruction)?;
+                Self::SweepExcessLamports { source_count }
+            }
+            60 => {
+                let (authority, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitializeClawbackConfig { authority }
+            }
+            61 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::ClawbackTransfer { amount }
+            }
+            62 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::ClawbackBurn { amount }
+            }
+            63 => {
+                let (&kind, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                if rest.len() % 2 != 0 {
+                    return Err(TokenError::InvalidInstruction.into());
+                }
+                let extension_types = rest
+                    .chunks_exact(2)
+                    .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+                    .collect();
+                Self::GetAccountDataSizeForKind {
+                    kind: AccountKind::from(kind)?,
+                    extension_types,
+                }
+            }
+            64 => Self::WithdrawExcessNativeLamports,
             255 => Self::Batch,
             _ => return Err(TokenError::InvalidInstruction.into()),
         })
@@ -776,37 +1301,133 @@ impl<'a> TokenInstruction<'a> {
                 buf.push(45);
                 Self::pack_u64_option(amount, &mut buf);
             }
-            &Self::Batch => {
-                buf.push(255);
+            &Self::SweepAndClose { burn } => {
+                buf.push(46);
+                buf.push(burn as u8);
             }
-        };
-        buf
-    }
-
-    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
-        if input.len() >= 32 {
-            let (key, rest) = input.split_at(32);
-            let pk = Pubkey::try_from(key).map_err(|_| TokenError::InvalidInstruction)?;
-            Ok((pk, rest))
-        } else {
-            Err(TokenError::InvalidInstruction.into())
-        }
-    }
-
-    fn unpack_pubkey_option(input: &[u8]) -> Result<(COption<Pubkey>, &[u8]), ProgramError> {
-        match input.split_first() {
-            Option::Some((&0, rest)) => Ok((COption::None, rest)),
-            Option::Some((&1, rest)) if rest.len() >= 32 => {
-                let (key, rest) = rest.split_at(32);
-                let pk = Pubkey::try_from(key).map_err(|_| TokenError::InvalidInstruction)?;
-                Ok((COption::Some(pk), rest))
+            &Self::BurnAll => {
+                buf.push(47);
             }
-            _ => Err(TokenError::InvalidInstruction.into()),
-        }
-    }
-
-    fn pack_pubkey_option(value: &COption<Pubkey>, buf: &mut Vec<u8>) {
-        match *value {
+            &Self::TransferAll => {
+                buf.push(48);
+            }
+            &Self::TransferAllChecked { decimals } => {
+                buf.push(49);
+                buf.push(decimals);
+            }
+            Self::ApproveIfCurrent {
+                ref expected_delegate,
+                expected_amount,
+                new_amount,
+            } => {
+                buf.push(50);
+                Self::pack_pubkey_option(expected_delegate, &mut buf);
+                buf.extend_from_slice(&expected_amount.to_le_bytes());
+                buf.extend_from_slice(&new_amount.to_le_bytes());
+            }
+            &Self::IncreaseAllowance { amount } => {
+                buf.push(51);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::DecreaseAllowance { amount } => {
+                buf.push(52);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::InitializePermitNonce => {
+                buf.push(53);
+            }
+            &Self::ApproveWithPermit {
+                amount,
+                nonce,
+                expiry,
+            } => {
+                buf.push(54);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.extend_from_slice(&expiry.to_le_bytes());
+            }
+            Self::GetMinimumBalance { ref kind } => {
+                buf.push(55);
+                buf.push(kind.into());
+            }
+            Self::CreateAndInitializeMint {
+                ref mint_authority,
+                ref freeze_authority,
+                decimals,
+            } => {
+                buf.push(56);
+                buf.push(*decimals);
+                buf.extend_from_slice(mint_authority.as_ref());
+                Self::pack_pubkey_option(freeze_authority, &mut buf);
+            }
+            Self::CreateAndInitializeAccount { ref owner } => {
+                buf.push(57);
+                buf.extend_from_slice(owner.as_ref());
+            }
+            Self::InitializeAccountIdempotent { ref owner } => {
+                buf.push(58);
+                buf.extend_from_slice(owner.as_ref());
+            }
+            &Self::SweepExcessLamports { source_count } => {
+                buf.push(59);
+                buf.push(source_count);
+            }
+            Self::InitializeClawbackConfig { ref authority } => {
+                buf.push(60);
+                buf.extend_from_slice(authority.as_ref());
+            }
+            &Self::ClawbackTransfer { amount } => {
+                buf.push(61);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::ClawbackBurn { amount } => {
+                buf.push(62);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::GetAccountDataSizeForKind {
+                ref kind,
+                ref extension_types,
+            } => {
+                buf.push(63);
+                buf.push(kind.into());
+                for extension_type in extension_types {
+                    buf.extend_from_slice(&extension_type.to_le_bytes());
+                }
+            }
+            &Self::WithdrawExcessNativeLamports => {
+                buf.push(64);
+            }
+            &Self::Batch => {
+                buf.push(255);
+            }
+        };
+        buf
+    }
+
+    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
+        if input.len() >= 32 {
+            let (key, rest) = input.split_at(32);
+            let pk = Pubkey::try_from(key).map_err(|_| TokenError::InvalidInstruction)?;
+            Ok((pk, rest))
+        } else {
+            Err(TokenError::InvalidInstruction.into())
+        }
+    }
+
+    fn unpack_pubkey_option(input: &[u8]) -> Result<(COption<Pubkey>, &[u8]), ProgramError> {
+        match input.split_first() {
+            Option::Some((&0, rest)) => Ok((COption::None, rest)),
+            Option::Some((&1, rest)) if rest.len() >= 32 => {
+                let (key, rest) = rest.split_at(32);
+                let pk = Pubkey::try_from(key).map_err(|_| TokenError::InvalidInstruction)?;
+                Ok((COption::Some(pk), rest))
+            }
+            _ => Err(TokenError::InvalidInstruction.into()),
+        }
+    }
+
+    fn pack_pubkey_option(value: &COption<Pubkey>, buf: &mut Vec<u8>) {
+        match *value {
             COption::Some(ref key) => {
                 buf.push(1);
                 buf.extend_from_slice(&key.to_bytes());
@@ -848,6 +1469,14 @@ impl<'a> TokenInstruction<'a> {
         }
     }
 
+    fn unpack_bool(input: &[u8]) -> Result<(bool, &[u8]), ProgramError> {
+        match input.split_first() {
+            Option::Some((&0, rest)) => Ok((false, rest)),
+            Option::Some((&1, rest)) => Ok((true, rest)),
+            _ => Err(TokenError::InvalidInstruction.into()),
+        }
+    }
+
     fn unpack_amount_decimals(input: &[u8]) -> Result<(u64, u8, &[u8]), ProgramError> {
         let (amount, rest) = Self::unpack_u64(input)?;
         let (&decimals, rest) = rest.split_first().ok_or(TokenError::InvalidInstruction)?;
@@ -858,6 +1487,11 @@ impl<'a> TokenInstruction<'a> {
 /// Specifies the authority type for `SetAuthority` instructions
 #[repr(u8)]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub enum AuthorityType {
     /// Authority to mint new tokens
     MintTokens,
@@ -870,7 +1504,7 @@ pub enum AuthorityType {
 }
 
 impl AuthorityType {
-    fn into(&self) -> u8 {
+    pub(crate) fn into(&self) -> u8 {
         match self {
             AuthorityType::MintTokens => 0,
             AuthorityType::FreezeAccount => 1,
@@ -879,7 +1513,7 @@ impl AuthorityType {
         }
     }
 
-    fn from(index: u8) -> Result<Self, ProgramError> {
+    pub(crate) fn from(index: u8) -> Result<Self, ProgramError> {
         match index {
             0 => Ok(AuthorityType::MintTokens),
             1 => Ok(AuthorityType::FreezeAccount),
@@ -890,6 +1524,56 @@ impl AuthorityType {
     }
 }
 
+/// Token-2022 extension type of the `ImmutableOwner` extension, the only one
+/// accepted by `GetAccountDataSizeForKind` instructions.
+pub const IMMUTABLE_OWNER_EXTENSION_TYPE: u16 = 7;
+
+/// Specifies the kind of account for `GetAccountDataSizeForKind` and
+/// `GetMinimumBalance` instructions
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum AccountKind {
+    /// A token account
+    Account,
+    /// A mint
+    Mint,
+    /// A multisignature account
+    Multisig,
+}
+
+impl AccountKind {
+    pub(crate) fn into(&self) -> u8 {
+        match self {
+            AccountKind::Account => 0,
+            AccountKind::Mint => 1,
+            AccountKind::Multisig => 2,
+        }
+    }
+
+    pub(crate) fn from(index: u8) -> Result<Self, ProgramError> {
+        match index {
+            0 => Ok(AccountKind::Account),
+            1 => Ok(AccountKind::Mint),
+            2 => Ok(AccountKind::Multisig),
+            _ => Err(TokenError::InvalidInstruction.into()),
+        }
+    }
+
+    /// Returns the size of the account data.
+    pub fn data_len(&self) -> usize {
+        match self {
+            AccountKind::Account => Account::LEN,
+            AccountKind::Mint => Mint::LEN,
+            AccountKind::Multisig => Multisig::LEN,
+        }
+    }
+}
+
 /// Creates a `InitializeMint` instruction.
 pub fn initialize_mint(
     token_program_id: &Pubkey,
@@ -1623,88 +2307,643 @@ pub fn unwrap_lamports(
     })
 }
 
-/// Creates a `Batch` instruction
-pub fn batch(
+/// Creates a `SweepAndClose` instruction.
+///
+/// When `burn` is `true`, `destination_pubkey` must be the token mint.
+pub fn sweep_and_close(
     token_program_id: &Pubkey,
-    instructions: &[Instruction],
+    account_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    lamports_destination_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    burn: bool,
 ) -> Result<Instruction, ProgramError> {
     check_program_account(token_program_id)?;
+    let data = TokenInstruction::SweepAndClose { burn }.pack();
 
-    let mut data: Vec<u8> = TokenInstruction::Batch.pack();
-    let mut accounts: Vec<AccountMeta> = vec![];
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new(*lamports_destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
 
-    for instruction in instructions {
-        if token_program_id != &instruction.program_id {
-            return Err(ProgramError::IncorrectProgramId);
-        }
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
 
-        data.push(instruction.accounts.len() as u8);
+/// Creates a `BurnAll` instruction.
+pub fn burn_all(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::BurnAll.pack();
 
-        if instruction.data.len() > u8::MAX as usize {
-            return Err(ProgramError::InvalidInstructionData);
-        }
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
 
-        data.push(instruction.data.len() as u8);
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
 
-        data.extend_from_slice(&instruction.data);
-        accounts.extend_from_slice(&instruction.accounts);
+/// Creates a `TransferAll` instruction.
+pub fn transfer_all(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::TransferAll.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
     }
 
     Ok(Instruction {
         program_id: *token_program_id,
-        data,
         accounts,
+        data,
     })
 }
 
-/// Utility function that checks index is between `MIN_SIGNERS` and
-/// `MAX_SIGNERS`
-pub fn is_valid_signer_index(index: usize) -> bool {
-    (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
-}
+/// Creates a `TransferAllChecked` instruction.
+pub fn transfer_all_checked(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::TransferAllChecked { decimals }.pack();
 
-#[cfg(test)]
-mod test {
-    use {super::*, proptest::prelude::*};
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
 
-    #[test]
-    fn test_instruction_packing() {
-        let check = TokenInstruction::InitializeMint {
-            decimals: 2,
-            mint_authority: Pubkey::new_from_array([1u8; 32]),
-            freeze_authority: COption::None,
-        };
-        let packed = check.pack();
-        let mut expect = Vec::from([0u8, 2]);
-        expect.extend_from_slice(&[1u8; 32]);
-        expect.extend_from_slice(&[0]);
-        assert_eq!(packed, expect);
-        let unpacked = TokenInstruction::unpack(&expect).unwrap();
-        assert_eq!(unpacked, check);
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
 
-        let check = TokenInstruction::InitializeMint {
-            decimals: 2,
-            mint_authority: Pubkey::new_from_array([2u8; 32]),
-            freeze_authority: COption::Some(Pubkey::new_from_array([3u8; 32])),
-        };
-        let packed = check.pack();
-        let mut expect = vec![0u8, 2];
-        expect.extend_from_slice(&[2u8; 32]);
-        expect.extend_from_slice(&[1]);
-        expect.extend_from_slice(&[3u8; 32]);
-        assert_eq!(packed, expect);
-        let unpacked = TokenInstruction::unpack(&expect).unwrap();
-        assert_eq!(unpacked, check);
+/// Creates an `ApproveIfCurrent` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn approve_if_current(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    expected_delegate: Option<&Pubkey>,
+    expected_amount: u64,
+    new_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let expected_delegate = expected_delegate.cloned().into();
+    let data = TokenInstruction::ApproveIfCurrent {
+        expected_delegate,
+        expected_amount,
+        new_amount,
+    }
+    .pack();
 
-        let check = TokenInstruction::InitializeAccount;
-        let packed = check.pack();
-        let expect = Vec::from([1u8]);
-        assert_eq!(packed, expect);
-        let unpacked = TokenInstruction::unpack(&expect).unwrap();
-        assert_eq!(unpacked, check);
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
 
-        let check = TokenInstruction::InitializeMultisig { m: 1 };
-        let packed = check.pack();
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `IncreaseAllowance` instruction.
+pub fn increase_allowance(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::IncreaseAllowance { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `DecreaseAllowance` instruction.
+pub fn decrease_allowance(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::DecreaseAllowance { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitializePermitNonce` instruction.
+pub fn initialize_permit_nonce(
+    token_program_id: &Pubkey,
+    permit_nonce_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::InitializePermitNonce.pack();
+
+    let accounts = vec![
+        AccountMeta::new(*permit_nonce_pubkey, false),
+        AccountMeta::new_readonly(*owner_pubkey, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `ApproveWithPermit` instruction.
+///
+/// The instruction must be immediately preceded by the ed25519 program
+/// instruction verifying the permit, see
+/// [`Permit::sign_and_approve`](crate::permit::Permit::sign_and_approve).
+pub fn approve_with_permit(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    permit_nonce_pubkey: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::ApproveWithPermit {
+        amount,
+        nonce,
+        expiry,
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*source_pubkey, false),
+        AccountMeta::new_readonly(*delegate_pubkey, false),
+        AccountMeta::new(*permit_nonce_pubkey, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `GetMinimumBalance` instruction.
+pub fn get_minimum_balance(
+    token_program_id: &Pubkey,
+    kind: AccountKind,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![],
+        data: TokenInstruction::GetMinimumBalance { kind }.pack(),
+    })
+}
+
+/// Creates a `CreateAndInitializeMint` instruction.
+pub fn create_and_initialize_mint(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    payer_pubkey: &Pubkey,
+    mint_authority_pubkey: &Pubkey,
+    freeze_authority_pubkey: Option<&Pubkey>,
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let freeze_authority = freeze_authority_pubkey.cloned().into();
+    let data = TokenInstruction::CreateAndInitializeMint {
+        mint_authority: *mint_authority_pubkey,
+        freeze_authority,
+        decimals,
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*mint_pubkey, true),
+        AccountMeta::new(*payer_pubkey, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CreateAndInitializeAccount` instruction.
+pub fn create_and_initialize_account(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    payer_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::CreateAndInitializeAccount {
+        owner: *owner_pubkey,
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*account_pubkey, true),
+        AccountMeta::new_readonly(*mint_pubkey, false),
+        AccountMeta::new(*payer_pubkey, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitializeAccountIdempotent` instruction.
+pub fn initialize_account_idempotent(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::InitializeAccountIdempotent {
+        owner: *owner_pubkey,
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*account_pubkey, false),
+        AccountMeta::new_readonly(*mint_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SweepExcessLamports` instruction
+pub fn sweep_excess_lamports(
+    token_program_id: &Pubkey,
+    source_pubkeys: &[&Pubkey],
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    if source_pubkeys.is_empty() || source_pubkeys.len() > u8::MAX as usize {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let data = TokenInstruction::SweepExcessLamports {
+        source_count: source_pubkeys.len() as u8,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(2 + source_pubkeys.len() + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for source_pubkey in source_pubkeys.iter() {
+        accounts.push(AccountMeta::new(**source_pubkey, false));
+    }
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitializeClawbackConfig` instruction.
+pub fn initialize_clawback_config(
+    token_program_id: &Pubkey,
+    clawback_config_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    payer_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::InitializeClawbackConfig {
+        authority: *authority_pubkey,
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*clawback_config_pubkey, false),
+        AccountMeta::new_readonly(*mint_pubkey, true),
+        AccountMeta::new(*payer_pubkey, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ClawbackTransfer` instruction.
+pub fn clawback_transfer(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    clawback_config_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::ClawbackTransfer { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*clawback_config_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ClawbackBurn` instruction.
+pub fn clawback_burn(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    clawback_config_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::ClawbackBurn { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*clawback_config_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `GetAccountDataSizeForKind` instruction.
+pub fn get_account_data_size_for_kind(
+    token_program_id: &Pubkey,
+    kind: AccountKind,
+    extension_types: &[u16],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![],
+        data: TokenInstruction::GetAccountDataSizeForKind {
+            kind,
+            extension_types: extension_types.to_vec(),
+        }
+        .pack(),
+    })
+}
+
+/// Creates a `WithdrawExcessNativeLamports` instruction
+pub fn withdraw_excess_native_lamports(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::WithdrawExcessNativeLamports.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `Batch` instruction
+pub fn batch(
+    token_program_id: &Pubkey,
+    instructions: &[Instruction],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    let mut data: Vec<u8> = TokenInstruction::Batch.pack();
+    let mut accounts: Vec<AccountMeta> = vec![];
+
+    for instruction in instructions {
+        if token_program_id != &instruction.program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        data.push(instruction.accounts.len() as u8);
+
+        if instruction.data.len() > u8::MAX as usize {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        data.push(instruction.data.len() as u8);
+
+        data.extend_from_slice(&instruction.data);
+        accounts.extend_from_slice(&instruction.accounts);
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        data,
+        accounts,
+    })
+}
+
+/// Utility function that checks index is between `MIN_SIGNERS` and
+/// `MAX_SIGNERS`
+pub fn is_valid_signer_index(index: usize) -> bool {
+    (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, proptest::prelude::*};
+
+    #[test]
+    fn test_instruction_packing() {
+        let check = TokenInstruction::InitializeMint {
+            decimals: 2,
+            mint_authority: Pubkey::new_from_array([1u8; 32]),
+            freeze_authority: COption::None,
+        };
+        let packed = check.pack();
+        let mut expect = Vec::from([0u8, 2]);
+        expect.extend_from_slice(&[1u8; 32]);
+        expect.extend_from_slice(&[0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeMint {
+            decimals: 2,
+            mint_authority: Pubkey::new_from_array([2u8; 32]),
+            freeze_authority: COption::Some(Pubkey::new_from_array([3u8; 32])),
+        };
+        let packed = check.pack();
+        let mut expect = vec![0u8, 2];
+        expect.extend_from_slice(&[2u8; 32]);
+        expect.extend_from_slice(&[1]);
+        expect.extend_from_slice(&[3u8; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeAccount;
+        let packed = check.pack();
+        let expect = Vec::from([1u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeMultisig { m: 1 };
+        let packed = check.pack();
         let expect = Vec::from([2u8, 1]);
         assert_eq!(packed, expect);
         let unpacked = TokenInstruction::unpack(&expect).unwrap();
@@ -1932,6 +3171,213 @@ mod test {
         let unpacked = TokenInstruction::unpack(&expect).unwrap();
         assert_eq!(unpacked, check);
 
+        let check = TokenInstruction::SweepAndClose { burn: false };
+        let packed = check.pack();
+        let expect = vec![46u8, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::SweepAndClose { burn: true };
+        let packed = check.pack();
+        let expect = vec![46u8, 1];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::BurnAll;
+        let packed = check.pack();
+        let expect = vec![47u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::TransferAll;
+        let packed = check.pack();
+        let expect = vec![48u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::TransferAllChecked { decimals: 2 };
+        let packed = check.pack();
+        let expect = vec![49u8, 2];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ApproveIfCurrent {
+            expected_delegate: COption::Some(Pubkey::new_from_array([2u8; 32])),
+            expected_amount: 1,
+            new_amount: 2,
+        };
+        let packed = check.pack();
+        let mut expect = vec![50u8, 1];
+        expect.extend_from_slice(&[2u8; 32]);
+        expect.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+        expect.extend_from_slice(&[2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ApproveIfCurrent {
+            expected_delegate: COption::None,
+            expected_amount: 0,
+            new_amount: 2,
+        };
+        let packed = check.pack();
+        let expect = vec![50u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::IncreaseAllowance { amount: 1 };
+        let packed = check.pack();
+        let expect = vec![51u8, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::DecreaseAllowance { amount: 1 };
+        let packed = check.pack();
+        let expect = vec![52u8, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializePermitNonce;
+        let packed = check.pack();
+        let expect = vec![53u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ApproveWithPermit {
+            amount: 1,
+            nonce: 2,
+            expiry: -1,
+        };
+        let packed = check.pack();
+        let mut expect = vec![54u8, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        expect.extend_from_slice(&[255u8; 8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::GetMinimumBalance {
+            kind: AccountKind::Multisig,
+        };
+        let packed = check.pack();
+        let expect = vec![55u8, 2];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::CreateAndInitializeMint {
+            decimals: 2,
+            mint_authority: Pubkey::new_from_array([1u8; 32]),
+            freeze_authority: COption::Some(Pubkey::new_from_array([3u8; 32])),
+        };
+        let packed = check.pack();
+        let mut expect = vec![56u8, 2];
+        expect.extend_from_slice(&[1u8; 32]);
+        expect.push(1);
+        expect.extend_from_slice(&[3u8; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::CreateAndInitializeAccount {
+            owner: Pubkey::new_from_array([2u8; 32]),
+        };
+        let packed = check.pack();
+        let mut expect = vec![57u8];
+        expect.extend_from_slice(&[2u8; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeAccountIdempotent {
+            owner: Pubkey::new_from_array([2u8; 32]),
+        };
+        let packed = check.pack();
+        let mut expect = vec![58u8];
+        expect.extend_from_slice(&[2u8; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::SweepExcessLamports { source_count: 3 };
+        let packed = check.pack();
+        let expect = vec![59u8, 3];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeClawbackConfig {
+            authority: Pubkey::new_from_array([2u8; 32]),
+        };
+        let packed = check.pack();
+        let mut expect = vec![60u8];
+        expect.extend_from_slice(&[2u8; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ClawbackTransfer { amount: 1 };
+        let packed = check.pack();
+        let expect = vec![61u8, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ClawbackBurn { amount: 1 };
+        let packed = check.pack();
+        let expect = vec![62u8, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::GetAccountDataSizeForKind {
+            kind: AccountKind::Account,
+            extension_types: vec![IMMUTABLE_OWNER_EXTENSION_TYPE],
+        };
+        let packed = check.pack();
+        let expect = vec![63u8, 0, 7, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::GetAccountDataSizeForKind {
+            kind: AccountKind::Multisig,
+            extension_types: vec![1, 0x0203],
+        };
+        let packed = check.pack();
+        let expect = vec![63u8, 2, 1, 0, 3, 2];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        assert_eq!(
+            TokenInstruction::unpack(&[63]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[63, 3]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[63, 0, 7]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+
+        let check = TokenInstruction::WithdrawExcessNativeLamports;
+        let packed = check.pack();
+        let expect = vec![64u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
         let check = TokenInstruction::Batch;
         let packed = check.pack();
         let expect = vec![255u8];
@@ -9,9 +9,17 @@ use {
     solana_pubkey::Pubkey,
 };
 
+pub mod amount;
+#[cfg(feature = "borsh")]
+pub mod codec;
+pub mod decode;
 pub mod error;
+pub mod filter;
 pub mod instruction;
 pub mod native_mint;
+#[cfg(feature = "serde")]
+pub mod parsed;
+pub mod permit;
 pub mod state;
 
 solana_pubkey::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
@@ -1,7 +1,237 @@
 //! The Mint that represents the native token
 
+use {
+    crate::{error::TokenError, state::Account},
+    solana_program_option::COption,
+    solana_pubkey::Pubkey,
+};
+
 /// There are `10^9` lamports in one SOL
 pub const DECIMALS: u8 = 9;
 
 // The Mint for native SOL Token accounts
 solana_pubkey::declare_id!("So11111111111111111111111111111111111111112");
+
+/// A native (wrapped SOL) token account together with its lamports balance.
+///
+/// The `is_native` field of a native account stores its rent-exempt reserve,
+/// and the token amount tracks the lamports held above that reserve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NativeAccount<'a> {
+    account: &'a Account,
+    lamports: u64,
+}
+
+/// Outcome of an `UnwrapLamports` instruction, as computed by
+/// [`NativeAccount::unwrap_plan`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnwrapPlan {
+    /// The amount of tokens (and lamports) unwrapped.
+    pub amount: u64,
+    /// The token amount of the account after the unwrap.
+    pub remaining_amount: u64,
+    /// The lamports of the account after the unwrap, when the destination is
+    /// a different account.
+    pub remaining_lamports: u64,
+    /// The delegated amount of the account after the unwrap.
+    pub remaining_delegated_amount: u64,
+    /// Indicates whether the delegate is cleared by the unwrap.
+    pub clears_delegate: bool,
+}
+
+impl<'a> NativeAccount<'a> {
+    /// Creates a view of a native token account holding `lamports`.
+    pub fn new(account: &'a Account, lamports: u64) -> Result<Self, TokenError> {
+        if account.is_native() {
+            Ok(Self { account, lamports })
+        } else {
+            Err(TokenError::NonNativeNotSupported)
+        }
+    }
+
+    /// The token account.
+    pub fn account(&self) -> &'a Account {
+        self.account
+    }
+
+    /// The lamports balance of the account.
+    pub fn lamports(&self) -> u64 {
+        self.lamports
+    }
+
+    /// The rent-exempt reserve stored in the account.
+    pub fn rent_exempt_reserve(&self) -> u64 {
+        self.account.is_native.unwrap_or_default()
+    }
+
+    /// Returns the lamports held by the account above its rent-exempt reserve
+    /// that are not yet reflected in its token amount.
+    ///
+    /// These lamports are wrapped by a `SyncNative` instruction when the rent
+    /// exemption does not change.
+    pub fn wrappable_lamports(&self) -> u64 {
+        self.lamports
+            .saturating_sub(self.rent_exempt_reserve())
+            .saturating_sub(self.account.amount)
+    }
+
+    /// Returns the token amount of the account after a `SyncNative`
+    /// instruction, given the rent-exempt reserve for the account data
+    /// length at the time the instruction is processed.
+    ///
+    /// `SyncNative` replaces the stored reserve with `rent_exempt_reserve`
+    /// and fails with [`TokenError::Overflow`] when the lamports do not
+    /// cover it.
+    pub fn expected_amount_after_sync(&self, rent_exempt_reserve: u64) -> Result<u64, TokenError> {
+        self.lamports
+            .checked_sub(rent_exempt_reserve)
+            .ok_or(TokenError::Overflow)
+    }
+
+    /// Returns the outcome of an `UnwrapLamports` instruction signed by
+    /// `authority`, which unwraps `amount` tokens or the whole balance when
+    /// `amount` is `None`.
+    ///
+    /// The authority is treated as the delegate when it matches the delegate
+    /// of the account, and as the owner otherwise; signatures are not
+    /// checked.
+    pub fn unwrap_plan(
+        &self,
+        authority: &Pubkey,
+        amount: Option<u64>,
+    ) -> Result<UnwrapPlan, TokenError> {
+        let (amount, remaining_amount) = if let Some(amount) = amount {
+            (
+                amount,
+                self.account
+                    .amount
+                    .checked_sub(amount)
+                    .ok_or(TokenError::InsufficientFunds)?,
+            )
+        } else {
+            (self.account.amount, 0)
+        };
+
+        let (remaining_delegated_amount, clears_delegate) =
+            if self.account.delegate == COption::Some(*authority) {
+                let delegated_amount = self
+                    .account
+                    .delegated_amount
+                    .checked_sub(amount)
+                    .ok_or(TokenError::InsufficientFunds)?;
+                (delegated_amount, delegated_amount == 0)
+            } else {
+                (self.account.delegated_amount, false)
+            };
+
+        Ok(UnwrapPlan {
+            amount,
+            remaining_amount,
+            remaining_lamports: self
+                .lamports
+                .checked_sub(amount)
+                .ok_or(TokenError::Overflow)?,
+            remaining_delegated_amount,
+            clears_delegate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::state::AccountState};
+
+    const RESERVE: u64 = 2_039_280;
+
+    fn native_account(amount: u64, delegate: Option<(Pubkey, u64)>) -> Account {
+        Account {
+            mint: id(),
+            owner: Pubkey::new_unique(),
+            amount,
+            delegate: delegate.map(|(delegate, _)| delegate).into(),
+            state: AccountState::Initialized,
+            is_native: COption::Some(RESERVE),
+            delegated_amount: delegate.map(|(_, amount)| amount).unwrap_or_default(),
+            close_authority: COption::None,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let mut account = native_account(0, None);
+        assert!(NativeAccount::new(&account, RESERVE).is_ok());
+
+        account.is_native = COption::None;
+        assert_eq!(
+            NativeAccount::new(&account, RESERVE),
+            Err(TokenError::NonNativeNotSupported)
+        );
+    }
+
+    #[test]
+    fn test_sync() {
+        let account = native_account(10, None);
+        let native = NativeAccount::new(&account, RESERVE + 25).unwrap();
+        assert_eq!(native.rent_exempt_reserve(), RESERVE);
+        assert_eq!(native.wrappable_lamports(), 15);
+        assert_eq!(native.expected_amount_after_sync(RESERVE), Ok(25));
+        // rent exemption increased
+        assert_eq!(native.expected_amount_after_sync(RESERVE + 5), Ok(20));
+        assert_eq!(
+            native.expected_amount_after_sync(RESERVE + 26),
+            Err(TokenError::Overflow)
+        );
+
+        // lamports below the reserve
+        let native = NativeAccount::new(&account, RESERVE - 1).unwrap();
+        assert_eq!(native.wrappable_lamports(), 0);
+    }
+
+    #[test]
+    fn test_unwrap_plan() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let account = native_account(100, Some((delegate, 40)));
+        let native = NativeAccount::new(&account, RESERVE + 100).unwrap();
+
+        assert_eq!(
+            native.unwrap_plan(&owner, None),
+            Ok(UnwrapPlan {
+                amount: 100,
+                remaining_amount: 0,
+                remaining_lamports: RESERVE,
+                remaining_delegated_amount: 40,
+                clears_delegate: false,
+            })
+        );
+        assert_eq!(
+            native.unwrap_plan(&owner, Some(101)),
+            Err(TokenError::InsufficientFunds)
+        );
+
+        assert_eq!(
+            native.unwrap_plan(&delegate, Some(30)),
+            Ok(UnwrapPlan {
+                amount: 30,
+                remaining_amount: 70,
+                remaining_lamports: RESERVE + 70,
+                remaining_delegated_amount: 10,
+                clears_delegate: false,
+            })
+        );
+        assert_eq!(
+            native.unwrap_plan(&delegate, Some(40)).unwrap(),
+            UnwrapPlan {
+                amount: 40,
+                remaining_amount: 60,
+                remaining_lamports: RESERVE + 60,
+                remaining_delegated_amount: 0,
+                clears_delegate: true,
+            }
+        );
+        assert_eq!(
+            native.unwrap_plan(&delegate, None),
+            Err(TokenError::InsufficientFunds)
+        );
+    }
+}
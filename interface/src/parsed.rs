@@ -0,0 +1,401 @@
+//! JSON-parsed representation of token program accounts.
+//!
+//! The types in this module serialize to the "jsonParsed" shape returned by
+//! RPC nodes for token program accounts: public keys are base58 strings,
+//! amounts are strings and token amounts include their UI representation
+//! computed from the mint decimals.
+//!
+//! [`Mint`] and [`Multisig`] serialize through [`UiMint`] and
+//! [`UiMultisig`]. Since the token amounts of an [`Account`] depend on the
+//! decimals of its mint, accounts are serialized through
+//! [`UiTokenAccount::new`].
+
+use {
+    crate::{
+        amount::TokenAmount,
+        instruction::MAX_SIGNERS,
+        state::{Account, AccountState, Mint, Multisig},
+    },
+    serde::{Deserialize, Serialize},
+    solana_program_error::ProgramError,
+    solana_program_option::COption,
+    solana_pubkey::Pubkey,
+    std::str::FromStr,
+};
+
+/// A token amount with its UI representation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTokenAmount {
+    /// The amount as a floating point number, which may lose precision.
+    pub ui_amount: Option<f64>,
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// The raw amount.
+    pub amount: String,
+    /// The exact UI amount, without trailing zeros.
+    pub ui_amount_string: String,
+}
+
+impl UiTokenAmount {
+    /// Creates the UI representation of a raw amount.
+    pub fn new(amount: u64, decimals: u8) -> Self {
+        Self {
+            ui_amount: Some(amount as f64 / 10_f64.powi(decimals as i32)),
+            decimals,
+            amount: amount.to_string(),
+            ui_amount_string: TokenAmount::new(amount, decimals).to_ui_amount_string_trimmed(),
+        }
+    }
+
+    fn raw_amount(&self) -> Result<u64, ProgramError> {
+        self.amount
+            .parse()
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// JSON-parsed token account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTokenAccount {
+    /// The mint associated with the account.
+    pub mint: String,
+    /// The owner of the account.
+    pub owner: String,
+    /// The amount of tokens the account holds.
+    pub token_amount: UiTokenAmount,
+    /// The delegate of the account, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<String>,
+    /// The account's state.
+    pub state: AccountState,
+    /// Whether the account holds wrapped SOL.
+    pub is_native: bool,
+    /// The rent-exempt reserve of a native account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rent_exempt_reserve: Option<UiTokenAmount>,
+    /// The amount delegated, present when the account has a delegate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegated_amount: Option<UiTokenAmount>,
+    /// The close authority of the account, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_authority: Option<String>,
+}
+
+impl UiTokenAccount {
+    /// Creates the JSON-parsed representation of an account, using the
+    /// decimals of its mint.
+    pub fn new(account: &Account, decimals: u8) -> Self {
+        Self {
+            mint: account.mint.to_string(),
+            owner: account.owner.to_string(),
+            token_amount: UiTokenAmount::new(account.amount, decimals),
+            delegate: Option::from(account.delegate).map(|delegate: Pubkey| delegate.to_string()),
+            state: account.state,
+            is_native: account.is_native(),
+            rent_exempt_reserve: Option::from(account.is_native)
+                .map(|reserve| UiTokenAmount::new(reserve, decimals)),
+            delegated_amount: account
+                .delegate
+                .map(|_| UiTokenAmount::new(account.delegated_amount, decimals))
+                .into(),
+            close_authority: Option::from(account.close_authority)
+                .map(|close_authority: Pubkey| close_authority.to_string()),
+        }
+    }
+}
+
+impl TryFrom<UiTokenAccount> for Account {
+    type Error = ProgramError;
+
+    fn try_from(account: UiTokenAccount) -> Result<Self, Self::Error> {
+        let is_native = match (account.is_native, account.rent_exempt_reserve) {
+            (true, Some(reserve)) => COption::Some(reserve.raw_amount()?),
+            (false, None) => COption::None,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        Ok(Account {
+            mint: parse_pubkey(&account.mint)?,
+            owner: parse_pubkey(&account.owner)?,
+            amount: account.token_amount.raw_amount()?,
+            delegate: parse_pubkey_option(account.delegate.as_deref())?,
+            state: account.state,
+            is_native,
+            delegated_amount: account
+                .delegated_amount
+                .map(|amount| amount.raw_amount())
+                .transpose()?
+                .unwrap_or_default(),
+            close_authority: parse_pubkey_option(account.close_authority.as_deref())?,
+        })
+    }
+}
+
+/// JSON-parsed mint.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiMint {
+    /// The authority used to mint new tokens, if any.
+    pub mint_authority: Option<String>,
+    /// Total supply of tokens.
+    pub supply: String,
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// Whether the mint has been initialized.
+    pub is_initialized: bool,
+    /// The authority used to freeze token accounts, if any.
+    pub freeze_authority: Option<String>,
+}
+
+impl From<Mint> for UiMint {
+    fn from(mint: Mint) -> Self {
+        Self {
+            mint_authority: Option::from(mint.mint_authority)
+                .map(|authority: Pubkey| authority.to_string()),
+            supply: mint.supply.to_string(),
+            decimals: mint.decimals,
+            is_initialized: mint.is_initialized,
+            freeze_authority: Option::from(mint.freeze_authority)
+                .map(|authority: Pubkey| authority.to_string()),
+        }
+    }
+}
+
+impl TryFrom<UiMint> for Mint {
+    type Error = ProgramError;
+
+    fn try_from(mint: UiMint) -> Result<Self, Self::Error> {
+        Ok(Mint {
+            mint_authority: parse_pubkey_option(mint.mint_authority.as_deref())?,
+            supply: mint
+                .supply
+                .parse()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+            decimals: mint.decimals,
+            is_initialized: mint.is_initialized,
+            freeze_authority: parse_pubkey_option(mint.freeze_authority.as_deref())?,
+        })
+    }
+}
+
+/// JSON-parsed multisignature account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiMultisig {
+    /// Number of signers required.
+    pub num_required_signers: u8,
+    /// Number of valid signers.
+    pub num_valid_signers: u8,
+    /// Whether the account has been initialized.
+    pub is_initialized: bool,
+    /// The valid signers.
+    pub signers: Vec<String>,
+}
+
+impl From<Multisig> for UiMultisig {
+    fn from(multisig: Multisig) -> Self {
+        Self {
+            num_required_signers: multisig.m,
+            num_valid_signers: multisig.n,
+            is_initialized: multisig.is_initialized,
+            signers: multisig
+                .signers
+                .iter()
+                .take(multisig.n as usize)
+                .map(Pubkey::to_string)
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<UiMultisig> for Multisig {
+    type Error = ProgramError;
+
+    fn try_from(multisig: UiMultisig) -> Result<Self, Self::Error> {
+        if multisig.signers.len() > MAX_SIGNERS {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let mut signers = [Pubkey::default(); MAX_SIGNERS];
+        for (signer, value) in signers.iter_mut().zip(&multisig.signers) {
+            *signer = parse_pubkey(value)?;
+        }
+        Ok(Multisig {
+            m: multisig.num_required_signers,
+            n: multisig.num_valid_signers,
+            is_initialized: multisig.is_initialized,
+            signers,
+        })
+    }
+}
+
+fn parse_pubkey(value: &str) -> Result<Pubkey, ProgramError> {
+    Pubkey::from_str(value).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+fn parse_pubkey_option(value: Option<&str>) -> Result<COption<Pubkey>, ProgramError> {
+    value.map(parse_pubkey).transpose().map(COption::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    const MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    const OWNER: &str = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T";
+    const DELEGATE: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    #[test]
+    fn test_token_account_golden() {
+        let account = Account {
+            mint: Pubkey::from_str(MINT).unwrap(),
+            owner: Pubkey::from_str(OWNER).unwrap(),
+            amount: 1_500_000,
+            delegate: COption::Some(Pubkey::from_str(DELEGATE).unwrap()),
+            state: AccountState::Frozen,
+            is_native: COption::None,
+            delegated_amount: 250_000,
+            close_authority: COption::None,
+        };
+        let ui_account = UiTokenAccount::new(&account, 6);
+
+        assert_eq!(
+            serde_json::to_value(&ui_account).unwrap(),
+            json!({
+                "mint": MINT,
+                "owner": OWNER,
+                "tokenAmount": {
+                    "uiAmount": 1.5,
+                    "decimals": 6,
+                    "amount": "1500000",
+                    "uiAmountString": "1.5"
+                },
+                "delegate": DELEGATE,
+                "state": "frozen",
+                "isNative": false,
+                "delegatedAmount": {
+                    "uiAmount": 0.25,
+                    "decimals": 6,
+                    "amount": "250000",
+                    "uiAmountString": "0.25"
+                }
+            })
+        );
+
+        let round_trip: UiTokenAccount =
+            serde_json::from_str(&serde_json::to_string(&ui_account).unwrap()).unwrap();
+        assert_eq!(Account::try_from(round_trip).unwrap(), account);
+    }
+
+    #[test]
+    fn test_native_token_account_golden() {
+        let account = Account {
+            mint: crate::native_mint::id(),
+            owner: Pubkey::from_str(OWNER).unwrap(),
+            amount: 1_000_000_000,
+            state: AccountState::Initialized,
+            is_native: COption::Some(2_039_280),
+            close_authority: COption::Some(Pubkey::from_str(DELEGATE).unwrap()),
+            ..Account::default()
+        };
+        let ui_account = UiTokenAccount::new(&account, 9);
+
+        assert_eq!(
+            serde_json::to_string(&ui_account).unwrap(),
+            format!(
+                "{{\"mint\":\"So11111111111111111111111111111111111111112\",\
+                 \"owner\":\"{OWNER}\",\
+                 \"tokenAmount\":{{\"uiAmount\":1.0,\"decimals\":9,\"amount\":\"1000000000\",\"uiAmountString\":\"1\"}},\
+                 \"state\":\"initialized\",\
+                 \"isNative\":true,\
+                 \"rentExemptReserve\":{{\"uiAmount\":0.00203928,\"decimals\":9,\"amount\":\"2039280\",\"uiAmountString\":\"0.00203928\"}},\
+                 \"closeAuthority\":\"{DELEGATE}\"}}"
+            )
+        );
+
+        let round_trip: UiTokenAccount =
+            serde_json::from_str(&serde_json::to_string(&ui_account).unwrap()).unwrap();
+        assert_eq!(Account::try_from(round_trip).unwrap(), account);
+    }
+
+    #[test]
+    fn test_mint_golden() {
+        let mint = Mint {
+            mint_authority: COption::Some(Pubkey::from_str(OWNER).unwrap()),
+            supply: u64::MAX,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&mint).unwrap(),
+            format!(
+                "{{\"mintAuthority\":\"{OWNER}\",\"supply\":\"18446744073709551615\",\
+                 \"decimals\":6,\"isInitialized\":true,\"freezeAuthority\":null}}"
+            )
+        );
+        assert_eq!(
+            serde_json::from_str::<Mint>(&serde_json::to_string(&mint).unwrap()).unwrap(),
+            mint
+        );
+    }
+
+    #[test]
+    fn test_multisig_golden() {
+        let mut signers = [Pubkey::default(); MAX_SIGNERS];
+        signers[0] = Pubkey::from_str(OWNER).unwrap();
+        signers[1] = Pubkey::from_str(DELEGATE).unwrap();
+        let multisig = Multisig {
+            m: 1,
+            n: 2,
+            is_initialized: true,
+            signers,
+        };
+
+        assert_eq!(
+            serde_json::to_value(multisig).unwrap(),
+            json!({
+                "numRequiredSigners": 1,
+                "numValidSigners": 2,
+                "isInitialized": true,
+                "signers": [OWNER, DELEGATE]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Multisig>(serde_json::to_value(multisig).unwrap()).unwrap(),
+            multisig
+        );
+
+        let too_many_signers = json!({
+            "numRequiredSigners": 1,
+            "numValidSigners": 2,
+            "isInitialized": true,
+            "signers": vec![OWNER; MAX_SIGNERS + 1]
+        });
+        assert!(serde_json::from_value::<Multisig>(too_many_signers).is_err());
+    }
+
+    #[test]
+    fn test_enums_golden() {
+        assert_eq!(
+            serde_json::to_string(&[
+                AccountState::Uninitialized,
+                AccountState::Initialized,
+                AccountState::Frozen
+            ])
+            .unwrap(),
+            "[\"uninitialized\",\"initialized\",\"frozen\"]"
+        );
+        assert_eq!(
+            serde_json::to_string(&[
+                crate::instruction::AuthorityType::MintTokens,
+                crate::instruction::AuthorityType::FreezeAccount,
+                crate::instruction::AuthorityType::AccountOwner,
+                crate::instruction::AuthorityType::CloseAccount,
+            ])
+            .unwrap(),
+            "[\"mintTokens\",\"freezeAccount\",\"accountOwner\",\"closeAccount\"]"
+        );
+    }
+}
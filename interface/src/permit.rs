@@ -0,0 +1,265 @@
+//! Off-chain signed permits.
+//!
+//! A permit allows a delegate to be approved without the owner signing the
+//! transaction. The owner signs a [`Permit`] message off-chain, which is then
+//! verified by an ed25519 program instruction placed immediately before the
+//! `ApproveWithPermit` instruction.
+//!
+//! Signing permits and creating the ed25519 program instruction requires the
+//! `signer` feature.
+
+#[cfg(feature = "signer")]
+use {
+    crate::instruction::approve_with_permit,
+    solana_ed25519_program::new_ed25519_instruction_with_signature,
+    solana_instruction::Instruction,
+    solana_signature::Signature,
+    solana_signer::{Signer, SignerError},
+};
+use {
+    arrayref::{array_ref, array_refs},
+    solana_program_error::ProgramError,
+    solana_pubkey::Pubkey,
+};
+
+/// Domain separator prefixed to every permit message, preventing a permit
+/// signature from being valid for any other message format.
+pub const PERMIT_DOMAIN: &[u8; 16] = b"spl-token:permit";
+
+/// Length of a packed permit message.
+pub const PERMIT_MESSAGE_LEN: usize = 168;
+
+/// Offset of the signature offsets in an ed25519 program instruction data.
+const ED25519_SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Offset of the data following the signature offsets in an ed25519 program
+/// instruction data.
+const ED25519_DATA_START: usize = 16;
+
+/// Message signed by the owner of a token account to approve a delegate.
+///
+/// The packed message layout is:
+///
+///   - `[u8; 16]` The [`PERMIT_DOMAIN`] separator.
+///   - `Pubkey` The token account.
+///   - `Pubkey` The token mint.
+///   - `Pubkey` The delegate.
+///   - `Pubkey` The owner's [`PermitNonce`](crate::state::PermitNonce)
+///     account.
+///   - `u64` The amount of tokens the delegate is approved for.
+///   - `u64` The nonce of the permit nonce account.
+///   - `i64` The unix timestamp after which the permit expires.
+///
+/// Binding the permit nonce account prevents a used permit from being
+/// replayed against another permit nonce account of the same owner.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Permit {
+    /// The token account.
+    pub account: Pubkey,
+    /// The token mint.
+    pub mint: Pubkey,
+    /// The delegate.
+    pub delegate: Pubkey,
+    /// The owner's permit nonce account.
+    pub permit_nonce: Pubkey,
+    /// The amount of tokens the delegate is approved for.
+    pub amount: u64,
+    /// The nonce of the permit nonce account.
+    pub nonce: u64,
+    /// The unix timestamp after which the permit expires.
+    pub expiry: i64,
+}
+
+impl Permit {
+    /// Packs the permit into the message signed by the owner.
+    pub fn pack(&self) -> [u8; PERMIT_MESSAGE_LEN] {
+        let mut message = [0; PERMIT_MESSAGE_LEN];
+        message[..16].copy_from_slice(PERMIT_DOMAIN);
+        message[16..48].copy_from_slice(self.account.as_ref());
+        message[48..80].copy_from_slice(self.mint.as_ref());
+        message[80..112].copy_from_slice(self.delegate.as_ref());
+        message[112..144].copy_from_slice(self.permit_nonce.as_ref());
+        message[144..152].copy_from_slice(&self.amount.to_le_bytes());
+        message[152..160].copy_from_slice(&self.nonce.to_le_bytes());
+        message[160..168].copy_from_slice(&self.expiry.to_le_bytes());
+        message
+    }
+
+    /// Unpacks a permit from a signed message.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != PERMIT_MESSAGE_LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let input = array_ref![input, 0, PERMIT_MESSAGE_LEN];
+        let (domain, account, mint, delegate, permit_nonce, amount, nonce, expiry) =
+            array_refs![input, 16, 32, 32, 32, 32, 8, 8, 8];
+        if domain != PERMIT_DOMAIN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(Permit {
+            account: Pubkey::new_from_array(*account),
+            mint: Pubkey::new_from_array(*mint),
+            delegate: Pubkey::new_from_array(*delegate),
+            permit_nonce: Pubkey::new_from_array(*permit_nonce),
+            amount: u64::from_le_bytes(*amount),
+            nonce: u64::from_le_bytes(*nonce),
+            expiry: i64::from_le_bytes(*expiry),
+        })
+    }
+
+    /// Signs the permit message with the owner of the token account.
+    #[cfg(feature = "signer")]
+    pub fn sign(&self, owner: &dyn Signer) -> Result<Signature, SignerError> {
+        owner.try_sign_message(&self.pack())
+    }
+
+    /// Creates the ed25519 program instruction verifying the owner's
+    /// signature of the permit.
+    #[cfg(feature = "signer")]
+    pub fn verify_instruction(&self, owner_pubkey: &Pubkey, signature: &Signature) -> Instruction {
+        new_ed25519_instruction_with_signature(
+            &self.pack(),
+            signature.as_array(),
+            &owner_pubkey.to_bytes(),
+        )
+    }
+
+    /// Signs the permit and creates the ed25519 program instruction together
+    /// with the `ApproveWithPermit` instruction that consumes it.
+    ///
+    /// The instructions must be included in this order, one immediately
+    /// after the other, in the transaction.
+    #[cfg(feature = "signer")]
+    pub fn sign_and_approve(
+        &self,
+        token_program_id: &Pubkey,
+        owner: &dyn Signer,
+    ) -> Result<[Instruction; 2], ProgramError> {
+        let signature = self
+            .sign(owner)
+            .map_err(|_| ProgramError::MissingRequiredSignature)?;
+        Ok([
+            self.verify_instruction(&owner.pubkey(), &signature),
+            approve_with_permit(
+                token_program_id,
+                &self.account,
+                &self.delegate,
+                &self.permit_nonce,
+                self.amount,
+                self.nonce,
+                self.expiry,
+            )?,
+        ])
+    }
+}
+
+/// Returns the signer public key and message of an ed25519 program
+/// instruction data verifying a single signature with all its data
+/// contained in the instruction itself.
+///
+/// This is the only format accepted by `ApproveWithPermit`, since it
+/// guarantees that the verified public key and message are the ones read
+/// from the instruction.
+pub fn unpack_ed25519_instruction_data(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    // Only a single signature is supported.
+    if data.len() < ED25519_DATA_START || data[0] != 1 {
+        return None;
+    }
+
+    let read_u16 = |index: usize| {
+        let offset = ED25519_SIGNATURE_OFFSETS_START + index * 2;
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    };
+
+    // All data must be contained in the ed25519 instruction.
+    if read_u16(1) != u16::MAX || read_u16(3) != u16::MAX || read_u16(6) != u16::MAX {
+        return None;
+    }
+
+    let signature_offset = read_u16(0) as usize;
+    let public_key_offset = read_u16(2) as usize;
+    let message_offset = read_u16(4) as usize;
+    let message_size = read_u16(5) as usize;
+
+    data.get(signature_offset..signature_offset + 64)?;
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    let message = data.get(message_offset..message_offset + message_size)?;
+
+    Some((Pubkey::try_from(public_key).ok()?, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "signer")]
+    use solana_signer::null_signer::NullSigner;
+
+    #[test]
+    fn test_permit_pack_unpack() {
+        let permit = Permit {
+            account: Pubkey::new_from_array([1; 32]),
+            mint: Pubkey::new_from_array([2; 32]),
+            delegate: Pubkey::new_from_array([3; 32]),
+            permit_nonce: Pubkey::new_from_array([4; 32]),
+            amount: 5,
+            nonce: 6,
+            expiry: -7,
+        };
+        let message = permit.pack();
+        assert_eq!(&message[..16], PERMIT_DOMAIN);
+        assert_eq!(Permit::unpack(&message).unwrap(), permit);
+
+        let mut message = message;
+        message[0] = 0;
+        assert_eq!(
+            Permit::unpack(&message),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Permit::unpack(&message[1..]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[cfg(feature = "signer")]
+    #[test]
+    fn test_unpack_ed25519_instruction_data() {
+        let owner = Pubkey::new_unique();
+        let permit = Permit::default();
+        let signature = Signature::from([7; 64]);
+
+        let instruction = permit.verify_instruction(&owner, &signature);
+        let (public_key, message) = unpack_ed25519_instruction_data(&instruction.data).unwrap();
+        assert_eq!(public_key, owner);
+        assert_eq!(message, permit.pack());
+
+        // signature offsets pointing to another instruction
+        let mut data = instruction.data.clone();
+        data[4] = 0;
+        data[5] = 0;
+        assert!(unpack_ed25519_instruction_data(&data).is_none());
+
+        // multiple signatures
+        let mut data = instruction.data;
+        data[0] = 2;
+        assert!(unpack_ed25519_instruction_data(&data).is_none());
+    }
+
+    #[cfg(feature = "signer")]
+    #[test]
+    fn test_sign_and_approve() {
+        let owner = NullSigner::new(&Pubkey::new_unique());
+        let permit = Permit {
+            account: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+            permit_nonce: Pubkey::new_unique(),
+            amount: 10,
+            ..Permit::default()
+        };
+        let [verify, approve] = permit.sign_and_approve(&crate::id(), &owner).unwrap();
+        assert_eq!(verify.program_id, solana_sdk_ids::ed25519_program::id());
+        assert_eq!(approve.accounts[0].pubkey, permit.account);
+        assert_eq!(approve.accounts[1].pubkey, permit.delegate);
+        assert_eq!(approve.accounts[2].pubkey, permit.permit_nonce);
+    }
+}
@@ -13,6 +13,11 @@ use {
 /// Mint data.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "crate::parsed::UiMint", try_from = "crate::parsed::UiMint")
+)]
 pub struct Mint {
     /// Optional authority used to mint new tokens. The mint authority may only
     /// be provided during mint creation. If no mint authority is present
@@ -182,6 +187,11 @@ impl Pack for Account {
 /// Account state.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, TryFromPrimitive)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub enum AccountState {
     /// Account is not yet initialized
     #[default]
@@ -198,6 +208,14 @@ pub enum AccountState {
 /// Multisignature data.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "crate::parsed::UiMultisig",
+        try_from = "crate::parsed::UiMultisig"
+    )
+)]
 pub struct Multisig {
     /// Number of signers required
     pub m: u8,
@@ -249,6 +267,120 @@ impl Pack for Multisig {
     }
 }
 
+/// Permit nonce data, used to prevent permits from being replayed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PermitNonce {
+    /// The owner whose permits are tracked by this account.
+    pub owner: Pubkey,
+    /// The nonce expected by the next permit.
+    pub nonce: u64,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+}
+impl Sealed for PermitNonce {}
+impl IsInitialized for PermitNonce {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+impl Pack for PermitNonce {
+    const LEN: usize = 41;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 41];
+        let (owner, nonce, is_initialized) = array_refs![src, 32, 8, 1];
+        Ok(PermitNonce {
+            owner: Pubkey::new_from_array(*owner),
+            nonce: u64::from_le_bytes(*nonce),
+            is_initialized: match is_initialized {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+        })
+    }
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 41];
+        let (owner_dst, nonce_dst, is_initialized_dst) = mut_array_refs![dst, 32, 8, 1];
+        let &PermitNonce {
+            ref owner,
+            nonce,
+            is_initialized,
+        } = self;
+        owner_dst.copy_from_slice(owner.as_ref());
+        *nonce_dst = nonce.to_le_bytes();
+        is_initialized_dst[0] = is_initialized as u8;
+    }
+}
+
+/// Seed of the clawback configuration address of a mint, derived from the
+/// token program id as `[CLAWBACK_CONFIG_SEED, mint]`.
+pub const CLAWBACK_CONFIG_SEED: &[u8] = b"clawback";
+
+/// Finds the clawback configuration address of a mint and its bump seed.
+pub fn find_clawback_config_address(mint: &Pubkey, token_program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAWBACK_CONFIG_SEED, mint.as_ref()], token_program_id)
+}
+
+/// Clawback configuration of a mint, allowing an authority to transfer or
+/// burn tokens from any account of the mint.
+///
+/// The configuration is kept in a companion account at the program derived
+/// address of the mint, and can only be created while the mint is not yet
+/// initialized, so that holders can tell whether a mint has the capability
+/// from the start.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClawbackConfig {
+    /// The mint this configuration applies to.
+    pub mint: Pubkey,
+    /// The authority allowed to transfer or burn tokens from any account of
+    /// the mint.
+    pub authority: Pubkey,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// The bump seed of the configuration address.
+    pub bump: u8,
+}
+impl Sealed for ClawbackConfig {}
+impl IsInitialized for ClawbackConfig {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+impl Pack for ClawbackConfig {
+    const LEN: usize = 66;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 66];
+        let (mint, authority, is_initialized, bump) = array_refs![src, 32, 32, 1, 1];
+        Ok(ClawbackConfig {
+            mint: Pubkey::new_from_array(*mint),
+            authority: Pubkey::new_from_array(*authority),
+            is_initialized: match is_initialized {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+            bump: bump[0],
+        })
+    }
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 66];
+        let (mint_dst, authority_dst, is_initialized_dst, bump_dst) =
+            mut_array_refs![dst, 32, 32, 1, 1];
+        let &ClawbackConfig {
+            ref mint,
+            ref authority,
+            is_initialized,
+            bump,
+        } = self;
+        mint_dst.copy_from_slice(mint.as_ref());
+        authority_dst.copy_from_slice(authority.as_ref());
+        is_initialized_dst[0] = is_initialized as u8;
+        bump_dst[0] = bump;
+    }
+}
+
 // Helpers
 fn pack_coption_key(src: &COption<Pubkey>, dst: &mut [u8; 36]) {
     let (tag, body) = mut_array_refs![dst, 4, 32];
@@ -291,8 +423,11 @@ fn unpack_coption_u64(src: &[u8; 12]) -> Result<COption<u64>, ProgramError> {
     }
 }
 
-const SPL_TOKEN_ACCOUNT_MINT_OFFSET: usize = 0;
-const SPL_TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+pub(crate) const SPL_TOKEN_ACCOUNT_MINT_OFFSET: usize = 0;
+pub(crate) const SPL_TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+const SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+pub(crate) const SPL_TOKEN_ACCOUNT_DELEGATE_OFFSET: usize = 72;
+const SPL_TOKEN_ACCOUNT_DELEGATED_AMOUNT_OFFSET: usize = 121;
 
 /// A trait for token Account structs to enable efficiently unpacking various
 /// fields without unpacking the complete state.
@@ -336,6 +471,56 @@ pub trait GenericTokenAccount {
             None
         }
     }
+
+    /// Call after account length has already been verified to unpack the
+    /// account amount
+    fn unpack_account_amount_unchecked(account_data: &[u8]) -> u64 {
+        unpack_u64_unchecked(account_data, SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET)
+    }
+
+    /// Unpacks an account's amount from opaque account data.
+    fn unpack_account_amount(account_data: &[u8]) -> Option<u64> {
+        if Self::valid_account_data(account_data) {
+            Some(Self::unpack_account_amount_unchecked(account_data))
+        } else {
+            None
+        }
+    }
+
+    /// Unpacks an account's state from opaque account data.
+    fn unpack_account_state(account_data: &[u8]) -> Option<AccountState> {
+        if Self::valid_account_data(account_data) {
+            AccountState::try_from(account_data[ACCOUNT_INITIALIZED_INDEX]).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Unpacks an account's delegate from opaque account data.
+    fn unpack_account_delegate(account_data: &[u8]) -> Option<COption<Pubkey>> {
+        if Self::valid_account_data(account_data) {
+            unpack_coption_key_at(account_data, SPL_TOKEN_ACCOUNT_DELEGATE_OFFSET)
+        } else {
+            None
+        }
+    }
+
+    /// Call after account length has already been verified to unpack the
+    /// account delegated amount
+    fn unpack_account_delegated_amount_unchecked(account_data: &[u8]) -> u64 {
+        unpack_u64_unchecked(account_data, SPL_TOKEN_ACCOUNT_DELEGATED_AMOUNT_OFFSET)
+    }
+
+    /// Unpacks an account's delegated amount from opaque account data.
+    fn unpack_account_delegated_amount(account_data: &[u8]) -> Option<u64> {
+        if Self::valid_account_data(account_data) {
+            Some(Self::unpack_account_delegated_amount_unchecked(
+                account_data,
+            ))
+        } else {
+            None
+        }
+    }
 }
 
 /// The offset of state field in Account's C representation
@@ -356,6 +541,155 @@ impl GenericTokenAccount for Account {
     }
 }
 
+/// Call after data length has already been verified to unpack a `u64` at the
+/// specified offset.
+fn unpack_u64_unchecked(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(*array_ref![data, offset, 8])
+}
+
+/// Call after data length has already been verified to unpack a
+/// `COption<Pubkey>` at the specified offset. Returns `None` if the option
+/// tag is invalid.
+fn unpack_coption_key_at(data: &[u8], offset: usize) -> Option<COption<Pubkey>> {
+    unpack_coption_key(array_ref![data, offset, 36]).ok()
+}
+
+const SPL_TOKEN_MINT_AUTHORITY_OFFSET: usize = 0;
+const SPL_TOKEN_MINT_SUPPLY_OFFSET: usize = 36;
+const SPL_TOKEN_MINT_DECIMALS_OFFSET: usize = 44;
+const SPL_TOKEN_MINT_FREEZE_AUTHORITY_OFFSET: usize = 46;
+
+/// A trait for token Mint structs to enable efficiently unpacking various
+/// fields without unpacking the complete state.
+pub trait GenericMint {
+    /// Check if the account data is a valid mint
+    fn valid_mint_data(mint_data: &[u8]) -> bool;
+
+    /// Unpacks a mint's mint authority from opaque account data.
+    fn unpack_mint_authority(mint_data: &[u8]) -> Option<COption<Pubkey>> {
+        if Self::valid_mint_data(mint_data) {
+            unpack_coption_key_at(mint_data, SPL_TOKEN_MINT_AUTHORITY_OFFSET)
+        } else {
+            None
+        }
+    }
+
+    /// Call after account length has already been verified to unpack the
+    /// mint supply
+    fn unpack_mint_supply_unchecked(mint_data: &[u8]) -> u64 {
+        unpack_u64_unchecked(mint_data, SPL_TOKEN_MINT_SUPPLY_OFFSET)
+    }
+
+    /// Unpacks a mint's supply from opaque account data.
+    fn unpack_mint_supply(mint_data: &[u8]) -> Option<u64> {
+        if Self::valid_mint_data(mint_data) {
+            Some(Self::unpack_mint_supply_unchecked(mint_data))
+        } else {
+            None
+        }
+    }
+
+    /// Call after account length has already been verified to unpack the
+    /// mint decimals
+    fn unpack_mint_decimals_unchecked(mint_data: &[u8]) -> u8 {
+        mint_data[SPL_TOKEN_MINT_DECIMALS_OFFSET]
+    }
+
+    /// Unpacks a mint's decimals from opaque account data.
+    fn unpack_mint_decimals(mint_data: &[u8]) -> Option<u8> {
+        if Self::valid_mint_data(mint_data) {
+            Some(Self::unpack_mint_decimals_unchecked(mint_data))
+        } else {
+            None
+        }
+    }
+
+    /// Unpacks a mint's freeze authority from opaque account data.
+    fn unpack_mint_freeze_authority(mint_data: &[u8]) -> Option<COption<Pubkey>> {
+        if Self::valid_mint_data(mint_data) {
+            unpack_coption_key_at(mint_data, SPL_TOKEN_MINT_FREEZE_AUTHORITY_OFFSET)
+        } else {
+            None
+        }
+    }
+}
+
+/// The offset of the `is_initialized` field in Mint's C representation
+pub const MINT_INITIALIZED_INDEX: usize = 45;
+
+impl GenericMint for Mint {
+    fn valid_mint_data(mint_data: &[u8]) -> bool {
+        mint_data.len() == Mint::LEN && mint_data[MINT_INITIALIZED_INDEX] == 1
+    }
+}
+
+const SPL_TOKEN_MULTISIG_M_OFFSET: usize = 0;
+const SPL_TOKEN_MULTISIG_N_OFFSET: usize = 1;
+const SPL_TOKEN_MULTISIG_SIGNERS_OFFSET: usize = 3;
+
+/// A trait for token Multisig structs to enable efficiently unpacking various
+/// fields without unpacking the complete state.
+pub trait GenericMultisig {
+    /// Check if the account data is a valid multisig
+    fn valid_multisig_data(multisig_data: &[u8]) -> bool;
+
+    /// Call after account length has already been verified to unpack the
+    /// number of signers required
+    fn unpack_multisig_m_unchecked(multisig_data: &[u8]) -> u8 {
+        multisig_data[SPL_TOKEN_MULTISIG_M_OFFSET]
+    }
+
+    /// Unpacks a multisig's number of signers required from opaque account
+    /// data.
+    fn unpack_multisig_m(multisig_data: &[u8]) -> Option<u8> {
+        if Self::valid_multisig_data(multisig_data) {
+            Some(Self::unpack_multisig_m_unchecked(multisig_data))
+        } else {
+            None
+        }
+    }
+
+    /// Call after account length has already been verified to unpack the
+    /// number of valid signers
+    fn unpack_multisig_n_unchecked(multisig_data: &[u8]) -> u8 {
+        multisig_data[SPL_TOKEN_MULTISIG_N_OFFSET]
+    }
+
+    /// Unpacks a multisig's number of valid signers from opaque account data.
+    fn unpack_multisig_n(multisig_data: &[u8]) -> Option<u8> {
+        if Self::valid_multisig_data(multisig_data) {
+            Some(Self::unpack_multisig_n_unchecked(multisig_data))
+        } else {
+            None
+        }
+    }
+
+    /// Unpacks a multisig's valid signers from opaque account data.
+    ///
+    /// Only the first `n` signers are returned.
+    fn unpack_multisig_signers(multisig_data: &[u8]) -> Option<&[Pubkey]> {
+        if Self::valid_multisig_data(multisig_data) {
+            let n = Self::unpack_multisig_n_unchecked(multisig_data) as usize;
+            if n > MAX_SIGNERS {
+                return None;
+            }
+            let signers = &multisig_data[SPL_TOKEN_MULTISIG_SIGNERS_OFFSET..][..n * PUBKEY_BYTES];
+            Some(bytemuck::cast_slice(signers))
+        } else {
+            None
+        }
+    }
+}
+
+/// The offset of the `is_initialized` field in Multisig's C representation
+pub const MULTISIG_INITIALIZED_INDEX: usize = 2;
+
+impl GenericMultisig for Multisig {
+    fn valid_multisig_data(multisig_data: &[u8]) -> bool {
+        multisig_data.len() == Multisig::LEN && multisig_data[MULTISIG_INITIALIZED_INDEX] == 1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,6 +735,59 @@ mod tests {
         assert_eq!(multisig, ProgramError::InvalidAccountData);
     }
 
+    #[test]
+    fn test_permit_nonce_pack_unpack() {
+        let permit_nonce = PermitNonce {
+            owner: Pubkey::new_from_array([1; 32]),
+            nonce: 42,
+            is_initialized: true,
+        };
+        let mut dst = [0; PermitNonce::LEN];
+        PermitNonce::pack(permit_nonce, &mut dst).unwrap();
+        assert_eq!(PermitNonce::unpack(&dst).unwrap(), permit_nonce);
+
+        let mut src: [u8; PermitNonce::LEN] = [0; PermitNonce::LEN];
+        src[40] = 2;
+        let result = PermitNonce::unpack_from_slice(&src).unwrap_err();
+        assert_eq!(result, ProgramError::InvalidAccountData);
+    }
+
+    #[test]
+    fn test_clawback_config_pack_unpack() {
+        let clawback_config = ClawbackConfig {
+            mint: Pubkey::new_from_array([1; 32]),
+            authority: Pubkey::new_from_array([2; 32]),
+            is_initialized: true,
+            bump: 254,
+        };
+        let mut dst = [0; ClawbackConfig::LEN];
+        ClawbackConfig::pack(clawback_config, &mut dst).unwrap();
+        assert_eq!(ClawbackConfig::unpack(&dst).unwrap(), clawback_config);
+
+        let mut src: [u8; ClawbackConfig::LEN] = [0; ClawbackConfig::LEN];
+        src[64] = 2;
+        let result = ClawbackConfig::unpack_from_slice(&src).unwrap_err();
+        assert_eq!(result, ProgramError::InvalidAccountData);
+    }
+
+    #[test]
+    fn test_find_clawback_config_address() {
+        let mint = Pubkey::new_from_array([1; 32]);
+        let (address, bump) = find_clawback_config_address(&mint, &crate::id());
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[CLAWBACK_CONFIG_SEED, mint.as_ref(), &[bump]],
+                &crate::id()
+            )
+            .unwrap(),
+            address
+        );
+        assert_ne!(
+            find_clawback_config_address(&Pubkey::new_from_array([2; 32]), &crate::id()).0,
+            address
+        );
+    }
+
     #[test]
     fn test_unpack_coption_key() {
         let src: [u8; 36] = [0; 36];
@@ -459,6 +846,121 @@ mod tests {
         assert_eq!(result, Option::None);
     }
 
+    #[test]
+    fn test_unpack_token_fields() {
+        let delegate = Pubkey::new_unique();
+        let account = Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 1,
+            delegate: COption::Some(delegate),
+            state: AccountState::Frozen,
+            is_native: COption::None,
+            delegated_amount: 2,
+            close_authority: COption::None,
+        };
+        let mut src = [0; Account::LEN];
+        Account::pack(account, &mut src).unwrap();
+
+        assert_eq!(Account::unpack_account_amount(&src), Some(1));
+        assert_eq!(
+            Account::unpack_account_state(&src),
+            Some(AccountState::Frozen)
+        );
+        assert_eq!(
+            Account::unpack_account_delegate(&src),
+            Some(COption::Some(delegate))
+        );
+        assert_eq!(Account::unpack_account_delegated_amount(&src), Some(2));
+
+        // Invalid delegate option tag
+        let mut invalid = src;
+        invalid[72] = 2;
+        assert_eq!(Account::unpack_account_delegate(&invalid), None);
+
+        // Invalid state
+        let mut invalid = src;
+        invalid[ACCOUNT_INITIALIZED_INDEX] = 3;
+        assert_eq!(Account::unpack_account_state(&invalid), None);
+
+        // Uninitialized account
+        src[ACCOUNT_INITIALIZED_INDEX] = AccountState::Uninitialized as u8;
+        assert_eq!(Account::unpack_account_amount(&src), None);
+        assert_eq!(Account::unpack_account_state(&src), None);
+        assert_eq!(Account::unpack_account_delegate(&src), None);
+        assert_eq!(Account::unpack_account_delegated_amount(&src), None);
+    }
+
+    #[test]
+    fn test_unpack_mint_fields() {
+        let mint_authority = Pubkey::new_unique();
+        let mint = Mint {
+            mint_authority: COption::Some(mint_authority),
+            supply: 42,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut src = [0; Mint::LEN];
+        Mint::pack(mint, &mut src).unwrap();
+
+        assert_eq!(
+            Mint::unpack_mint_authority(&src),
+            Some(COption::Some(mint_authority))
+        );
+        assert_eq!(Mint::unpack_mint_supply(&src), Some(42));
+        assert_eq!(Mint::unpack_mint_decimals(&src), Some(6));
+        assert_eq!(
+            Mint::unpack_mint_freeze_authority(&src),
+            Some(COption::None)
+        );
+
+        // Wrong data length
+        assert_eq!(Mint::unpack_mint_supply(&src[..Mint::LEN - 1]), None);
+        let mut longer = [0; Mint::LEN + 1];
+        longer[..Mint::LEN].copy_from_slice(&src);
+        assert_eq!(Mint::unpack_mint_supply(&longer), None);
+        // A token account is not a mint
+        assert_eq!(Mint::unpack_mint_supply(&[1; Account::LEN]), None);
+
+        // Uninitialized mint
+        src[MINT_INITIALIZED_INDEX] = 0;
+        assert_eq!(Mint::unpack_mint_authority(&src), None);
+        assert_eq!(Mint::unpack_mint_supply(&src), None);
+        assert_eq!(Mint::unpack_mint_decimals(&src), None);
+        assert_eq!(Mint::unpack_mint_freeze_authority(&src), None);
+    }
+
+    #[test]
+    fn test_unpack_multisig_fields() {
+        let mut signers = [Pubkey::default(); MAX_SIGNERS];
+        signers[0] = Pubkey::new_unique();
+        signers[1] = Pubkey::new_unique();
+        let multisig = Multisig {
+            m: 1,
+            n: 2,
+            is_initialized: true,
+            signers,
+        };
+        let mut src = [0; Multisig::LEN];
+        Multisig::pack(multisig, &mut src).unwrap();
+
+        assert_eq!(Multisig::unpack_multisig_m(&src), Some(1));
+        assert_eq!(Multisig::unpack_multisig_n(&src), Some(2));
+        assert_eq!(Multisig::unpack_multisig_signers(&src), Some(&signers[..2]));
+
+        // Invalid number of signers
+        let mut invalid = src;
+        invalid[1] = MAX_SIGNERS as u8 + 1;
+        assert_eq!(Multisig::unpack_multisig_signers(&invalid), None);
+
+        // Uninitialized multisig
+        src[MULTISIG_INITIALIZED_INDEX] = 0;
+        assert_eq!(Multisig::unpack_multisig_m(&src), None);
+        assert_eq!(Multisig::unpack_multisig_n(&src), None);
+        assert_eq!(Multisig::unpack_multisig_signers(&src), None);
+    }
+
     #[test]
     fn test_unpack_token_mint() {
         // Account data length < Account::LEN, unpack will not return a key
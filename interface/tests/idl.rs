@@ -0,0 +1,728 @@
+//! Checks that `idl.json`, from which the clients are generated, matches the
+//! instructions, errors and account layouts of the interface.
+
+use {
+    serde_json::{json, Map, Value},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    spl_token_interface::{
+        decode::decode,
+        error::TokenError,
+        instruction::{self, AccountKind, AuthorityType, TokenInstruction},
+        state::{Account, AccountState, ClawbackConfig, Mint, Multisig, PermitNonce},
+    },
+    std::{collections::BTreeSet, str::FromStr},
+};
+
+fn idl() -> Value {
+    serde_json::from_str(include_str!("../../idl.json")).unwrap()
+}
+
+fn find<'a>(nodes: &'a Value, name: &str) -> &'a Value {
+    nodes
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|node| node["name"] == name)
+        .unwrap_or_else(|| panic!("missing IDL node `{name}`"))
+}
+
+fn number(value: &Value) -> i128 {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .map(i128::from)
+            .unwrap_or_else(|| number.as_u64().map(i128::from).unwrap()),
+        Value::String(number) => number.parse().unwrap(),
+        Value::Bool(value) => *value as i128,
+        _ => panic!("expected a number, found {value}"),
+    }
+}
+
+fn encode_number(format: &Value, value: i128, out: &mut Vec<u8>) {
+    match format.as_str().unwrap() {
+        "u8" => out.push(u8::try_from(value).unwrap()),
+        "u16" => out.extend_from_slice(&u16::try_from(value).unwrap().to_le_bytes()),
+        "u32" => out.extend_from_slice(&u32::try_from(value).unwrap().to_le_bytes()),
+        "u64" => out.extend_from_slice(&u64::try_from(value).unwrap().to_le_bytes()),
+        "i64" => out.extend_from_slice(&i64::try_from(value).unwrap().to_le_bytes()),
+        format => panic!("unsupported number format `{format}`"),
+    }
+}
+
+/// Returns the size of a fixed-size IDL type.
+fn fixed_size(ty: &Value) -> usize {
+    match ty["kind"].as_str().unwrap() {
+        "publicKeyTypeNode" => 32,
+        "numberTypeNode" => match ty["format"].as_str().unwrap() {
+            "u8" => 1,
+            "u16" => 2,
+            "u32" => 4,
+            "u64" | "i64" => 8,
+            format => panic!("unsupported number format `{format}`"),
+        },
+        kind => panic!("unsupported fixed-size type `{kind}`"),
+    }
+}
+
+/// Encodes a JSON value following an IDL type node.
+fn encode(idl: &Value, ty: &Value, value: &Value, out: &mut Vec<u8>) {
+    match ty["kind"].as_str().unwrap() {
+        "numberTypeNode" => encode_number(&ty["format"], number(value), out),
+        "booleanTypeNode" => {
+            encode_number(&ty["size"]["format"], value.as_bool().unwrap() as i128, out)
+        }
+        "publicKeyTypeNode" => {
+            let pubkey = Pubkey::from_str(value.as_str().unwrap()).unwrap();
+            out.extend_from_slice(pubkey.as_ref());
+        }
+        "optionTypeNode" => {
+            let fixed = ty["fixed"].as_bool().unwrap();
+            if value.is_null() {
+                encode_number(&ty["prefix"]["format"], 0, out);
+                if fixed {
+                    out.extend(std::iter::repeat_n(0, fixed_size(&ty["item"])));
+                }
+            } else {
+                encode_number(&ty["prefix"]["format"], 1, out);
+                encode(idl, &ty["item"], value, out);
+            }
+        }
+        "definedTypeLinkNode" => {
+            let defined = find(
+                &idl["program"]["definedTypes"],
+                ty["name"].as_str().unwrap(),
+            );
+            let ty = &defined["type"];
+            assert_eq!(ty["kind"], "enumTypeNode");
+            let index = ty["variants"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .position(|variant| variant["name"] == *value)
+                .unwrap_or_else(|| panic!("missing variant {value}"));
+            encode_number(&ty["size"]["format"], index as i128, out);
+        }
+        "stringTypeNode" => out.extend_from_slice(value.as_str().unwrap().as_bytes()),
+        "bytesTypeNode" => out.extend(
+            value
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|byte| u8::try_from(number(byte)).unwrap()),
+        ),
+        "sizePrefixTypeNode" => {
+            let mut inner = Vec::new();
+            encode(idl, &ty["type"], value, &mut inner);
+            encode_number(&ty["prefix"]["format"], inner.len() as i128, out);
+            out.extend_from_slice(&inner);
+        }
+        "arrayTypeNode" => {
+            let items = value.as_array().unwrap();
+            match ty["count"]["kind"].as_str().unwrap() {
+                "fixedCountNode" => assert_eq!(items.len() as u64, ty["count"]["value"]),
+                "remainderCountNode" => {}
+                kind => panic!("unsupported count `{kind}`"),
+            }
+            for item in items {
+                encode(idl, &ty["item"], item, out);
+            }
+        }
+        "structTypeNode" => {
+            for field in ty["fields"].as_array().unwrap() {
+                encode(
+                    idl,
+                    &field["type"],
+                    &value[field["name"].as_str().unwrap()],
+                    out,
+                );
+            }
+        }
+        kind => panic!("unsupported type `{kind}`"),
+    }
+}
+
+/// Returns sample instructions covering every instruction of the interface,
+/// each with the values of its arguments in the IDL.
+fn sample_instructions() -> Vec<(Instruction, Value)> {
+    let program_id = spl_token_interface::id();
+    let [a, b, c, d] = [(); 4].map(|_| Pubkey::new_unique());
+    let [b_str, c_str, d_str] = [b, c, d].map(|pubkey| pubkey.to_string());
+
+    vec![
+        (
+            instruction::initialize_mint(&program_id, &a, &b, Some(&c), 6).unwrap(),
+            json!({ "decimals": 6, "mintAuthority": b_str, "freezeAuthority": c_str }),
+        ),
+        (
+            instruction::initialize_account(&program_id, &a, &b, &c).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::initialize_multisig(&program_id, &a, &[&b, &c], 2).unwrap(),
+            json!({ "m": 2 }),
+        ),
+        (
+            instruction::transfer(&program_id, &a, &b, &c, &[], 1).unwrap(),
+            json!({ "amount": 1 }),
+        ),
+        (
+            instruction::approve(&program_id, &a, &b, &c, &[], 2).unwrap(),
+            json!({ "amount": 2 }),
+        ),
+        (
+            instruction::revoke(&program_id, &a, &b, &[]).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::set_authority(
+                &program_id,
+                &a,
+                Some(&b),
+                AuthorityType::CloseAccount,
+                &c,
+                &[],
+            )
+            .unwrap(),
+            json!({ "authorityType": "closeAccount", "newAuthority": b_str }),
+        ),
+        (
+            instruction::mint_to(&program_id, &a, &b, &c, &[], 3).unwrap(),
+            json!({ "amount": 3 }),
+        ),
+        (
+            instruction::burn(&program_id, &a, &b, &c, &[], 4).unwrap(),
+            json!({ "amount": 4 }),
+        ),
+        (
+            instruction::close_account(&program_id, &a, &b, &c, &[]).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::freeze_account(&program_id, &a, &b, &c, &[]).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::thaw_account(&program_id, &a, &b, &c, &[]).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::transfer_checked(&program_id, &a, &b, &c, &d, &[], 5, 2).unwrap(),
+            json!({ "amount": 5, "decimals": 2 }),
+        ),
+        (
+            instruction::approve_checked(&program_id, &a, &b, &c, &d, &[], 6, 2).unwrap(),
+            json!({ "amount": 6, "decimals": 2 }),
+        ),
+        (
+            instruction::mint_to_checked(&program_id, &a, &b, &c, &[], 7, 2).unwrap(),
+            json!({ "amount": 7, "decimals": 2 }),
+        ),
+        (
+            instruction::burn_checked(&program_id, &a, &b, &c, &[], 8, 2).unwrap(),
+            json!({ "amount": 8, "decimals": 2 }),
+        ),
+        (
+            instruction::initialize_account2(&program_id, &a, &b, &c).unwrap(),
+            json!({ "owner": c_str }),
+        ),
+        (
+            instruction::sync_native(&program_id, &a).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::sync_native_with_rent_sysvar(&program_id, &a).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::initialize_account3(&program_id, &a, &b, &c).unwrap(),
+            json!({ "owner": c_str }),
+        ),
+        (
+            instruction::initialize_multisig2(&program_id, &a, &[&b, &c], 1).unwrap(),
+            json!({ "m": 1 }),
+        ),
+        (
+            instruction::initialize_mint2(&program_id, &a, &b, None, 9).unwrap(),
+            json!({ "decimals": 9, "mintAuthority": b_str, "freezeAuthority": null }),
+        ),
+        (
+            instruction::get_account_data_size(&program_id, &a).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::initialize_immutable_owner(&program_id, &a).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::amount_to_ui_amount(&program_id, &a, 10).unwrap(),
+            json!({ "amount": 10 }),
+        ),
+        (
+            instruction::ui_amount_to_amount(&program_id, &a, "1.5").unwrap(),
+            json!({ "uiAmount": "1.5" }),
+        ),
+        (
+            instruction::withdraw_excess_lamports(&program_id, &a, &b, &c, &[]).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::unwrap_lamports(&program_id, &a, &b, &c, &[], Some(11)).unwrap(),
+            json!({ "amount": 11 }),
+        ),
+        (
+            instruction::unwrap_lamports(&program_id, &a, &b, &c, &[], None).unwrap(),
+            json!({ "amount": null }),
+        ),
+        (
+            instruction::sweep_and_close(&program_id, &a, &b, &c, &d, &[], true).unwrap(),
+            json!({ "burn": true }),
+        ),
+        (
+            instruction::burn_all(&program_id, &a, &b, &c, &[]).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::transfer_all(&program_id, &a, &b, &c, &[]).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::transfer_all_checked(&program_id, &a, &b, &c, &d, &[], 2).unwrap(),
+            json!({ "decimals": 2 }),
+        ),
+        (
+            instruction::approve_if_current(&program_id, &a, &b, &c, &[], Some(&d), 12, 13)
+                .unwrap(),
+            json!({ "expectedDelegate": d_str, "expectedAmount": 12, "newAmount": 13 }),
+        ),
+        (
+            instruction::approve_if_current(&program_id, &a, &b, &c, &[], None, 0, 14).unwrap(),
+            json!({ "expectedDelegate": null, "expectedAmount": 0, "newAmount": 14 }),
+        ),
+        (
+            instruction::increase_allowance(&program_id, &a, &b, &c, &[], 15).unwrap(),
+            json!({ "amount": 15 }),
+        ),
+        (
+            instruction::decrease_allowance(&program_id, &a, &b, &c, &[], 16).unwrap(),
+            json!({ "amount": 16 }),
+        ),
+        (
+            instruction::initialize_permit_nonce(&program_id, &a, &b).unwrap(),
+            json!({}),
+        ),
+        (
+            instruction::approve_with_permit(&program_id, &a, &b, &c, 17, 18, -19).unwrap(),
+            json!({ "amount": 17, "nonce": 18, "expiry": -19 }),
+        ),
+        (
+            instruction::get_minimum_balance(&program_id, AccountKind::Mint).unwrap(),
+            json!({ "kind": "mint" }),
+        ),
+        (
+            instruction::create_and_initialize_mint(&program_id, &a, &b, &c, Some(&d), 20).unwrap(),
+            json!({ "decimals": 20, "mintAuthority": c_str, "freezeAuthority": d_str }),
+        ),
+        (
+            instruction::create_and_initialize_account(&program_id, &a, &b, &c, &d).unwrap(),
+            json!({ "owner": c_str }),
+        ),
+        (
+            instruction::initialize_account_idempotent(&program_id, &a, &b, &c).unwrap(),
+            json!({ "owner": c_str }),
+        ),
+        (
+            instruction::sweep_excess_lamports(&program_id, &[&a], &b, &c, &[]).unwrap(),
+            json!({ "sourceCount": 1 }),
+        ),
+        (
+            instruction::sweep_excess_lamports(&program_id, &[&a, &d], &b, &c, &[]).unwrap(),
+            json!({ "sourceCount": 2 }),
+        ),
+        (
+            instruction::initialize_clawback_config(&program_id, &a, &b, &c, &d).unwrap(),
+            json!({ "authority": d_str }),
+        ),
+        (
+            instruction::clawback_transfer(&program_id, &a, &b, &c, &d, &[], 21).unwrap(),
+            json!({ "amount": 21 }),
+        ),
+        (
+            instruction::clawback_burn(&program_id, &a, &b, &c, &d, &[], 22).unwrap(),
+            json!({ "amount": 22 }),
+        ),
+        (
+            instruction::get_account_data_size_for_kind(
+                &program_id,
+                AccountKind::Account,
+                &[instruction::IMMUTABLE_OWNER_EXTENSION_TYPE],
+            )
+            .unwrap(),
+            json!({
+                "kind": "account",
+                "extensionTypes": [instruction::IMMUTABLE_OWNER_EXTENSION_TYPE],
+            }),
+        ),
+        (
+            instruction::get_account_data_size_for_kind(&program_id, AccountKind::Multisig, &[])
+                .unwrap(),
+            json!({ "kind": "multisig", "extensionTypes": [] }),
+        ),
+        (
+            instruction::withdraw_excess_native_lamports(&program_id, &a, &b, &c, &[&d]).unwrap(),
+            json!({}),
+        ),
+    ]
+}
+
+fn check_instruction(idl: &Value, instruction: &Instruction, values: &Value) {
+    let decoded = decode(instruction).unwrap();
+    let node = find(&idl["program"]["instructions"], decoded.name);
+    let name = decoded.name;
+
+    // Discriminator.
+    let arguments = node["arguments"].as_array().unwrap();
+    assert_eq!(arguments[0]["name"], "discriminator", "{name}");
+    assert_eq!(
+        arguments[0]["defaultValue"]["number"], instruction.data[0],
+        "{name}"
+    );
+
+    // Accounts.
+    let accounts = node["accounts"].as_array().unwrap();
+    let required = accounts
+        .iter()
+        .filter(|account| account["isOptional"] != true)
+        .count();
+    assert!(decoded.accounts.len() >= required, "{name}");
+    // Additional accounts of the same role as the last one (such as the extra
+    // sources of `sweepExcessLamports`) are passed as remaining accounts.
+    assert!(
+        decoded.accounts.len() <= accounts.len() || node.get("remainingAccounts").is_some(),
+        "{name}"
+    );
+    let repeated = accounts.last().into_iter().cycle();
+    for (account, decoded) in accounts.iter().chain(repeated).zip(&decoded.accounts) {
+        assert_eq!(account["name"], decoded.label, "{name}");
+        assert_eq!(account["isWritable"], decoded.is_writable, "{name}");
+        match &account["isSigner"] {
+            Value::Bool(is_signer) => assert_eq!(*is_signer, decoded.is_signer, "{name}"),
+            is_signer => assert_eq!(is_signer, "either", "{name}"),
+        }
+    }
+
+    // Remaining accounts are only accepted as multisig signers (or, for
+    // `initializeMultisig`, as the signers of the multisig).
+    let mut extended = instruction.clone();
+    extended
+        .accounts
+        .push(AccountMeta::new_readonly(Pubkey::new_unique(), true));
+    let extended = decode(&extended).unwrap();
+    assert_eq!(
+        node.get("remainingAccounts").is_some(),
+        extended.signers.len() > decoded.signers.len(),
+        "{name}"
+    );
+
+    // Arguments.
+    let arguments = &arguments[1..];
+    check_arguments(
+        idl,
+        name,
+        arguments,
+        values.as_object().unwrap(),
+        &instruction.data,
+    );
+
+    // The data of the JSON representation of the decoded instruction follows
+    // the IDL arguments too.
+    #[cfg(feature = "serde")]
+    {
+        let json: Value = serde_json::from_str(&decoded.to_json()).unwrap();
+        let mut data = json["data"].as_object().unwrap().clone();
+        data.remove("uiAmountString");
+        check_arguments(idl, name, arguments, &data, &instruction.data);
+    }
+}
+
+/// Checks that the instruction data is the discriminator followed by the
+/// values of the arguments encoded as described in the IDL.
+fn check_arguments(
+    idl: &Value,
+    name: &str,
+    arguments: &[Value],
+    values: &Map<String, Value>,
+    data: &[u8],
+) {
+    assert_eq!(
+        arguments
+            .iter()
+            .map(|argument| argument["name"].as_str().unwrap())
+            .collect::<BTreeSet<_>>(),
+        values.keys().map(String::as_str).collect::<BTreeSet<_>>(),
+        "{name}"
+    );
+    let mut encoded = vec![data[0]];
+    for argument in arguments {
+        let value = &values[argument["name"].as_str().unwrap()];
+        encode(idl, &argument["type"], value, &mut encoded);
+    }
+    assert_eq!(encoded, data, "{name}");
+}
+
+#[test]
+fn test_idl_discriminators() {
+    let idl = idl();
+    let idl_discriminators = idl["program"]["instructions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| number(&node["arguments"][0]["defaultValue"]["number"]) as u8)
+        .collect::<BTreeSet<_>>();
+
+    let discriminators = (0..=u8::MAX)
+        .filter(|discriminator| {
+            let mut data = vec![0; 100];
+            data[0] = *discriminator;
+            TokenInstruction::unpack(&data).is_ok()
+        })
+        .collect::<BTreeSet<_>>();
+
+    assert_eq!(idl_discriminators, discriminators);
+}
+
+#[test]
+fn test_idl_instructions() {
+    let idl = idl();
+    let samples = sample_instructions();
+
+    let mut names = BTreeSet::new();
+    for (instruction, values) in &samples {
+        check_instruction(&idl, instruction, values);
+        names.insert(decode(instruction).unwrap().name);
+    }
+    let instructions = samples
+        .into_iter()
+        .map(|(instruction, _)| instruction)
+        .collect::<Vec<_>>();
+
+    // Batch data is the list of inner instructions, each prefixed by its
+    // number of accounts and data length.
+    let batch = instruction::batch(&spl_token_interface::id(), &instructions[3..6]).unwrap();
+    let node = find(&idl["program"]["instructions"], "batch");
+    assert_eq!(
+        node["arguments"][0]["defaultValue"]["number"],
+        batch.data[0]
+    );
+    let value = instructions[3..6]
+        .iter()
+        .map(|instruction| {
+            json!({
+                "numberOfAccounts": instruction.accounts.len(),
+                "instructionData": instruction.data,
+            })
+        })
+        .collect::<Value>();
+    let mut encoded = vec![batch.data[0]];
+    encode(&idl, &node["arguments"][1]["type"], &value, &mut encoded);
+    assert_eq!(encoded, batch.data);
+    names.insert(decode(&batch).unwrap().name);
+
+    // Every IDL instruction is covered by the samples.
+    let idl_names = idl["program"]["instructions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["name"].as_str().unwrap())
+        .collect::<BTreeSet<_>>();
+    assert_eq!(idl_names, names);
+}
+
+#[test]
+fn test_idl_errors() {
+    let idl = idl();
+    let errors = idl["program"]["errors"].as_array().unwrap();
+
+    for error in errors {
+        let code = u32::try_from(number(&error["code"])).unwrap();
+        let token_error =
+            TokenError::try_from(code).unwrap_or_else(|_| panic!("unknown error code {code}"));
+        let mut name = format!("{token_error:?}");
+        name[..1].make_ascii_lowercase();
+        assert_eq!(error["name"], name);
+    }
+
+    let count = (0..)
+        .take_while(|code| TokenError::try_from(*code).is_ok())
+        .count();
+    assert_eq!(errors.len(), count);
+}
+
+#[test]
+fn test_idl_defined_types() {
+    let idl = idl();
+    let variants = |name: &str| {
+        find(&idl["program"]["definedTypes"], name)["type"]["variants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["name"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let account_states = [
+        AccountState::Uninitialized,
+        AccountState::Initialized,
+        AccountState::Frozen,
+    ];
+    assert_eq!(variants("accountState").len(), account_states.len());
+    for (index, state) in account_states.iter().enumerate() {
+        let mut name = format!("{state:?}");
+        name[..1].make_ascii_lowercase();
+        assert_eq!(variants("accountState")[index], name);
+        assert_eq!(*state as usize, index);
+    }
+
+    let authority_types = [
+        AuthorityType::MintTokens,
+        AuthorityType::FreezeAccount,
+        AuthorityType::AccountOwner,
+        AuthorityType::CloseAccount,
+    ];
+    assert_eq!(variants("authorityType").len(), authority_types.len());
+    for (index, authority_type) in authority_types.iter().enumerate() {
+        let mut name = format!("{authority_type:?}");
+        name[..1].make_ascii_lowercase();
+        assert_eq!(variants("authorityType")[index], name);
+        assert_eq!(authority_type.clone() as usize, index);
+    }
+
+    let account_kinds = [
+        AccountKind::Account,
+        AccountKind::Mint,
+        AccountKind::Multisig,
+    ];
+    assert_eq!(variants("accountKind").len(), account_kinds.len());
+    for (index, kind) in account_kinds.iter().enumerate() {
+        let mut name = format!("{kind:?}");
+        name[..1].make_ascii_lowercase();
+        assert_eq!(variants("accountKind")[index], name);
+        assert_eq!(*kind as usize, index);
+    }
+}
+
+fn check_account<T: Pack>(idl: &Value, name: &str, state: T, value: Value) {
+    let node = find(&idl["program"]["accounts"], name);
+    assert_eq!(node["size"], T::LEN, "{name}");
+
+    let mut packed = vec![0; T::LEN];
+    T::pack(state, &mut packed).unwrap();
+
+    let mut encoded = Vec::new();
+    encode(idl, &node["data"], &value, &mut encoded);
+    assert_eq!(encoded, packed, "{name}");
+}
+
+#[test]
+fn test_idl_accounts() {
+    let idl = idl();
+    let [a, b, c] = [(); 3].map(|_| Pubkey::new_unique());
+
+    check_account(
+        &idl,
+        "mint",
+        Mint {
+            mint_authority: COption::Some(a),
+            supply: 1,
+            decimals: 2,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        },
+        json!({
+            "mintAuthority": a.to_string(),
+            "supply": 1,
+            "decimals": 2,
+            "isInitialized": true,
+            "freezeAuthority": null,
+        }),
+    );
+
+    check_account(
+        &idl,
+        "token",
+        Account {
+            mint: a,
+            owner: b,
+            amount: 3,
+            delegate: COption::Some(c),
+            state: AccountState::Frozen,
+            is_native: COption::Some(4),
+            delegated_amount: 5,
+            close_authority: COption::None,
+        },
+        json!({
+            "mint": a.to_string(),
+            "owner": b.to_string(),
+            "amount": 3,
+            "delegate": c.to_string(),
+            "state": "frozen",
+            "isNative": 4,
+            "delegatedAmount": 5,
+            "closeAuthority": null,
+        }),
+    );
+
+    let mut signers = [Pubkey::default(); 11];
+    signers[0] = a;
+    signers[1] = b;
+    check_account(
+        &idl,
+        "multisig",
+        Multisig {
+            m: 1,
+            n: 2,
+            is_initialized: true,
+            signers,
+        },
+        json!({
+            "m": 1,
+            "n": 2,
+            "isInitialized": true,
+            "signers": signers.iter().map(Pubkey::to_string).collect::<Vec<_>>(),
+        }),
+    );
+
+    check_account(
+        &idl,
+        "permitNonce",
+        PermitNonce {
+            owner: c,
+            nonce: 6,
+            is_initialized: true,
+        },
+        json!({
+            "owner": c.to_string(),
+            "nonce": 6,
+            "isInitialized": true,
+        }),
+    );
+
+    check_account(
+        &idl,
+        "clawbackConfig",
+        ClawbackConfig {
+            mint: a,
+            authority: b,
+            is_initialized: true,
+            bump: 253,
+        },
+        json!({
+            "mint": a.to_string(),
+            "authority": b.to_string(),
+            "isInitialized": true,
+            "bump": 253,
+        }),
+    );
+}
@@ -0,0 +1,156 @@
+//! Cross-program invocation helpers.
+//!
+//! These builders create and invoke instructions on the token program
+//! without requiring an allocator. Multisignature authorities are not
+//! supported.
+
+use {
+    crate::{
+        instruction::{AccountKind, TokenInstruction},
+        program::ID,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::{get_return_data, invoke_signed},
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        ProgramResult,
+    },
+};
+
+/// Increases the amount of tokens the current delegate is approved for.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The current delegate.
+///   2. `[SIGNER]` The source account owner.
+pub struct IncreaseAllowance<'a> {
+    /// Source account.
+    pub source: &'a AccountInfo,
+    /// Current delegate account.
+    pub delegate: &'a AccountInfo,
+    /// Source account owner.
+    pub authority: &'a AccountInfo,
+    /// Amount of tokens to add to the delegated amount.
+    pub amount: u64,
+}
+
+impl IncreaseAllowance<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_allowance_update(
+            TokenInstruction::IncreaseAllowance,
+            [self.source, self.delegate, self.authority],
+            self.amount,
+            signers,
+        )
+    }
+}
+
+/// Decreases the amount of tokens the current delegate is approved for,
+/// revoking the delegate when the delegated amount reaches zero.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The current delegate.
+///   2. `[SIGNER]` The source account owner.
+pub struct DecreaseAllowance<'a> {
+    /// Source account.
+    pub source: &'a AccountInfo,
+    /// Current delegate account.
+    pub delegate: &'a AccountInfo,
+    /// Source account owner.
+    pub authority: &'a AccountInfo,
+    /// Amount of tokens to subtract from the delegated amount.
+    pub amount: u64,
+}
+
+impl DecreaseAllowance<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_allowance_update(
+            TokenInstruction::DecreaseAllowance,
+            [self.source, self.delegate, self.authority],
+            self.amount,
+            signers,
+        )
+    }
+}
+
+#[inline(always)]
+fn invoke_allowance_update(
+    instruction: TokenInstruction,
+    [source, delegate, authority]: [&AccountInfo; 3],
+    amount: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(source.key()),
+        AccountMeta::readonly(delegate.key()),
+        AccountMeta::readonly_signer(authority.key()),
+    ];
+
+    // instruction data
+    // -  [0]: instruction discriminator (1 byte, u8)
+    // -  [1..9]: amount (8 bytes, u64)
+    let mut instruction_data = [0u8; 9];
+    instruction_data[0] = instruction as u8;
+    instruction_data[1..].copy_from_slice(&amount.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[source, delegate, authority], signers)
+}
+
+/// Gets the minimum balance for an account of the given kind to be
+/// rent-exempt.
+///
+/// ### Accounts:
+///   None.
+pub struct GetMinimumBalance {
+    /// Kind of account.
+    pub kind: AccountKind,
+}
+
+impl GetMinimumBalance {
+    /// Invokes the instruction, returning the minimum balance.
+    ///
+    /// Fails with `InvalidAccountData` if the token program does not return
+    /// a balance.
+    pub fn invoke(&self) -> Result<u64, ProgramError> {
+        // instruction data
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1]: kind (1 byte, u8)
+        let instruction_data = [TokenInstruction::GetMinimumBalance as u8, self.kind as u8];
+
+        let instruction = Instruction {
+            program_id: &ID,
+            accounts: &[],
+            data: &instruction_data,
+        };
+
+        invoke_signed::<0>(&instruction, &[], &[])?;
+
+        get_return_data()
+            .filter(|return_data| return_data.program_id() == &ID)
+            .and_then(|return_data| return_data.as_slice().try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
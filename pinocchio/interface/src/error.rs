@@ -55,6 +55,16 @@ pub enum TokenError {
     MintDecimalsMismatch,
     /// Instruction does not support non-native tokens
     NonNativeNotSupported,
+
+    // 20
+    /// Current delegation does not match the expected one
+    DelegationMismatch,
+    /// Permit has expired
+    PermitExpired,
+    /// Permit signature, message or nonce is invalid
+    InvalidPermit,
+    /// Requested extension is not supported by this program
+    ExtensionNotSupported,
 }
 
 impl From<TokenError> for ProgramError {
@@ -99,6 +109,12 @@ impl ToStr for TokenError {
             TokenError::NonNativeNotSupported => {
                 "Error: Instruction does not support non-native tokens"
             }
+            TokenError::DelegationMismatch => {
+                "Error: Current delegation does not match the expected one"
+            }
+            TokenError::PermitExpired => "Error: Permit has expired",
+            TokenError::InvalidPermit => "Error: Invalid permit",
+            TokenError::ExtensionNotSupported => "Error: Extension not supported by this program",
         }
     }
 }
@@ -127,6 +143,10 @@ impl TryFrom<u32> for TokenError {
             17 => Ok(TokenError::AccountFrozen),
             18 => Ok(TokenError::MintDecimalsMismatch),
             19 => Ok(TokenError::NonNativeNotSupported),
+            20 => Ok(TokenError::DelegationMismatch),
+            21 => Ok(TokenError::PermitExpired),
+            22 => Ok(TokenError::InvalidPermit),
+            23 => Ok(TokenError::ExtensionNotSupported),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -562,6 +562,9 @@ pub enum TokenInstruction {
     /// owned account by sending them to any other account, leaving behind only
     /// lamports for rent exemption.
     ///
+    /// Native accounts are not supported, use `WithdrawExcessNativeLamports`
+    /// instead.
+    ///
     /// This instruction accepts multisignature accounts owned by either
     /// the Token or Token-2022 programs.
     ///
@@ -606,6 +609,458 @@ pub enum TokenInstruction {
     ///     transferred.
     UnwrapLamports = 45,
 
+    /// Transfers or burns the entire token balance of an account and closes
+    /// it, sending its lamports to the given destination.
+    ///
+    /// This is equivalent to a `Transfer` (or `Burn`) of the full balance
+    /// followed by a `CloseAccount`, validated against a single authority:
+    /// the account owner. If the account has a close authority, it must be
+    /// the same as the owner. Native accounts are not supported, use
+    /// `CloseAccount` instead.
+    ///
+    /// This instruction accepts multisignature accounts owned by either
+    /// the Token or Token-2022 programs.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The account to sweep and close.
+    ///   1. `[writable]` The destination token account or, when burning, the
+    ///      token mint.
+    ///   2. `[writable]` The destination account for the lamports.
+    ///   3. `[signer]` The account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The account to sweep and close.
+    ///   1. `[writable]` The destination token account or, when burning, the
+    ///      token mint.
+    ///   2. `[writable]` The destination account for the lamports.
+    ///   3. `[]` The account's multisignature owner.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `bool` Whether to burn the remaining balance instead of
+    ///     transferring it.
+    SweepAndClose = 46,
+
+    /// Burns the entire token balance of an account. When signed by the
+    /// delegate, the amount burned is the delegated amount, capped by the
+    /// account balance.
+    ///
+    /// This is equivalent to a `Burn` of the amount read at execution time
+    /// instead of being provided by the caller. To also close the account,
+    /// use `SweepAndClose` with `burn` set to `true`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[signer]` The account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The account's multisignature owner/delegate.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    BurnAll = 47,
+
+    /// Transfers the entire token balance of an account to another account
+    /// either directly or via a delegate. When signed by the delegate, the
+    /// amount transferred is the delegated amount, capped by the account
+    /// balance. If this account is associated with the native mint then equal
+    /// amounts of SOL and Tokens will be transferred to the destination
+    /// account.
+    ///
+    /// This is equivalent to a `Transfer` of the amount read at execution
+    /// time instead of being provided by the caller.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The source account's multisignature owner/delegate.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    TransferAll = 48,
+
+    /// Transfers the entire token balance of an account to another account
+    /// either directly or via a delegate. When signed by the delegate, the
+    /// amount transferred is the delegated amount, capped by the account
+    /// balance.
+    ///
+    /// This instruction differs from `TransferAll` in that the token mint and
+    /// decimals value is checked by the caller.  This may be useful when
+    /// creating transactions offline or within a hardware wallet.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The source account's owner/delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The source account's multisignature owner/delegate.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` Expected number of base 10 digits to the right of the decimal
+    ///     place.
+    TransferAllChecked = 49,
+
+    /// Approves a delegate only if the current delegation matches the expected
+    /// one, failing with `DelegationMismatch` otherwise. A delegate is given
+    /// the authority over tokens on behalf of the source account's owner.
+    ///
+    /// This avoids the race where the current delegate spends tokens between
+    /// the owner reading the allowance and approving a new one. When no
+    /// delegate is expected, the current delegated amount must also match
+    /// `expected_amount` (usually `0`).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The new delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The new delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `Option<Pubkey>` The delegate expected to be currently set on the
+    ///     account.
+    ///   - `u64` The amount expected to be currently delegated.
+    ///   - `u64` The new amount of tokens the delegate is approved for.
+    ApproveIfCurrent = 50,
+
+    /// Increases the amount of tokens the current delegate is approved for.
+    ///
+    /// The delegate account must match the delegate currently set on the
+    /// source account, otherwise the instruction fails with
+    /// `DelegationMismatch`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to add to the delegated amount.
+    IncreaseAllowance = 51,
+
+    /// Decreases the amount of tokens the current delegate is approved for.
+    ///
+    /// The delegate account must match the delegate currently set on the
+    /// source account, otherwise the instruction fails with
+    /// `DelegationMismatch`. When the delegated amount
+    /// reaches zero, the delegate is revoked.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The current delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to subtract from the delegated amount.
+    DecreaseAllowance = 52,
+
+    /// Initializes a permit nonce account, used by `ApproveWithPermit` to
+    /// prevent permits of the signing owner from being replayed.
+    ///
+    /// The `InitializePermitNonce` instruction MUST be included within the
+    /// same Transaction as the system program's `CreateAccount` instruction
+    /// that creates the account being initialized. Otherwise another party can
+    /// acquire ownership of the uninitialized account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The permit nonce account to initialize.
+    ///   1. `[signer]` The owner whose permits are tracked by the account.
+    InitializePermitNonce = 53,
+
+    /// Approves a delegate using a permit signed off-chain by the source
+    /// account's owner.
+    ///
+    /// The permit is verified by introspecting the ed25519 program
+    /// instruction that must immediately precede this instruction in the
+    /// transaction. That instruction must verify a single signature of the
+    /// owner over the packed permit message, with the public key, signature
+    /// and message all contained in its own instruction data. The permit
+    /// message includes the address of the owner's permit nonce account,
+    /// whose nonce is incremented on success, so each permit can only be used
+    /// once.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[writable]` The owner's permit nonce account.
+    ///   3. `[]` Instructions sysvar.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens the delegate is approved for.
+    ///   - `u64` The expected nonce of the permit nonce account.
+    ///   - `i64` The unix timestamp after which the permit expires.
+    ApproveWithPermit = 54,
+
+    /// Gets the minimum balance for an account of the given kind to be
+    /// rent-exempt, as a little-endian `u64`.
+    ///
+    /// Return data can be fetched using `sol_get_return_data` and deserializing
+    /// the return data as a little-endian `u64`.
+    ///
+    /// No accounts are expected by this instruction.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `AccountKind` The kind of account.
+    GetMinimumBalance = 55,
+
+    /// Creates and initializes a new mint in a single instruction.
+    ///
+    /// The mint account is funded by the payer with the rent-exempt minimum
+    /// balance, allocated and assigned to the token program through the system
+    /// program, then initialized as with `InitializeMint2`. Since the mint must
+    /// sign, it cannot be initialized by another party in between.
+    ///
+    /// Lamports already held by the mint account are kept, and only the
+    /// remaining balance is transferred from the payer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable, signer]` The mint to create.
+    ///   1. `[writable, signer]` The payer.
+    ///   2. `[]` System program.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` The number of base 10 digits to the right of the decimal place.
+    ///   - `Pubkey` The authority/multisignature to mint tokens.
+    ///   - `Option<Pubkey>` The freeze authority/multisignature of the mint.
+    CreateAndInitializeMint = 56,
+
+    /// Creates and initializes a new token account in a single instruction.
+    ///
+    /// The account is funded by the payer with the rent-exempt minimum
+    /// balance, allocated and assigned to the token program through the system
+    /// program, then initialized as with `InitializeAccount3`. Since the account
+    /// must sign, it cannot be initialized by another party in between.
+    ///
+    /// Lamports already held by the account are kept, and only the remaining
+    /// balance is transferred from the payer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable, signer]` The account to create.
+    ///   1. `[]` The mint this account will be associated with.
+    ///   2. `[writable, signer]` The payer.
+    ///   3. `[]` System program.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `Pubkey` The new account's owner/multisignature.
+    CreateAndInitializeAccount = 57,
+
+    /// Like `InitializeAccount3`, but succeeds without any change if the
+    /// account is already initialized for the same mint and owner.
+    ///
+    /// An already initialized account must be owned by the token program and
+    /// not be frozen. Otherwise, or if its mint or owner differ from the ones
+    /// requested, the instruction fails.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]`  The account to initialize.
+    ///   1. `[]` The mint this account will be associated with.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `Pubkey` The new account's owner/multisignature.
+    InitializeAccountIdempotent = 58,
+
+    /// Like `WithdrawExcessLamports`, but withdraws the excess lamports of
+    /// several source accounts of the same kind (token accounts, mints or
+    /// multisigs) into a single destination.
+    ///
+    /// A single authority is accepted: it must be the owner expected by every
+    /// source account, and its signature is only validated once. Since a
+    /// multisig account is the authority of its own excess lamports, only one
+    /// multisig can be swept at a time; the signers of a multisignature
+    /// authority follow the source accounts.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The destination account.
+    ///   1. `[signer]` The source accounts' owner.
+    ///   2. `..+N` `[writable]` N source accounts.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The destination account.
+    ///   1. `[]` The source accounts' multisignature owner.
+    ///   2. `..+N` `[writable]` N source accounts.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` The number of source accounts.
+    SweepExcessLamports = 59,
+
+    /// Initializes a clawback configuration account, allowing the given
+    /// authority to transfer or burn tokens from any account of the mint with
+    /// `ClawbackTransfer` and `ClawbackBurn`.
+    ///
+    /// The mint must sign and not be initialized yet, so the capability can
+    /// only be opted into when the mint is created. This instruction MUST be
+    /// included before the instruction initializing the mint.
+    ///
+    /// The configuration account is the program derived address of the mint
+    /// with the `CLAWBACK_CONFIG_SEED` seed, created by the token program
+    /// through the system program and funded by the payer with the
+    /// rent-exempt minimum balance.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The clawback configuration account to create.
+    ///   1. `[signer]` The mint, not yet initialized.
+    ///   2. `[writable, signer]` The payer.
+    ///   3. `[]` System program.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `Pubkey` The clawback authority.
+    InitializeClawbackConfig = 60,
+
+    /// Transfers tokens from any account of a mint with a clawback
+    /// configuration, signed by the clawback authority.
+    ///
+    /// The source account may be frozen, but the destination account must not
+    /// be. The delegation of the source account is left unchanged.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[signer]` The clawback authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[]` The clawback multisignature authority.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to transfer.
+    ClawbackTransfer = 61,
+
+    /// Burns tokens from any account of a mint with a clawback configuration,
+    /// signed by the clawback authority.
+    ///
+    /// The source account may be frozen. The delegation of the source account
+    /// is left unchanged.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[signer]` The clawback authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The clawback configuration account of the mint.
+    ///   3. `[]` The clawback multisignature authority.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to burn.
+    ClawbackBurn = 62,
+
+    /// Gets the required size of an account of the given kind as a
+    /// little-endian `u64`.
+    ///
+    /// Return data can be fetched using `sol_get_return_data` and deserializing
+    /// the return data as a little-endian `u64`.
+    ///
+    /// Token-2022 extension types are accepted for compatibility. Only
+    /// `ImmutableOwner` is supported, for token accounts, and does not change
+    /// their size; any other extension fails with `ExtensionNotSupported`.
+    ///
+    /// No accounts are expected by this instruction.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `AccountKind` The kind of account.
+    ///   - `[u16]` Token-2022 extension types of the account, using the
+    ///     remaining data.
+    GetAccountDataSizeForKind = 63,
+
+    /// Withdraws the lamports of a native account above its rent-exempt
+    /// reserve and token amount, instead of wrapping them with `SyncNative`.
+    ///
+    /// Non-native accounts are not supported, use `WithdrawExcessLamports`
+    /// instead.
+    ///
+    /// This instruction accepts multisignature accounts owned by either
+    /// the Token or Token-2022 programs.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The native account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The native account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The native account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The native account's multisignature owner.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    WithdrawExcessNativeLamports = 64,
+
     /// Executes a batch of instructions. The instructions to be executed are
     /// specified in sequence on the instruction data. Each instruction
     /// provides:
@@ -646,7 +1101,7 @@ impl TryFrom<u8> for TokenInstruction {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             // SAFETY: `value` is guaranteed to be in the range of the enum variants.
-            0..=24 | 38 | 45 | 255 => {
+            0..=24 | 38 | 45..=64 | 255 => {
                 Ok(unsafe { core::mem::transmute::<u8, TokenInstruction>(value) })
             }
             _ => Err(TokenError::InvalidInstruction.into()),
@@ -682,10 +1137,41 @@ impl TryFrom<u8> for AuthorityType {
     }
 }
 
+/// Token-2022 extension type of the `ImmutableOwner` extension, accepted by
+/// `GetAccountDataSizeForKind` for token accounts.
+pub const IMMUTABLE_OWNER_EXTENSION_TYPE: u16 = 7;
+
+/// Specifies the kind of account for `GetAccountDataSizeForKind` and
+/// `GetMinimumBalance` instructions
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(test, derive(strum_macros::FromRepr, strum_macros::EnumIter))]
+pub enum AccountKind {
+    /// A token account
+    Account,
+    /// A mint
+    Mint,
+    /// A multisignature account
+    Multisig,
+}
+
+impl TryFrom<u8> for AccountKind {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            // SAFETY: `value` is guaranteed to be in the range of the enum variants.
+            0..=2 => Ok(unsafe { core::mem::transmute::<u8, AccountKind>(value) }),
+            _ => Err(TokenError::InvalidInstruction.into()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
-        super::{AuthorityType, TokenInstruction},
+        super::{AccountKind, AuthorityType, TokenInstruction},
         strum::IntoEnumIterator,
     };
 
@@ -712,4 +1198,16 @@ mod tests {
             assert_eq!(AuthorityType::try_from(variant_u8).unwrap(), variant);
         }
     }
+
+    #[test]
+    fn test_account_kind_from_u8_exhaustive() {
+        for variant in AccountKind::iter() {
+            let variant_u8 = variant as u8;
+            assert_eq!(
+                AccountKind::from_repr(variant_u8),
+                Some(AccountKind::try_from(variant_u8).unwrap())
+            );
+            assert_eq!(AccountKind::try_from(variant_u8).unwrap(), variant);
+        }
+    }
 }
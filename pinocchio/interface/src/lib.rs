@@ -1,8 +1,10 @@
 #![no_std]
 
+pub mod cpi;
 pub mod error;
 pub mod instruction;
 pub mod native_mint;
+pub mod permit;
 pub mod state;
 
 pub mod program {
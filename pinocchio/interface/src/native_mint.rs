@@ -1,6 +1,9 @@
 //! The Mint that represents the native token.
 
-use pinocchio::pubkey::Pubkey;
+use {
+    crate::{error::TokenError, state::account::Account},
+    pinocchio::pubkey::{pubkey_eq, Pubkey},
+};
 
 /// There are `10^9` lamports in one SOL
 pub const DECIMALS: u8 = 9;
@@ -13,3 +16,134 @@ pub fn is_native_mint(mint: &Pubkey) -> bool {
     // Avoid using `pubkey_eq` since it increased CU consumption.
     mint == &ID
 }
+
+/// A native (wrapped SOL) token account together with its lamports balance.
+///
+/// The native amount of a native account stores its rent-exempt reserve, and
+/// the token amount tracks the lamports held above that reserve.
+#[derive(Clone, Copy)]
+pub struct NativeAccount<'a> {
+    account: &'a Account,
+    lamports: u64,
+}
+
+/// Outcome of an `UnwrapLamports` instruction, as computed by
+/// [`NativeAccount::unwrap_plan`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnwrapPlan {
+    /// The amount of tokens (and lamports) unwrapped.
+    pub amount: u64,
+    /// The token amount of the account after the unwrap.
+    pub remaining_amount: u64,
+    /// The lamports of the account after the unwrap, when the destination is
+    /// a different account.
+    pub remaining_lamports: u64,
+    /// The delegated amount of the account after the unwrap.
+    pub remaining_delegated_amount: u64,
+    /// Indicates whether the delegate is cleared by the unwrap.
+    pub clears_delegate: bool,
+}
+
+impl<'a> NativeAccount<'a> {
+    /// Creates a view of a native token account holding `lamports`.
+    #[inline(always)]
+    pub fn new(account: &'a Account, lamports: u64) -> Result<Self, TokenError> {
+        if account.is_native() {
+            Ok(Self { account, lamports })
+        } else {
+            Err(TokenError::NonNativeNotSupported)
+        }
+    }
+
+    /// The token account.
+    #[inline(always)]
+    pub fn account(&self) -> &'a Account {
+        self.account
+    }
+
+    /// The lamports balance of the account.
+    #[inline(always)]
+    pub fn lamports(&self) -> u64 {
+        self.lamports
+    }
+
+    /// The rent-exempt reserve stored in the account.
+    #[inline(always)]
+    pub fn rent_exempt_reserve(&self) -> u64 {
+        self.account.native_amount().unwrap_or_default()
+    }
+
+    /// Returns the lamports held by the account above its rent-exempt reserve
+    /// that are not yet reflected in its token amount.
+    ///
+    /// These lamports are wrapped by a `SyncNative` instruction when the rent
+    /// exemption does not change.
+    #[inline(always)]
+    pub fn wrappable_lamports(&self) -> u64 {
+        self.lamports
+            .saturating_sub(self.rent_exempt_reserve())
+            .saturating_sub(self.account.amount())
+    }
+
+    /// Returns the token amount of the account after a `SyncNative`
+    /// instruction, given the rent-exempt reserve for the account data
+    /// length at the time the instruction is processed.
+    ///
+    /// `SyncNative` replaces the stored reserve with `rent_exempt_reserve`
+    /// and fails with [`TokenError::Overflow`] when the lamports do not
+    /// cover it.
+    #[inline(always)]
+    pub fn expected_amount_after_sync(&self, rent_exempt_reserve: u64) -> Result<u64, TokenError> {
+        self.lamports
+            .checked_sub(rent_exempt_reserve)
+            .ok_or(TokenError::Overflow)
+    }
+
+    /// Returns the outcome of an `UnwrapLamports` instruction signed by
+    /// `authority`, which unwraps `amount` tokens or the whole balance when
+    /// `amount` is `None`.
+    ///
+    /// The authority is treated as the delegate when it matches the delegate
+    /// of the account, and as the owner otherwise; signatures are not
+    /// checked.
+    pub fn unwrap_plan(
+        &self,
+        authority: &Pubkey,
+        amount: Option<u64>,
+    ) -> Result<UnwrapPlan, TokenError> {
+        let (amount, remaining_amount) = if let Some(amount) = amount {
+            (
+                amount,
+                self.account
+                    .amount()
+                    .checked_sub(amount)
+                    .ok_or(TokenError::InsufficientFunds)?,
+            )
+        } else {
+            (self.account.amount(), 0)
+        };
+
+        let (remaining_delegated_amount, clears_delegate) = match self.account.delegate() {
+            Some(delegate) if pubkey_eq(delegate, authority) => {
+                let delegated_amount = self
+                    .account
+                    .delegated_amount()
+                    .checked_sub(amount)
+                    .ok_or(TokenError::InsufficientFunds)?;
+                (delegated_amount, delegated_amount == 0)
+            }
+            _ => (self.account.delegated_amount(), false),
+        };
+
+        Ok(UnwrapPlan {
+            amount,
+            remaining_amount,
+            remaining_lamports: self
+                .lamports
+                .checked_sub(amount)
+                .ok_or(TokenError::Overflow)?,
+            remaining_delegated_amount,
+            clears_delegate,
+        })
+    }
+}
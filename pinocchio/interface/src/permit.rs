@@ -0,0 +1,82 @@
+//! Off-chain signed permits.
+
+use pinocchio::pubkey::Pubkey;
+
+/// The ed25519 signature verification program.
+pub const ED25519_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Domain separator prefixed to every permit message.
+pub const PERMIT_DOMAIN: &[u8; 16] = b"spl-token:permit";
+
+/// Length of a packed permit message.
+///
+/// The packed message layout is:
+///
+///   - `[u8; 16]` The [`PERMIT_DOMAIN`] separator.
+///   - `Pubkey` The token account.
+///   - `Pubkey` The token mint.
+///   - `Pubkey` The delegate.
+///   - `Pubkey` The owner's permit nonce account.
+///   - `u64` The amount of tokens the delegate is approved for.
+///   - `u64` The nonce of the owner's permit nonce account.
+///   - `i64` The unix timestamp after which the permit expires.
+pub const PERMIT_MESSAGE_LEN: usize = 168;
+
+/// Packs a permit message.
+#[inline(always)]
+pub fn pack_permit_message(
+    account: &Pubkey,
+    mint: &Pubkey,
+    delegate: &Pubkey,
+    permit_nonce: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> [u8; PERMIT_MESSAGE_LEN] {
+    let mut message = [0; PERMIT_MESSAGE_LEN];
+    message[..16].copy_from_slice(PERMIT_DOMAIN);
+    message[16..48].copy_from_slice(account);
+    message[48..80].copy_from_slice(mint);
+    message[80..112].copy_from_slice(delegate);
+    message[112..144].copy_from_slice(permit_nonce);
+    message[144..152].copy_from_slice(&amount.to_le_bytes());
+    message[152..160].copy_from_slice(&nonce.to_le_bytes());
+    message[160..168].copy_from_slice(&expiry.to_le_bytes());
+    message
+}
+
+/// Offset of the data following the signature offsets in an ed25519
+/// program instruction.
+const ED25519_DATA_START: usize = 16;
+
+/// Returns the signer public key and message of an ed25519 program
+/// instruction data verifying a single signature with all its data
+/// contained in the instruction itself.
+pub fn unpack_ed25519_instruction_data(data: &[u8]) -> Option<(&Pubkey, &[u8])> {
+    // Only a single signature is supported.
+    if data.len() < ED25519_DATA_START || data[0] != 1 {
+        return None;
+    }
+
+    let read_u16 = |offset: usize| {
+        data.get(offset..offset.checked_add(2)?)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    };
+
+    // All data must be contained in the ed25519 instruction.
+    if read_u16(4)? != u16::MAX || read_u16(8)? != u16::MAX || read_u16(14)? != u16::MAX {
+        return None;
+    }
+
+    let signature_offset = read_u16(2)? as usize;
+    let public_key_offset = read_u16(6)? as usize;
+    let message_offset = read_u16(10)? as usize;
+    let message_size = read_u16(12)? as usize;
+
+    data.get(signature_offset..signature_offset.checked_add(64)?)?;
+    let public_key = data.get(public_key_offset..public_key_offset.checked_add(32)?)?;
+    let message = data.get(message_offset..message_offset.checked_add(message_size)?)?;
+
+    Some((public_key.try_into().ok()?, message))
+}
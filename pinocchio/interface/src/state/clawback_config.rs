@@ -0,0 +1,50 @@
+use {
+    super::{Initializable, Transmutable},
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Seed of the clawback configuration address, derived from the token
+/// program id as `[CLAWBACK_CONFIG_SEED, mint, bump]`.
+pub const CLAWBACK_CONFIG_SEED: &[u8] = b"clawback";
+
+/// Clawback configuration data of a mint.
+#[repr(C)]
+pub struct ClawbackConfig {
+    /// The mint this configuration applies to.
+    pub mint: Pubkey,
+
+    /// The authority allowed to transfer or burn tokens from any account of
+    /// the mint.
+    pub authority: Pubkey,
+
+    /// Is `true` if this structure has been initialized.
+    is_initialized: u8,
+
+    /// The bump seed of the configuration address.
+    pub bump: u8,
+}
+
+impl ClawbackConfig {
+    #[inline]
+    pub fn set_initialized(&mut self, value: bool) {
+        self.is_initialized = value as u8;
+    }
+}
+
+unsafe impl Transmutable for ClawbackConfig {
+    /// The length of the `ClawbackConfig` account data.
+    const LEN: usize = core::mem::size_of::<ClawbackConfig>();
+}
+
+impl super::sealed::Sealed for ClawbackConfig {}
+
+impl Initializable for ClawbackConfig {
+    #[inline(always)]
+    fn is_initialized(&self) -> Result<bool, ProgramError> {
+        match self.is_initialized {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
@@ -5,8 +5,10 @@ use pinocchio::{
 
 pub mod account;
 pub mod account_state;
+pub mod clawback_config;
 pub mod mint;
 pub mod multisig;
+pub mod permit_nonce;
 
 /// Type alias for fields represented as `COption`.
 ///
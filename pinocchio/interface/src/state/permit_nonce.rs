@@ -0,0 +1,52 @@
+use {
+    super::{Initializable, Transmutable},
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Permit nonce data.
+#[repr(C)]
+pub struct PermitNonce {
+    /// The owner of the token accounts whose permits use this nonce.
+    pub owner: Pubkey,
+
+    /// The nonce expected by the next permit.
+    nonce: [u8; 8],
+
+    /// Is `true` if this structure has been initialized.
+    is_initialized: u8,
+}
+
+impl PermitNonce {
+    #[inline(always)]
+    pub fn nonce(&self) -> u64 {
+        u64::from_le_bytes(self.nonce)
+    }
+
+    #[inline(always)]
+    pub fn set_nonce(&mut self, nonce: u64) {
+        self.nonce = nonce.to_le_bytes();
+    }
+
+    #[inline]
+    pub fn set_initialized(&mut self, value: bool) {
+        self.is_initialized = value as u8;
+    }
+}
+
+unsafe impl Transmutable for PermitNonce {
+    /// The length of the `PermitNonce` account data.
+    const LEN: usize = core::mem::size_of::<PermitNonce>();
+}
+
+impl super::sealed::Sealed for PermitNonce {}
+
+impl Initializable for PermitNonce {
+    #[inline(always)]
+    fn is_initialized(&self) -> Result<bool, ProgramError> {
+        match self.is_initialized {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
@@ -497,6 +497,139 @@ fn inner_process_remaining_instruction(
 
             process_unwrap_lamports(accounts, instruction_data)
         }
+        // 46 - SweepAndClose
+        46 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SweepAndClose");
+
+            process_sweep_and_close(accounts, instruction_data)
+        }
+        // 47 - BurnAll
+        47 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: BurnAll");
+
+            process_burn_all(accounts)
+        }
+        // 48 - TransferAll
+        48 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TransferAll");
+
+            process_transfer_all(accounts)
+        }
+        // 49 - TransferAllChecked
+        49 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TransferAllChecked");
+
+            process_transfer_all_checked(accounts, instruction_data)
+        }
+        // 50 - ApproveIfCurrent
+        50 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ApproveIfCurrent");
+
+            process_approve_if_current(accounts, instruction_data)
+        }
+        // 51 - IncreaseAllowance
+        51 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: IncreaseAllowance");
+
+            process_increase_allowance(accounts, instruction_data)
+        }
+        // 52 - DecreaseAllowance
+        52 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DecreaseAllowance");
+
+            process_decrease_allowance(accounts, instruction_data)
+        }
+        // 53 - InitializePermitNonce
+        53 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializePermitNonce");
+
+            process_initialize_permit_nonce(accounts)
+        }
+        // 54 - ApproveWithPermit
+        54 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ApproveWithPermit");
+
+            process_approve_with_permit(accounts, instruction_data)
+        }
+        // 55 - GetMinimumBalance
+        55 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetMinimumBalance");
+
+            process_get_minimum_balance(instruction_data)
+        }
+        // 56 - CreateAndInitializeMint
+        56 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateAndInitializeMint");
+
+            process_create_and_initialize_mint(accounts, instruction_data)
+        }
+        // 57 - CreateAndInitializeAccount
+        57 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateAndInitializeAccount");
+
+            process_create_and_initialize_account(accounts, instruction_data)
+        }
+        // 58 - InitializeAccountIdempotent
+        58 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeAccountIdempotent");
+
+            process_initialize_account_idempotent(accounts, instruction_data)
+        }
+        // 59 - SweepExcessLamports
+        59 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SweepExcessLamports");
+
+            process_sweep_excess_lamports(accounts, instruction_data)
+        }
+        // 60 - InitializeClawbackConfig
+        60 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeClawbackConfig");
+
+            process_initialize_clawback_config(accounts, instruction_data)
+        }
+        // 61 - ClawbackTransfer
+        61 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ClawbackTransfer");
+
+            process_clawback_transfer(accounts, instruction_data)
+        }
+        // 62 - ClawbackBurn
+        62 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ClawbackBurn");
+
+            process_clawback_burn(accounts, instruction_data)
+        }
+        // 63 - GetAccountDataSizeForKind
+        63 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetAccountDataSizeForKind");
+
+            process_get_account_data_size_for_kind(instruction_data)
+        }
+        // 64 - WithdrawExcessNativeLamports
+        64 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: WithdrawExcessNativeLamports");
+
+            process_withdraw_excess_native_lamports(accounts)
+        }
         _ => Err(TokenError::InvalidInstruction.into()),
     }
 }
@@ -0,0 +1,76 @@
+use {
+    super::{unpack_amount, validate_owner, U64_BYTES},
+    pinocchio::{
+        account_info::AccountInfo,
+        hint::{likely, unlikely},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load_mut},
+    },
+};
+
+#[inline(always)]
+pub fn process_approve_if_current(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // Validates the instruction data.
+
+    // The expected size of the instruction data is either 17 or 49 bytes:
+    //   - option + expected_delegate (1 byte + 32 bytes)
+    //   - expected_amount (8 bytes)
+    //   - new_amount (8 bytes)
+    let (expected_delegate, amounts) = match instruction_data.split_first() {
+        Some((0, amounts)) => (None, amounts),
+        Some((1, data)) if likely(data.len() >= 32) => {
+            let (expected_delegate, amounts) = data.split_at(32);
+            // SAFETY: `expected_delegate` is guaranteed to be 32 bytes long.
+            (
+                Some(unsafe { &*(expected_delegate.as_ptr() as *const Pubkey) }),
+                amounts,
+            )
+        }
+        _ => return Err(TokenError::InvalidInstruction.into()),
+    };
+
+    let expected_amount = unpack_amount(amounts)?;
+    let new_amount = unpack_amount(amounts.get(U64_BYTES..).unwrap_or_default())?;
+
+    // Validates the accounts.
+
+    let [source_account_info, delegate_info, owner_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if source_account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_owner(&source_account.owner, owner_info, remaining)? };
+
+    // Validates the current delegation.
+
+    if unlikely(
+        source_account.delegate() != expected_delegate
+            || source_account.delegated_amount() != expected_amount,
+    ) {
+        return Err(TokenError::DelegationMismatch.into());
+    }
+
+    // Sets the delegate and delegated amount.
+
+    source_account.set_delegate(delegate_info.key());
+    source_account.set_delegated_amount(new_amount);
+
+    Ok(())
+}
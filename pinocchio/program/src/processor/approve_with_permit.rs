@@ -0,0 +1,111 @@
+use {
+    super::{check_account_owner, unpack_amount, U64_BYTES},
+    pinocchio::{
+        account_info::AccountInfo,
+        hint::unlikely,
+        program_error::ProgramError,
+        pubkey::pubkey_eq,
+        sysvars::{clock::Clock, instructions::Instructions, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        permit::{pack_permit_message, unpack_ed25519_instruction_data, ED25519_PROGRAM_ID},
+        state::{account::Account, load_mut, permit_nonce::PermitNonce},
+    },
+};
+
+pub fn process_approve_with_permit(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // Validates the instruction data.
+
+    // The expected size of the instruction data is 24 bytes:
+    //   - amount (8 bytes)
+    //   - nonce (8 bytes)
+    //   - expiry (8 bytes)
+    let amount = unpack_amount(instruction_data)?;
+    let nonce = unpack_amount(instruction_data.get(U64_BYTES..).unwrap_or_default())?;
+    let expiry = unpack_amount(instruction_data.get(U64_BYTES * 2..).unwrap_or_default())? as i64;
+
+    // Validates the accounts.
+
+    let [source_account_info, delegate_info, permit_nonce_info, instructions_sysvar_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if unlikely(source_account.is_frozen()?) {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    if unlikely(Clock::get()?.unix_timestamp > expiry) {
+        return Err(TokenError::PermitExpired.into());
+    }
+
+    check_account_owner(permit_nonce_info)?;
+
+    // SAFETY: single mutable borrow to `permit_nonce_info` account data and
+    // `load_mut` validates that the account is initialized; the account data
+    // length is different than a token account, so it is guaranteed to be
+    // different than `source_account_info`.
+    let permit_nonce =
+        unsafe { load_mut::<PermitNonce>(permit_nonce_info.borrow_mut_data_unchecked())? };
+
+    if unlikely(!pubkey_eq(&permit_nonce.owner, &source_account.owner)) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    if unlikely(permit_nonce.nonce() != nonce) {
+        return Err(TokenError::InvalidPermit.into());
+    }
+
+    // Validates the permit signature, which must be verified by the ed25519
+    // program instruction immediately preceding this one.
+
+    let instructions = Instructions::try_from(instructions_sysvar_info)?;
+    let verify_instruction = instructions
+        .get_instruction_relative(-1)
+        .map_err(|_| TokenError::InvalidPermit)?;
+
+    if unlikely(!pubkey_eq(
+        verify_instruction.get_program_id(),
+        &ED25519_PROGRAM_ID,
+    )) {
+        return Err(TokenError::InvalidPermit.into());
+    }
+
+    let (signer, message) =
+        unpack_ed25519_instruction_data(verify_instruction.get_instruction_data())
+            .ok_or(TokenError::InvalidPermit)?;
+
+    let expected_message = pack_permit_message(
+        source_account_info.key(),
+        &source_account.mint,
+        delegate_info.key(),
+        permit_nonce_info.key(),
+        amount,
+        nonce,
+        expiry,
+    );
+
+    if unlikely(!pubkey_eq(signer, &source_account.owner) || message != expected_message) {
+        return Err(TokenError::InvalidPermit.into());
+    }
+
+    // Consumes the permit and sets the delegate and delegated amount.
+
+    permit_nonce.set_nonce(nonce.checked_add(1).ok_or(TokenError::Overflow)?);
+
+    source_account.set_delegate(delegate_info.key());
+    source_account.set_delegated_amount(amount);
+
+    Ok(())
+}
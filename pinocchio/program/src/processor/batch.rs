@@ -60,7 +60,12 @@ pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8])
                 // 8 - Burn
                 // 14 - MintToChecked
                 // 15 - BurnChecked
-                3 | 7 | 8 | 14 | 15 => {
+                // 46 - SweepAndClose
+                // 47 - BurnAll
+                // 48 - TransferAll
+                // 61 - ClawbackTransfer
+                // 62 - ClawbackBurn
+                3 | 7 | 8 | 14 | 15 | 46..=48 | 61 | 62 => {
                     let [a0, a1, ..] = ix_accounts else {
                         return Err(ProgramError::NotEnoughAccountKeys);
                     };
@@ -68,7 +73,9 @@ pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8])
                     check_account_owner(a1)?;
                 }
                 // 12 - TransferChecked
-                12 => {
+                // 49 - TransferAllChecked
+                // 54 - ApproveWithPermit
+                12 | 49 | 54 => {
                     let [a0, _, a2, ..] = ix_accounts else {
                         return Err(ProgramError::NotEnoughAccountKeys);
                     };
@@ -85,12 +92,25 @@ pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8])
                 // 22 - InitializeImmutableOwner
                 // 38 - WithdrawExcessLamports
                 // 45 - UnwrapLamports
-                4..=13 | 22 | 38 | 45 => {
+                // 50 - ApproveIfCurrent
+                // 51 - IncreaseAllowance
+                // 52 - DecreaseAllowance
+                4..=13 | 22 | 38 | 45 | 50..=52 => {
                     let [a0, ..] = ix_accounts else {
                         return Err(ProgramError::NotEnoughAccountKeys);
                     };
                     check_account_owner(a0)?;
                 }
+                // 59 - SweepExcessLamports
+                59 => {
+                    let source_count = ix_data.get(1).copied().unwrap_or_default() as usize;
+                    let Some(sources) = ix_accounts.get(2..2 + source_count) else {
+                        return Err(ProgramError::NotEnoughAccountKeys);
+                    };
+                    for source in sources {
+                        check_account_owner(source)?;
+                    }
+                }
                 _ => {}
             }
         }
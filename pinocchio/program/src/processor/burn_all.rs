@@ -0,0 +1,26 @@
+use {
+    super::shared,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::state::{account::Account, load},
+};
+
+#[inline(always)]
+pub fn process_burn_all(accounts: &[AccountInfo]) -> ProgramResult {
+    let [source_account_info, _, authority_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `source_account_info` account data and
+    // `load` validates that the account is initialized.
+    let source_account = unsafe { load::<Account>(source_account_info.borrow_data_unchecked())? };
+
+    // When the delegate is the authority, the delegated amount capped by the
+    // balance is burned.
+    let amount = if source_account.delegate() == Some(authority_info.key()) {
+        core::cmp::min(source_account.amount(), source_account.delegated_amount())
+    } else {
+        source_account.amount()
+    };
+
+    shared::burn::process_burn(accounts, amount, None)
+}
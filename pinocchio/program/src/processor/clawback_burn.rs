@@ -0,0 +1,88 @@
+use {
+    super::{check_account_owner, check_clawback_config_address, unpack_amount, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo, hint::unlikely, log::sol_log_data, program_error::ProgramError,
+        pubkey::pubkey_eq, ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, clawback_config::ClawbackConfig, load, load_mut, mint::Mint},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_clawback_burn(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let amount = unpack_amount(instruction_data)?;
+
+    let [source_account_info, mint_info, clawback_config_info, authority_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the clawback configuration.
+
+    check_account_owner(clawback_config_info)?;
+
+    // SAFETY: single immutable borrow to `clawback_config_info` account data and
+    // `load` validates that the account is initialized.
+    let clawback_config =
+        unsafe { load::<ClawbackConfig>(clawback_config_info.borrow_data_unchecked())? };
+
+    check_clawback_config_address(clawback_config_info, clawback_config)?;
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+    // SAFETY: single mutable borrow to `mint_info` account data and
+    // `load_mut` validates that the mint is initialized; additionally, an
+    // account cannot be both a token account and a mint, so if duplicates are
+    // passed in, one of them will fail the `load_mut` check.
+    let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+
+    // The source account may be frozen.
+
+    if unlikely(source_account.is_native()) {
+        return Err(TokenError::NativeNotSupported.into());
+    }
+
+    if unlikely(
+        !pubkey_eq(mint_info.key(), &source_account.mint)
+            || !pubkey_eq(&clawback_config.mint, &source_account.mint),
+    ) {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    let updated_source_amount = source_account
+        .amount()
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+
+    // Validates the clawback authority.
+
+    // SAFETY: `authority_info` is not currently borrowed.
+    unsafe { validate_owner(&clawback_config.authority, authority_info, remaining)? };
+
+    // Updates the source account and mint supply; the delegation of the source
+    // account is left unchanged.
+
+    if unlikely(amount == 0) {
+        check_account_owner(source_account_info)?;
+        check_account_owner(mint_info)?;
+    } else {
+        source_account.set_amount(updated_source_amount);
+        // Note: The amount of a token account is always within the range of the
+        // mint supply (`u64`).
+        mint.set_supply(mint.supply() - amount);
+    }
+
+    sol_log_data(&[
+        b"clawback_burn",
+        source_account_info.key(),
+        mint_info.key(),
+        &amount.to_le_bytes(),
+    ]);
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use {
+    super::{check_account_owner, check_clawback_config_address, unpack_amount, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo, hint::unlikely, log::sol_log_data, program_error::ProgramError,
+        pubkey::pubkey_eq, ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, clawback_config::ClawbackConfig, load, load_mut},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_clawback_transfer(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let amount = unpack_amount(instruction_data)?;
+
+    let [source_account_info, destination_account_info, clawback_config_info, authority_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the clawback configuration.
+
+    check_account_owner(clawback_config_info)?;
+
+    // SAFETY: single immutable borrow to `clawback_config_info` account data and
+    // `load` validates that the account is initialized.
+    let clawback_config =
+        unsafe { load::<ClawbackConfig>(clawback_config_info.borrow_data_unchecked())? };
+
+    check_clawback_config_address(clawback_config_info, clawback_config)?;
+
+    // Validates source and destination accounts.
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if unlikely(!pubkey_eq(&clawback_config.mint, &source_account.mint)) {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if unlikely(source_account.is_native()) {
+        return Err(TokenError::NativeNotSupported.into());
+    }
+
+    let remaining_amount = source_account
+        .amount()
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+
+    // Comparing whether the AccountInfo's "point" to the same account or
+    // not - this is a faster comparison since it just checks the internal
+    // raw pointer.
+    let self_transfer = source_account_info == destination_account_info;
+
+    // The source account may be frozen, but tokens cannot be moved into a
+    // frozen account.
+    if !self_transfer {
+        // SAFETY: scoped immutable borrow to `destination_account_info` account data
+        // and `load` validates that the account is initialized; additionally,
+        // the account is guaranteed to be different than `source_account_info`.
+        let destination_account =
+            unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())? };
+
+        if destination_account.is_frozen()? {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        if !pubkey_eq(&source_account.mint, &destination_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+    }
+
+    // Validates the clawback authority.
+
+    // SAFETY: `authority_info` is not currently borrowed.
+    unsafe { validate_owner(&clawback_config.authority, authority_info, remaining)? };
+
+    if self_transfer || amount == 0 {
+        // Validates the token accounts owner since we are not writing
+        // to these account.
+        check_account_owner(source_account_info)?;
+        check_account_owner(destination_account_info)?;
+    } else {
+        // Moves the tokens; the delegation of the source account is left
+        // unchanged.
+
+        source_account.set_amount(remaining_amount);
+
+        // SAFETY: single mutable borrow to `destination_account_info` account data;
+        // the account is guaranteed to be initialized and different than
+        // `source_account_info`.
+        let destination_account =
+            unsafe { load_mut::<Account>(destination_account_info.borrow_mut_data_unchecked())? };
+        // Note: The amount of a token account is always within the range of the
+        // mint supply (`u64`).
+        destination_account.set_amount(destination_account.amount() + amount);
+    }
+
+    sol_log_data(&[
+        b"clawback_transfer",
+        source_account_info.key(),
+        destination_account_info.key(),
+        &amount.to_le_bytes(),
+    ]);
+
+    Ok(())
+}
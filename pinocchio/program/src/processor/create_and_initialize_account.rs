@@ -0,0 +1,36 @@
+use {
+    super::shared::{
+        create_account::create_account, initialize_account::process_initialize_account,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{Pubkey, PUBKEY_BYTES},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, Transmutable},
+    },
+};
+
+#[inline(always)]
+pub fn process_create_and_initialize_account(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let owner = if instruction_data.len() >= PUBKEY_BYTES {
+        // SAFETY: The minimum size of the instruction data is `PUBKEY_BYTES` bytes.
+        unsafe { &*(instruction_data.as_ptr() as *const Pubkey) }
+    } else {
+        return Err(TokenError::InvalidInstruction.into());
+    };
+
+    let [account_info, _mint_info, payer_info, _system_program_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    create_account(account_info, payer_info, Account::LEN, &[])?;
+
+    process_initialize_account(accounts, Some(owner), false, false)
+}
@@ -0,0 +1,19 @@
+use {
+    super::shared::{create_account::create_account, initialize_mint::process_initialize_mint},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::state::{mint::Mint, Transmutable},
+};
+
+#[inline(always)]
+pub fn process_create_and_initialize_mint(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [mint_info, payer_info, _system_program_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    create_account(mint_info, payer_info, Mint::LEN, &[])?;
+
+    process_initialize_mint(accounts, instruction_data, false)
+}
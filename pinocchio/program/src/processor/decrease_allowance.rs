@@ -0,0 +1,14 @@
+use {
+    super::{shared::update_allowance::process_update_allowance, unpack_amount},
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+};
+
+#[inline(always)]
+pub fn process_decrease_allowance(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let amount = unpack_amount(instruction_data)?;
+
+    process_update_allowance(accounts, amount, false)
+}
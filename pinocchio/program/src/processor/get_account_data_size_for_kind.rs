@@ -0,0 +1,41 @@
+use {
+    pinocchio::{program::set_return_data, ProgramResult},
+    pinocchio_token_interface::{
+        error::TokenError,
+        instruction::{AccountKind, IMMUTABLE_OWNER_EXTENSION_TYPE},
+        state::{account::Account, mint::Mint, multisig::Multisig, Transmutable},
+    },
+};
+
+#[inline(always)]
+pub fn process_get_account_data_size_for_kind(instruction_data: &[u8]) -> ProgramResult {
+    let (kind, extension_types) = instruction_data
+        .split_first()
+        .ok_or(TokenError::InvalidInstruction)?;
+
+    if extension_types.len() % 2 != 0 {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
+    let kind = AccountKind::try_from(*kind)?;
+
+    let len = match kind {
+        AccountKind::Account => Account::LEN,
+        AccountKind::Mint => Mint::LEN,
+        AccountKind::Multisig => Multisig::LEN,
+    };
+
+    // Immutable ownership is built into token accounts, so the extension is
+    // accepted without changing their size.
+    if extension_types.chunks_exact(2).any(|extension_type| {
+        kind != AccountKind::Account
+            || u16::from_le_bytes([extension_type[0], extension_type[1]])
+                != IMMUTABLE_OWNER_EXTENSION_TYPE
+    }) {
+        return Err(TokenError::ExtensionNotSupported.into());
+    }
+
+    set_return_data(&(len as u64).to_le_bytes());
+
+    Ok(())
+}
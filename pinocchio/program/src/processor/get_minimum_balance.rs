@@ -0,0 +1,29 @@
+use {
+    pinocchio::{
+        program::set_return_data,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        instruction::AccountKind,
+        state::{account::Account, mint::Mint, multisig::Multisig, Transmutable},
+    },
+};
+
+#[inline(always)]
+pub fn process_get_minimum_balance(instruction_data: &[u8]) -> ProgramResult {
+    let kind = instruction_data
+        .first()
+        .ok_or(TokenError::InvalidInstruction)?;
+
+    let len = match AccountKind::try_from(*kind)? {
+        AccountKind::Account => Account::LEN,
+        AccountKind::Mint => Mint::LEN,
+        AccountKind::Multisig => Multisig::LEN,
+    };
+
+    set_return_data(&Rent::get()?.minimum_balance(len).to_le_bytes());
+
+    Ok(())
+}
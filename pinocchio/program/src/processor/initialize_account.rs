@@ -5,5 +5,5 @@ use {
 
 #[inline(always)]
 pub fn process_initialize_account(accounts: &[AccountInfo]) -> ProgramResult {
-    shared::initialize_account::process_initialize_account(accounts, None, true)
+    shared::initialize_account::process_initialize_account(accounts, None, true, false)
 }
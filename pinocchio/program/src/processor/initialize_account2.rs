@@ -20,5 +20,5 @@ pub fn process_initialize_account2(
         return Err(TokenError::InvalidInstruction.into());
     };
 
-    shared::initialize_account::process_initialize_account(accounts, Some(owner), true)
+    shared::initialize_account::process_initialize_account(accounts, Some(owner), true, false)
 }
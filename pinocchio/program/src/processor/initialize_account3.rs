@@ -20,5 +20,5 @@ pub fn process_initialize_account3(
         return Err(TokenError::InvalidInstruction.into());
     };
 
-    shared::initialize_account::process_initialize_account(accounts, Some(owner), false)
+    shared::initialize_account::process_initialize_account(accounts, Some(owner), false, false)
 }
@@ -0,0 +1,104 @@
+use {
+    super::{check_account_owner, shared::create_account::create_account},
+    pinocchio::{
+        account_info::AccountInfo,
+        hint::unlikely,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::{find_program_address, pubkey_eq, Pubkey},
+        seeds, ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        program::ID as TOKEN_PROGRAM_ID,
+        state::{
+            clawback_config::{ClawbackConfig, CLAWBACK_CONFIG_SEED},
+            load_mut_unchecked, load_unchecked,
+            mint::Mint,
+            Initializable, Transmutable,
+        },
+    },
+};
+
+pub fn process_initialize_clawback_config(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // Validates the instruction data.
+
+    let authority = if instruction_data.len() >= 32 {
+        // SAFETY: `instruction_data` is at least 32 bytes long.
+        unsafe { &*(instruction_data.as_ptr() as *const Pubkey) }
+    } else {
+        return Err(TokenError::InvalidInstruction.into());
+    };
+
+    // Validates the accounts.
+
+    let [clawback_config_info, mint_info, payer_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // The clawback capability can only be opted into by the mint itself before
+    // it is initialized, so it is never added to an existing mint.
+
+    if unlikely(!mint_info.is_signer()) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_account_owner(mint_info)?;
+
+    if unlikely(mint_info.data_len() != Mint::LEN) {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    // SAFETY: single immutable borrow to `mint_info` account data; the length
+    // was validated above.
+    let mint = unsafe { load_unchecked::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if unlikely(mint.is_initialized()?) {
+        return Err(TokenError::AlreadyInUse.into());
+    }
+
+    // The configuration lives at a program derived address of the mint, so
+    // each mint has at most one configuration and it can be found from the
+    // mint alone.
+
+    let (address, bump) =
+        find_program_address(&[CLAWBACK_CONFIG_SEED, mint_info.key()], &TOKEN_PROGRAM_ID);
+
+    if unlikely(!pubkey_eq(&address, clawback_config_info.key())) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if unlikely(!clawback_config_info.data_is_empty()) {
+        return Err(TokenError::AlreadyInUse.into());
+    }
+
+    let bump_seed = [bump];
+    let seeds = seeds!(CLAWBACK_CONFIG_SEED, mint_info.key(), &bump_seed);
+
+    create_account(
+        clawback_config_info,
+        payer_info,
+        ClawbackConfig::LEN,
+        &[Signer::from(&seeds)],
+    )?;
+
+    // SAFETY: single mutable borrow to `clawback_config_info` account data; the
+    // account was just allocated with `ClawbackConfig::LEN` bytes.
+    let clawback_config = unsafe {
+        load_mut_unchecked::<ClawbackConfig>(clawback_config_info.borrow_mut_data_unchecked())?
+    };
+
+    // Initializes the clawback configuration account.
+
+    clawback_config.mint = *mint_info.key();
+    clawback_config.authority = *authority;
+    clawback_config.bump = bump;
+    clawback_config.set_initialized(true);
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        hint::unlikely,
+        program_error::ProgramError,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{load_mut_unchecked, permit_nonce::PermitNonce, Initializable, Transmutable},
+    },
+};
+
+pub fn process_initialize_permit_nonce(accounts: &[AccountInfo]) -> ProgramResult {
+    // Validates the accounts.
+
+    let [permit_nonce_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // The owner must sign, so permit nonce accounts cannot be created on
+    // behalf of another owner.
+    if unlikely(!owner_info.is_signer()) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let is_exempt = Rent::get()?.is_exempt(permit_nonce_info.lamports(), PermitNonce::LEN);
+
+    // SAFETY: single mutable borrow to `permit_nonce_info` account data.
+    let permit_nonce = unsafe {
+        load_mut_unchecked::<PermitNonce>(permit_nonce_info.borrow_mut_data_unchecked())?
+    };
+
+    if unlikely(permit_nonce.is_initialized()?) {
+        return Err(TokenError::AlreadyInUse.into());
+    }
+
+    if unlikely(!is_exempt) {
+        return Err(TokenError::NotRentExempt.into());
+    }
+
+    // Initializes the permit nonce account.
+
+    permit_nonce.owner = *owner_info.key();
+    permit_nonce.set_nonce(0);
+    permit_nonce.set_initialized(true);
+
+    Ok(())
+}
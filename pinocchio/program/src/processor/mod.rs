@@ -1,17 +1,17 @@
 use {
-    core::{slice::from_raw_parts, str::from_utf8_unchecked},
     pinocchio::{
         account_info::AccountInfo,
         hint::{likely, unlikely},
         program_error::ProgramError,
         pubkey::{pubkey_eq, Pubkey},
-        syscalls::sol_memcpy_,
         ProgramResult,
     },
+    pinocchio_pubkey::derive_address,
     pinocchio_token_interface::{
         error::TokenError,
         program::ID as TOKEN_PROGRAM_ID,
         state::{
+            clawback_config::{ClawbackConfig, CLAWBACK_CONFIG_SEED},
             load,
             multisig::{Multisig, MAX_SIGNERS},
             Transmutable,
@@ -22,52 +22,85 @@ use {
 pub mod amount_to_ui_amount;
 pub mod approve;
 pub mod approve_checked;
+pub mod approve_if_current;
+pub mod approve_with_permit;
 pub mod batch;
 pub mod burn;
+pub mod burn_all;
 pub mod burn_checked;
+pub mod clawback_burn;
+pub mod clawback_transfer;
 pub mod close_account;
+pub mod create_and_initialize_account;
+pub mod create_and_initialize_mint;
+pub mod decrease_allowance;
 pub mod freeze_account;
 pub mod get_account_data_size;
+pub mod get_account_data_size_for_kind;
+pub mod get_minimum_balance;
+pub mod increase_allowance;
 pub mod initialize_account;
 pub mod initialize_account2;
 pub mod initialize_account3;
+pub mod initialize_account_idempotent;
+pub mod initialize_clawback_config;
 pub mod initialize_immutable_owner;
 pub mod initialize_mint;
 pub mod initialize_mint2;
 pub mod initialize_multisig;
 pub mod initialize_multisig2;
+pub mod initialize_permit_nonce;
 pub mod mint_to;
 pub mod mint_to_checked;
 pub mod revoke;
 pub mod set_authority;
+pub mod sweep_and_close;
+pub mod sweep_excess_lamports;
 pub mod sync_native;
 pub mod thaw_account;
 pub mod transfer;
+pub mod transfer_all;
+pub mod transfer_all_checked;
 pub mod transfer_checked;
 pub mod ui_amount_to_amount;
 pub mod unwrap_lamports;
 pub mod withdraw_excess_lamports;
+pub mod withdraw_excess_native_lamports;
 // Shared processors.
 pub mod shared;
 
 pub use {
     amount_to_ui_amount::process_amount_to_ui_amount, approve::process_approve,
-    approve_checked::process_approve_checked, batch::process_batch, burn::process_burn,
-    burn_checked::process_burn_checked, close_account::process_close_account,
-    freeze_account::process_freeze_account, get_account_data_size::process_get_account_data_size,
-    initialize_account::process_initialize_account,
+    approve_checked::process_approve_checked, approve_if_current::process_approve_if_current,
+    approve_with_permit::process_approve_with_permit, batch::process_batch, burn::process_burn,
+    burn_all::process_burn_all, burn_checked::process_burn_checked,
+    clawback_burn::process_clawback_burn, clawback_transfer::process_clawback_transfer,
+    close_account::process_close_account,
+    create_and_initialize_account::process_create_and_initialize_account,
+    create_and_initialize_mint::process_create_and_initialize_mint,
+    decrease_allowance::process_decrease_allowance, freeze_account::process_freeze_account,
+    get_account_data_size::process_get_account_data_size,
+    get_account_data_size_for_kind::process_get_account_data_size_for_kind,
+    get_minimum_balance::process_get_minimum_balance,
+    increase_allowance::process_increase_allowance, initialize_account::process_initialize_account,
     initialize_account2::process_initialize_account2,
     initialize_account3::process_initialize_account3,
+    initialize_account_idempotent::process_initialize_account_idempotent,
+    initialize_clawback_config::process_initialize_clawback_config,
     initialize_immutable_owner::process_initialize_immutable_owner,
     initialize_mint::process_initialize_mint, initialize_mint2::process_initialize_mint2,
     initialize_multisig::process_initialize_multisig,
-    initialize_multisig2::process_initialize_multisig2, mint_to::process_mint_to,
+    initialize_multisig2::process_initialize_multisig2,
+    initialize_permit_nonce::process_initialize_permit_nonce, mint_to::process_mint_to,
     mint_to_checked::process_mint_to_checked, revoke::process_revoke,
-    set_authority::process_set_authority, sync_native::process_sync_native,
+    set_authority::process_set_authority, sweep_and_close::process_sweep_and_close,
+    sweep_excess_lamports::process_sweep_excess_lamports, sync_native::process_sync_native,
     thaw_account::process_thaw_account, transfer::process_transfer,
+    transfer_all::process_transfer_all, transfer_all_checked::process_transfer_all_checked,
     transfer_checked::process_transfer_checked, ui_amount_to_amount::process_ui_amount_to_amount,
     unwrap_lamports::process_unwrap_lamports,
     withdraw_excess_lamports::process_withdraw_excess_lamports,
+    withdraw_excess_native_lamports::process_withdraw_excess_native_lamports,
 };
 
 /// Number of bytes in a `u64`.
@@ -78,13 +111,6 @@ mod spl_token_2022 {
     pinocchio_pubkey::declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 }
 
-/// Maximum number of digits in a formatted `u64`.
-///
-/// The maximum number of digits is equal to the maximum number
-/// of decimals (`u8::MAX`) plus the length of the decimal point
-/// and the leading zero.
-const MAX_FORMATTED_DIGITS: usize = u8::MAX as usize + 2;
-
 /// Checks that the account is owned by the expected program.
 #[inline(always)]
 fn check_account_owner(account_info: &AccountInfo) -> ProgramResult {
@@ -95,6 +121,26 @@ fn check_account_owner(account_info: &AccountInfo) -> ProgramResult {
     }
 }
 
+/// Checks that the clawback configuration account is at the program derived
+/// address of its mint.
+#[inline(always)]
+fn check_clawback_config_address(
+    clawback_config_info: &AccountInfo,
+    clawback_config: &ClawbackConfig,
+) -> ProgramResult {
+    let address = derive_address(
+        &[CLAWBACK_CONFIG_SEED, &clawback_config.mint],
+        Some(clawback_config.bump),
+        &TOKEN_PROGRAM_ID,
+    );
+
+    if likely(pubkey_eq(&address, clawback_config_info.key())) {
+        Ok(())
+    } else {
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
 /// Validates owner(s) are present.
 ///
 ///
@@ -150,56 +196,6 @@ unsafe fn validate_owner(
     Ok(())
 }
 
-/// Try to convert a UI representation of a token amount to its raw amount using
-/// the given decimals field
-#[allow(clippy::arithmetic_side_effects)]
-fn try_ui_amount_into_amount(ui_amount: &str, decimals: u8) -> Result<u64, ProgramError> {
-    let decimals = decimals as usize;
-    let mut parts = ui_amount.split('.');
-
-    // Splitting a string, even an empty one, will always yield an iterator of at
-    // least length == 1.
-    let amount_str = parts.next().unwrap();
-    let after_decimal = parts.next().unwrap_or("");
-    // Clean up trailing zeros.
-    let after_decimal = after_decimal.trim_end_matches('0');
-
-    // Validates the input.
-
-    let length = amount_str.len();
-
-    if (amount_str.is_empty() && after_decimal.is_empty())
-        || parts.next().is_some()
-        || after_decimal.len() > decimals
-        || (length + decimals) > MAX_FORMATTED_DIGITS
-    {
-        return Err(ProgramError::InvalidArgument);
-    }
-
-    let mut digits = [b'0'; MAX_FORMATTED_DIGITS];
-
-    // SAFETY: the total length of `amount_str` and `after_decimal` is less than
-    // `MAX_FORMATTED_DIGITS`.
-    unsafe {
-        sol_memcpy_(digits.as_mut_ptr(), amount_str.as_ptr(), length as u64);
-
-        sol_memcpy_(
-            digits.as_mut_ptr().add(length),
-            after_decimal.as_ptr(),
-            after_decimal.len() as u64,
-        );
-    }
-
-    let length = amount_str.len() + decimals;
-
-    // SAFETY: `digits` only contains valid UTF-8 bytes.
-    unsafe {
-        from_utf8_unchecked(from_raw_parts(digits.as_ptr(), length))
-            .parse::<u64>()
-            .map_err(|_| ProgramError::InvalidArgument)
-    }
-}
-
 /// Unpacks a `u64` amount from the instruction data.
 #[inline(always)]
 const fn unpack_amount(instruction_data: &[u8]) -> Result<u64, TokenError> {
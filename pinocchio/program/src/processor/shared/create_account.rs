@@ -0,0 +1,124 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::program::ID as TOKEN_PROGRAM_ID,
+};
+
+/// System program id.
+mod system_program {
+    pinocchio_pubkey::declare_id!("11111111111111111111111111111111");
+}
+
+/// Discriminators of the system program instructions.
+const CREATE_ACCOUNT: u32 = 0;
+const ASSIGN: u32 = 1;
+const TRANSFER: u32 = 2;
+const ALLOCATE: u32 = 8;
+
+/// Funds, allocates and assigns `account` to the token program through the
+/// system program.
+///
+/// The account is funded by `payer` up to the rent-exempt minimum balance for
+/// `space` bytes. Lamports already held by the account are kept, so that the
+/// account cannot be prevented from being created by transferring lamports to
+/// it beforehand.
+///
+/// The `signers` seeds are used when `account` is a program derived address
+/// of the token program, which cannot sign the transaction itself.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn create_account(
+    account: &AccountInfo,
+    payer: &AccountInfo,
+    space: usize,
+    signers: &[Signer],
+) -> ProgramResult {
+    let minimum_balance = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if lamports == 0 {
+        // instruction data
+        // -  [0..4]: instruction discriminator (4 bytes, u32)
+        // -  [4..12]: lamports (8 bytes, u64)
+        // - [12..20]: space (8 bytes, u64)
+        // - [20..52]: owner (32 bytes, Pubkey)
+        let mut instruction_data = [0u8; 52];
+        instruction_data[..4].copy_from_slice(&CREATE_ACCOUNT.to_le_bytes());
+        instruction_data[4..12].copy_from_slice(&minimum_balance.to_le_bytes());
+        instruction_data[12..20].copy_from_slice(&(space as u64).to_le_bytes());
+        instruction_data[20..].copy_from_slice(&TOKEN_PROGRAM_ID);
+
+        return invoke_signed(
+            &Instruction {
+                program_id: &system_program::ID,
+                accounts: &[
+                    AccountMeta::writable_signer(payer.key()),
+                    AccountMeta::writable_signer(account.key()),
+                ],
+                data: &instruction_data,
+            },
+            &[payer, account],
+            signers,
+        );
+    }
+
+    if lamports < minimum_balance {
+        // instruction data
+        // -  [0..4]: instruction discriminator (4 bytes, u32)
+        // -  [4..12]: lamports (8 bytes, u64)
+        let mut instruction_data = [0u8; 12];
+        instruction_data[..4].copy_from_slice(&TRANSFER.to_le_bytes());
+        instruction_data[4..].copy_from_slice(&(minimum_balance - lamports).to_le_bytes());
+
+        invoke_signed(
+            &Instruction {
+                program_id: &system_program::ID,
+                accounts: &[
+                    AccountMeta::writable_signer(payer.key()),
+                    AccountMeta::writable(account.key()),
+                ],
+                data: &instruction_data,
+            },
+            &[payer, account],
+            signers,
+        )?;
+    }
+
+    // instruction data
+    // -  [0..4]: instruction discriminator (4 bytes, u32)
+    // -  [4..12]: space (8 bytes, u64)
+    let mut instruction_data = [0u8; 12];
+    instruction_data[..4].copy_from_slice(&ALLOCATE.to_le_bytes());
+    instruction_data[4..].copy_from_slice(&(space as u64).to_le_bytes());
+
+    invoke_signed(
+        &Instruction {
+            program_id: &system_program::ID,
+            accounts: &[AccountMeta::writable_signer(account.key())],
+            data: &instruction_data,
+        },
+        &[account],
+        signers,
+    )?;
+
+    // instruction data
+    // -  [0..4]: instruction discriminator (4 bytes, u32)
+    // -  [4..36]: owner (32 bytes, Pubkey)
+    let mut instruction_data = [0u8; 36];
+    instruction_data[..4].copy_from_slice(&ASSIGN.to_le_bytes());
+    instruction_data[4..].copy_from_slice(&TOKEN_PROGRAM_ID);
+
+    invoke_signed(
+        &Instruction {
+            program_id: &system_program::ID,
+            accounts: &[AccountMeta::writable_signer(account.key())],
+            data: &instruction_data,
+        },
+        &[account],
+        signers,
+    )
+}
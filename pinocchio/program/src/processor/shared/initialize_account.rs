@@ -3,7 +3,7 @@ use {
     pinocchio::{
         account_info::AccountInfo,
         program_error::ProgramError,
-        pubkey::Pubkey,
+        pubkey::{pubkey_eq, Pubkey},
         sysvars::{rent::Rent, Sysvar},
         ProgramResult,
     },
@@ -23,6 +23,7 @@ pub fn process_initialize_account(
     accounts: &[AccountInfo],
     owner: Option<&Pubkey>,
     rent_sysvar_account_provided: bool,
+    idempotent: bool,
 ) -> ProgramResult {
     // Accounts expected depend on whether we have the `rent_sysvar` account or not.
 
@@ -63,7 +64,27 @@ pub fn process_initialize_account(
         unsafe { load_mut_unchecked::<Account>(new_account_info.borrow_mut_data_unchecked())? };
 
     if account.is_initialized()? {
-        return Err(TokenError::AlreadyInUse.into());
+        if !idempotent {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+
+        // An existing account is left unchanged if it matches the request.
+
+        check_account_owner(new_account_info)?;
+
+        if !pubkey_eq(&account.mint, mint_info.key()) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        if !pubkey_eq(&account.owner, owner) {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+
+        if account.is_frozen()? {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        return Ok(());
     }
 
     if new_account_info.lamports() < minimum_balance {
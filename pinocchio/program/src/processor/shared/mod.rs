@@ -5,9 +5,12 @@
 
 pub mod approve;
 pub mod burn;
+pub mod create_account;
 pub mod initialize_account;
 pub mod initialize_mint;
 pub mod initialize_multisig;
 pub mod mint_to;
 pub mod toggle_account_state;
 pub mod transfer;
+pub mod update_allowance;
+pub mod withdraw_excess_lamports;
@@ -14,7 +14,7 @@ use {
 #[allow(clippy::arithmetic_side_effects)]
 pub fn process_transfer(
     accounts: &[AccountInfo],
-    amount: u64,
+    amount: Option<u64>,
     expected_decimals: Option<u8>,
 ) -> ProgramResult {
     // Accounts expected depend on whether we have the mint `decimals` or not; when
@@ -61,6 +61,17 @@ pub fn process_transfer(
     let source_account =
         unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
 
+    // When no `amount` is specified, the full balance is transferred or, when
+    // the delegate is the authority, the delegated amount capped by the
+    // balance.
+    let amount = match amount {
+        Some(amount) => amount,
+        None if source_account.delegate() == Some(authority_info.key()) => {
+            core::cmp::min(source_account.amount(), source_account.delegated_amount())
+        }
+        None => source_account.amount(),
+    };
+
     // Comparing whether the AccountInfo's "point" to the same account or
     // not - this is a faster comparison since it just checks the internal
     // raw pointer.
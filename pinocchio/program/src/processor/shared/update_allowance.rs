@@ -0,0 +1,63 @@
+use {
+    crate::processor::validate_owner,
+    pinocchio::{
+        account_info::AccountInfo, hint::unlikely, program_error::ProgramError, pubkey::pubkey_eq,
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load_mut},
+    },
+};
+
+#[inline(always)]
+pub fn process_update_allowance(
+    accounts: &[AccountInfo],
+    amount: u64,
+    increase: bool,
+) -> ProgramResult {
+    let [source_account_info, delegate_info, owner_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if source_account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_owner(&source_account.owner, owner_info, remaining)? };
+
+    // Validates that the allowance belongs to the current delegate.
+
+    match source_account.delegate() {
+        Some(delegate) if pubkey_eq(delegate_info.key(), delegate) => (),
+        _ => return Err(TokenError::DelegationMismatch.into()),
+    }
+
+    // Updates the delegated amount.
+
+    let delegated_amount = if increase {
+        source_account
+            .delegated_amount()
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?
+    } else {
+        source_account
+            .delegated_amount()
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?
+    };
+
+    source_account.set_delegated_amount(delegated_amount);
+
+    if unlikely(delegated_amount == 0) {
+        source_account.clear_delegate();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, sysvars::rent::Rent,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load, mint::Mint, multisig::Multisig, Transmutable},
+    },
+};
+
+/// Returns the owner/delegate expected to authorize withdrawing the excess
+/// lamports of the source account, together with the lamports that must
+/// remain in the account.
+///
+/// The owner is `None` for a mint without a mint authority, in which case the
+/// mint itself must sign. Native accounts are not supported: their excess
+/// lamports are withdrawn by `WithdrawExcessNativeLamports`.
+///
+/// # Safety
+///
+/// The caller must ensure that there are no mutable borrows of the
+/// `source_account_info` account data.
+#[inline(always)]
+pub unsafe fn withdraw_authority_and_reserve<'a>(
+    source_account_info: &'a AccountInfo,
+    rent: &Rent,
+) -> Result<(Option<&'a Pubkey>, u64), ProgramError> {
+    // SAFETY: the caller guarantees that there are no mutable borrows of
+    // `source_account_info` account data.
+    let source_data = unsafe { source_account_info.borrow_data_unchecked() };
+
+    match source_data.len() {
+        Account::LEN => {
+            // SAFETY: `source_data` has the same length as `Account`.
+            let account = unsafe { load::<Account>(source_data)? };
+
+            if account.is_native() {
+                return Err(TokenError::NativeNotSupported.into());
+            }
+
+            Ok((Some(&account.owner), rent.minimum_balance(Account::LEN)))
+        }
+        Mint::LEN => {
+            // SAFETY: `source_data` has the same length as `Mint`.
+            let mint = unsafe { load::<Mint>(source_data)? };
+
+            Ok((mint.mint_authority(), rent.minimum_balance(Mint::LEN)))
+        }
+        Multisig::LEN => Ok((
+            Some(source_account_info.key()),
+            rent.minimum_balance(Multisig::LEN),
+        )),
+        _ => Err(TokenError::InvalidState.into()),
+    }
+}
+
+/// Moves the lamports above `reserve` out of the source account, returning the
+/// amount withdrawn.
+///
+/// The caller is responsible for crediting the returned amount to the
+/// destination account.
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn withdraw_excess_lamports(
+    source_account_info: &AccountInfo,
+    reserve: u64,
+) -> Result<u64, ProgramError> {
+    let transfer_amount = source_account_info
+        .lamports()
+        .checked_sub(reserve)
+        .ok_or(TokenError::NotRentExempt)?;
+
+    let source_starting_lamports = source_account_info.lamports();
+    // SAFETY: single mutable borrow to `source_account_info` lamports.
+    unsafe {
+        // Moves the lamports out of the source account.
+        //
+        // Note: The `transfer_amount` is guaranteed to be less than the source
+        // account's lamports.
+        *source_account_info.borrow_mut_lamports_unchecked() =
+            source_starting_lamports - transfer_amount;
+    }
+
+    Ok(transfer_amount)
+}
@@ -0,0 +1,115 @@
+use {
+    super::{check_account_owner, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo, hint::unlikely, program_error::ProgramError, pubkey::pubkey_eq,
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load, load_mut, mint::Mint},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_sweep_and_close(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // instruction data: expected u8 (1)
+    let burn = match instruction_data.first() {
+        Some(0) => false,
+        Some(1) => true,
+        _ => return Err(TokenError::InvalidInstruction.into()),
+    };
+
+    let [source_account_info, destination_info, lamports_destination_info, authority_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Comparing whether the AccountInfo's "point" to the same account or
+    // not - this is a faster comparison since it just checks the internal
+    // raw pointer.
+    if source_account_info == destination_info || source_account_info == lamports_destination_info {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // SAFETY: scoped immutable borrow to `source_account_info` account data and
+    // `load` validates that the account is initialized.
+    let source_account = unsafe { load::<Account>(source_account_info.borrow_data_unchecked())? };
+
+    if unlikely(source_account.is_frozen()?) {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    if unlikely(source_account.is_native()) {
+        return Err(TokenError::NativeNotSupported.into());
+    }
+
+    // Validates the owner, which must also be the close authority when one
+    // is set.
+
+    // SAFETY: `authority_info` is not currently mutably borrowed.
+    unsafe { validate_owner(&source_account.owner, authority_info, remaining)? };
+
+    if let Some(close_authority) = source_account.close_authority() {
+        if unlikely(!pubkey_eq(close_authority, &source_account.owner)) {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+    }
+
+    let amount = source_account.amount();
+
+    // Moves the remaining tokens out of the source account.
+
+    if burn {
+        if unlikely(!pubkey_eq(destination_info.key(), &source_account.mint)) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        // SAFETY: single mutable borrow to `destination_info` account data and
+        // `load_mut` validates that the mint is initialized; the account is
+        // guaranteed to be different than `source_account_info`.
+        let mint = unsafe { load_mut::<Mint>(destination_info.borrow_mut_data_unchecked())? };
+        // Note: The amount of a token account is always within the range of the
+        // mint supply (`u64`).
+        mint.set_supply(mint.supply() - amount);
+    } else {
+        // SAFETY: single mutable borrow to `destination_info` account data and
+        // `load_mut` validates that the account is initialized; the account is
+        // guaranteed to be different than `source_account_info`.
+        let destination_account =
+            unsafe { load_mut::<Account>(destination_info.borrow_mut_data_unchecked())? };
+
+        if unlikely(destination_account.is_frozen()?) {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        if unlikely(!pubkey_eq(&source_account.mint, &destination_account.mint)) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        // Note: The amount of a token account is always within the range of the
+        // mint supply (`u64`).
+        destination_account.set_amount(destination_account.amount() + amount);
+    }
+
+    if unlikely(amount == 0) {
+        // Validates the destination owner since its data is not modified.
+        check_account_owner(destination_info)?;
+    }
+
+    // SAFETY: single mutable borrow to `lamports_destination_info` lamports and
+    // there are no "active" borrows of `source_account_info` account data.
+    unsafe {
+        // Moves the lamports to the destination account.
+        //
+        // Note: This is safe since the runtime checks for balanced instructions
+        // before and after each CPI and instruction, and the total lamports
+        // supply is bound to `u64::MAX`.
+        *lamports_destination_info.borrow_mut_lamports_unchecked() +=
+            source_account_info.lamports();
+        // Closes the source account.
+        source_account_info.close_unchecked();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use {
+    super::{shared::withdraw_excess_lamports, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::pubkey_eq,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::error::TokenError,
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_sweep_excess_lamports(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let source_count = *instruction_data
+        .first()
+        .ok_or(TokenError::InvalidInstruction)? as usize;
+
+    let [destination_info, authority_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if source_count == 0 || remaining.len() < source_count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let (sources, signers) = remaining.split_at(source_count);
+    let data_len = sources[0].data_len();
+    let rent = Rent::get()?;
+
+    let mut authority_validated = false;
+    let mut transfer_amount = 0u64;
+
+    for source_account_info in sources {
+        // All sources must be of the same kind.
+        if source_account_info.data_len() != data_len {
+            return Err(TokenError::InvalidState.into());
+        }
+
+        // SAFETY: there are no mutable borrows to `source_account_info` account data.
+        let (authority, reserve) = unsafe {
+            withdraw_excess_lamports::withdraw_authority_and_reserve(source_account_info, &rent)?
+        };
+
+        match authority {
+            Some(expected_owner) if authority_validated => {
+                // The authority signatures were already validated, so it is only
+                // necessary to check that the source expects the same owner.
+                if !pubkey_eq(expected_owner, authority_info.key()) {
+                    return Err(TokenError::OwnerMismatch.into());
+                }
+            }
+            Some(expected_owner) => {
+                // SAFETY: `authority_info` is not currently mutably borrowed.
+                unsafe { validate_owner(expected_owner, authority_info, signers)? };
+                authority_validated = true;
+            }
+            None if source_account_info == authority_info => {
+                // A mint without a mint authority needs to be the authority account
+                // and, therefore, needs to be a signer.
+                if !authority_info.is_signer() {
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+            }
+            None => {
+                return Err(TokenError::AuthorityTypeNotSupported.into());
+            }
+        }
+
+        // Note: The total lamports supply is bound to `u64::MAX`.
+        transfer_amount +=
+            withdraw_excess_lamports::withdraw_excess_lamports(source_account_info, reserve)?;
+    }
+
+    // SAFETY: single mutable borrow to `destination_info` lamports.
+    unsafe {
+        // Moves the lamports to the destination account.
+        //
+        // Note: The total lamports supply is bound to `u64::MAX`.
+        *destination_info.borrow_mut_lamports_unchecked() += transfer_amount;
+    }
+
+    Ok(())
+}
@@ -7,5 +7,5 @@ use {
 pub fn process_transfer(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
     let amount = unpack_amount(instruction_data)?;
 
-    shared::transfer::process_transfer(accounts, amount, None)
+    shared::transfer::process_transfer(accounts, Some(amount), None)
 }
@@ -0,0 +1,9 @@
+use {
+    super::shared,
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+};
+
+#[inline(always)]
+pub fn process_transfer_all(accounts: &[AccountInfo]) -> ProgramResult {
+    shared::transfer::process_transfer(accounts, None, None)
+}
@@ -0,0 +1,18 @@
+use {
+    super::shared,
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+    pinocchio_token_interface::error::TokenError,
+};
+
+#[inline(always)]
+pub fn process_transfer_all_checked(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1)
+    let decimals = instruction_data
+        .first()
+        .ok_or(TokenError::InvalidInstruction)?;
+
+    shared::transfer::process_transfer(accounts, None, Some(*decimals))
+}
@@ -10,5 +10,5 @@ pub fn process_transfer_checked(
 ) -> ProgramResult {
     let (amount, decimals) = unpack_amount_and_decimals(instruction_data)?;
 
-    shared::transfer::process_transfer(accounts, amount, Some(decimals))
+    shared::transfer::process_transfer(accounts, Some(amount), Some(decimals))
 }
@@ -1,5 +1,5 @@
 use {
-    super::{check_account_owner, try_ui_amount_into_amount},
+    super::check_account_owner,
     core::str::from_utf8,
     pinocchio::{
         account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
@@ -9,6 +9,7 @@ use {
         error::TokenError,
         state::{load, mint::Mint},
     },
+    spl_token_ui_amount::parse_ui_amount,
 };
 
 pub fn process_ui_amount_to_amount(
@@ -25,7 +26,7 @@ pub fn process_ui_amount_to_amount(
         load::<Mint>(mint_info.borrow_data_unchecked()).map_err(|_| TokenError::InvalidMint)?
     };
 
-    let amount = try_ui_amount_into_amount(ui_amount, mint.decimals)?;
+    let amount = parse_ui_amount(ui_amount, mint.decimals).ok_or(ProgramError::InvalidArgument)?;
     set_return_data(&amount.to_le_bytes());
 
     Ok(())
@@ -0,0 +1,45 @@
+use {
+    super::{shared::withdraw_excess_lamports, validate_owner},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::{
+        native_mint::NativeAccount,
+        state::{account::Account, load},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_withdraw_excess_native_lamports(accounts: &[AccountInfo]) -> ProgramResult {
+    let [source_account_info, destination_info, authority_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single immutable borrow to `source_account_info` account data.
+    let source_account = unsafe { load::<Account>(source_account_info.borrow_data_unchecked())? };
+
+    let lamports = source_account_info.lamports();
+    let native_account = NativeAccount::new(source_account, lamports)?;
+
+    // SAFETY: `authority_info` is not currently borrowed; in the case
+    // `authority_info` is the same as `source_account_info`, then it cannot be
+    // a multisig.
+    unsafe { validate_owner(&source_account.owner, authority_info, remaining)? };
+
+    // Withdraws the lamports that `SyncNative` would wrap, keeping the
+    // rent-exempt reserve and the token amount of the account.
+    //
+    // Note: The wrappable lamports are at most the account's lamports.
+    let reserve = lamports - native_account.wrappable_lamports();
+
+    let transfer_amount =
+        withdraw_excess_lamports::withdraw_excess_lamports(source_account_info, reserve)?;
+
+    // SAFETY: single mutable borrow to `destination_info` lamports.
+    unsafe {
+        // Moves the lamports to the destination account.
+        //
+        // Note: The total lamports supply is bound to `u64::MAX`.
+        *destination_info.borrow_mut_lamports_unchecked() += transfer_amount;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,246 @@
+mod setup;
+
+use {
+    assert_matches::assert_matches,
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::error::InstructionError,
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+    spl_token_interface::{error::TokenError, permit::Permit, state::PermitNonce},
+};
+
+async fn initialize_permit_nonce(context: &mut ProgramTestContext, owner: &Keypair) -> Pubkey {
+    let permit_nonce = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_account(
+                &context.payer.pubkey(),
+                &permit_nonce.pubkey(),
+                rent.minimum_balance(PermitNonce::LEN),
+                PermitNonce::LEN as u64,
+                &TOKEN_PROGRAM_ID,
+            ),
+            spl_token_interface::instruction::initialize_permit_nonce(
+                &spl_token_interface::ID,
+                &permit_nonce.pubkey(),
+                &owner.pubkey(),
+            )
+            .unwrap(),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &permit_nonce, owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    permit_nonce.pubkey()
+}
+
+#[tokio::test]
+async fn approve_with_permit() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a permit nonce account for the owner.
+
+    let permit_nonce = initialize_permit_nonce(&mut context, &owner).await;
+
+    // When a delegate is approved using a permit signed by the owner.
+
+    let delegate = Pubkey::new_unique();
+
+    let permit = Permit {
+        account,
+        mint,
+        delegate,
+        permit_nonce,
+        amount: 50,
+        nonce: 0,
+        expiry: i64::MAX,
+    };
+
+    let instructions = permit
+        .sign_and_approve(&spl_token_interface::ID, &owner)
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account should have the delegate and delegated amount.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.delegate.is_some());
+    assert!(account.delegate.unwrap() == delegate);
+    assert!(account.delegated_amount == 50);
+
+    // And the permit nonce should be incremented.
+
+    let permit_nonce = context
+        .banks_client
+        .get_account(permit_nonce)
+        .await
+        .unwrap()
+        .unwrap();
+    let permit_nonce = PermitNonce::unpack(&permit_nonce.data).unwrap();
+
+    assert!(permit_nonce.owner == owner.pubkey());
+    assert!(permit_nonce.nonce == 1);
+}
+
+#[tokio::test]
+async fn fail_replay_permit_with_another_permit_nonce() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And two permit nonce accounts for the owner.
+
+    let permit_nonce = initialize_permit_nonce(&mut context, &owner).await;
+    let another_permit_nonce = initialize_permit_nonce(&mut context, &owner).await;
+
+    // And a permit that was already used with the first permit nonce account.
+
+    let delegate = Pubkey::new_unique();
+
+    let permit = Permit {
+        account,
+        mint,
+        delegate,
+        permit_nonce,
+        amount: 50,
+        nonce: 0,
+        expiry: i64::MAX,
+    };
+
+    let [verify_ix, approve_ix] = permit
+        .sign_and_approve(&spl_token_interface::ID, &owner)
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[verify_ix.clone(), approve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the same signed permit is replayed with the second permit nonce
+    // account, which is still at nonce 0.
+
+    let replay_ix = spl_token_interface::instruction::approve_with_permit(
+        &spl_token_interface::ID,
+        &account,
+        &delegate,
+        &another_permit_nonce,
+        50,
+        0,
+        i64::MAX,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[verify_ix, replay_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the permit should be rejected.
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(code)
+        )) if code == TokenError::InvalidPermit as u32
+    );
+}
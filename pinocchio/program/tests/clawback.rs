@@ -0,0 +1,507 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::system_program,
+    spl_token_interface::{
+        error::TokenError,
+        instruction::{clawback_burn, clawback_transfer, initialize_clawback_config},
+        state::{
+            find_clawback_config_address, Account as TokenAccount, AccountState, ClawbackConfig,
+            Mint,
+        },
+    },
+};
+
+fn create_token_account(
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    state: AccountState,
+) -> Account {
+    let mut data = vec![0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            delegate: COption::None,
+            state,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    Account {
+        lamports: Rent::default().minimum_balance(TokenAccount::LEN),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn clawback_config_address(mint: &Pubkey) -> Pubkey {
+    find_clawback_config_address(mint, &TOKEN_PROGRAM_ID).0
+}
+
+fn create_clawback_config_account(mint: &Pubkey, authority: &Pubkey) -> Account {
+    let mut data = vec![0u8; ClawbackConfig::LEN];
+    ClawbackConfig::pack(
+        ClawbackConfig {
+            mint: *mint,
+            authority: *authority,
+            is_initialized: true,
+            bump: find_clawback_config_address(mint, &TOKEN_PROGRAM_ID).1,
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    Account {
+        lamports: Rent::default().minimum_balance(ClawbackConfig::LEN),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn create_empty_account(space: usize) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(space),
+        data: vec![0u8; space],
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn initialize_clawback_config_with_uninitialized_mint() {
+    // Given an uninitialized mint and the address of its clawback
+    // configuration.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = clawback_config_address(&mint);
+    let payer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = initialize_clawback_config(
+        &spl_token_interface::ID,
+        &clawback_config,
+        &mint,
+        &payer,
+        &authority,
+    )
+    .unwrap();
+
+    // When the clawback configuration is initialized, it should be created at
+    // the derived address and store the mint and the authority.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (clawback_config, Account::default()),
+            (mint, create_empty_account(Mint::LEN)),
+            (payer, Account::new(1_000_000_000, 0, &system_program::id())),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&clawback_config)
+                .owner(&TOKEN_PROGRAM_ID)
+                .lamports(Rent::default().minimum_balance(ClawbackConfig::LEN))
+                .data(&create_clawback_config_account(&mint, &authority).data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_clawback_config_with_initialized_mint() {
+    // Given an initialized mint and the address of its clawback configuration.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = clawback_config_address(&mint);
+    let payer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = initialize_clawback_config(
+        &spl_token_interface::ID,
+        &clawback_config,
+        &mint,
+        &payer,
+        &authority,
+    )
+    .unwrap();
+
+    // When the clawback configuration is initialized, the instruction should
+    // fail since the capability cannot be added to an existing mint.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (clawback_config, Account::default()),
+            (
+                mint,
+                create_mint_account(authority, None, 0, &TOKEN_PROGRAM_ID),
+            ),
+            (payer, Account::new(1_000_000_000, 0, &system_program::id())),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::AlreadyInUse as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_initialize_clawback_config_with_non_derived_address() {
+    // Given an uninitialized mint and an account that is not at the derived
+    // address of its clawback configuration.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let instruction = initialize_clawback_config(
+        &spl_token_interface::ID,
+        &clawback_config,
+        &mint,
+        &payer,
+        &authority,
+    )
+    .unwrap();
+
+    // When the clawback configuration is initialized, the instruction should
+    // fail since each mint has a single configuration address.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (clawback_config, Account::default()),
+            (mint, create_empty_account(Mint::LEN)),
+            (payer, Account::new(1_000_000_000, 0, &system_program::id())),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn clawback_transfer_from_frozen_account() {
+    // Given a frozen source account with 100 tokens and a mint with a clawback
+    // configuration.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = clawback_config_address(&mint);
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let instruction = clawback_transfer(
+        &spl_token_interface::ID,
+        &source,
+        &destination,
+        &clawback_config,
+        &authority,
+        &[],
+        60,
+    )
+    .unwrap();
+
+    // When the clawback authority transfers 60 tokens, the tokens should be
+    // moved out of the frozen account.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                source,
+                create_token_account(&mint, &owner, 100, AccountState::Frozen),
+            ),
+            (
+                destination,
+                create_token_account(&mint, &authority, 0, AccountState::Initialized),
+            ),
+            (
+                clawback_config,
+                create_clawback_config_account(&mint, &authority),
+            ),
+            (authority, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&source)
+                .data(&create_token_account(&mint, &owner, 40, AccountState::Frozen).data)
+                .build(),
+            Check::account(&destination)
+                .data(&create_token_account(&mint, &authority, 60, AccountState::Initialized).data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_clawback_transfer_to_frozen_account() {
+    // Given a source account with 100 tokens and a frozen destination account.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = clawback_config_address(&mint);
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let instruction = clawback_transfer(
+        &spl_token_interface::ID,
+        &source,
+        &destination,
+        &clawback_config,
+        &authority,
+        &[],
+        60,
+    )
+    .unwrap();
+
+    // When the clawback authority transfers the tokens, the instruction should
+    // fail since the destination is frozen.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                source,
+                create_token_account(&mint, &Pubkey::new_unique(), 100, AccountState::Initialized),
+            ),
+            (
+                destination,
+                create_token_account(&mint, &authority, 0, AccountState::Frozen),
+            ),
+            (
+                clawback_config,
+                create_clawback_config_account(&mint, &authority),
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::AccountFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_clawback_transfer_with_wrong_authority() {
+    // Given a frozen source account and a clawback configuration with a
+    // different authority.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = clawback_config_address(&mint);
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let instruction = clawback_transfer(
+        &spl_token_interface::ID,
+        &source,
+        &destination,
+        &clawback_config,
+        &authority,
+        &[],
+        60,
+    )
+    .unwrap();
+
+    // When the tokens are clawed back, the instruction should fail.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                source,
+                create_token_account(&mint, &owner, 100, AccountState::Frozen),
+            ),
+            (
+                destination,
+                create_token_account(&mint, &authority, 0, AccountState::Initialized),
+            ),
+            (
+                clawback_config,
+                create_clawback_config_account(&mint, &Pubkey::new_unique()),
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::OwnerMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_clawback_transfer_with_config_of_another_mint() {
+    // Given a frozen source account and a clawback configuration of a
+    // different mint.
+
+    let mint = Pubkey::new_unique();
+    let another_mint = Pubkey::new_unique();
+    let clawback_config = clawback_config_address(&another_mint);
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let instruction = clawback_transfer(
+        &spl_token_interface::ID,
+        &source,
+        &destination,
+        &clawback_config,
+        &authority,
+        &[],
+        60,
+    )
+    .unwrap();
+
+    // When the tokens are clawed back, the instruction should fail.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                source,
+                create_token_account(&mint, &owner, 100, AccountState::Frozen),
+            ),
+            (
+                destination,
+                create_token_account(&mint, &authority, 0, AccountState::Initialized),
+            ),
+            (
+                clawback_config,
+                create_clawback_config_account(&another_mint, &authority),
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_clawback_transfer_with_non_derived_config() {
+    // Given a frozen source account and a copy of the clawback configuration
+    // of the mint at an address that is not derived from the mint.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let instruction = clawback_transfer(
+        &spl_token_interface::ID,
+        &source,
+        &destination,
+        &clawback_config,
+        &authority,
+        &[],
+        60,
+    )
+    .unwrap();
+
+    // When the tokens are clawed back, the instruction should fail.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                source,
+                create_token_account(&mint, &owner, 100, AccountState::Frozen),
+            ),
+            (
+                destination,
+                create_token_account(&mint, &authority, 0, AccountState::Initialized),
+            ),
+            (
+                clawback_config,
+                create_clawback_config_account(&mint, &authority),
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn clawback_burn_from_frozen_account() {
+    // Given a frozen account with 100 tokens and a mint with a clawback
+    // configuration and a supply of 100 tokens.
+
+    let mint = Pubkey::new_unique();
+    let clawback_config = clawback_config_address(&mint);
+    let authority = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let mut mint_account = create_mint_account(authority, None, 0, &TOKEN_PROGRAM_ID);
+    let mut mint_state = Mint::unpack(&mint_account.data).unwrap();
+    mint_state.supply = 100;
+    Mint::pack(mint_state, &mut mint_account.data).unwrap();
+
+    let instruction = clawback_burn(
+        &spl_token_interface::ID,
+        &account,
+        &mint,
+        &clawback_config,
+        &authority,
+        &[],
+        60,
+    )
+    .unwrap();
+
+    // When the clawback authority burns 60 tokens, the account balance and the
+    // mint supply should decrease.
+
+    mint_state.supply = 40;
+    let mut expected_mint_data = vec![0u8; Mint::LEN];
+    Mint::pack(mint_state, &mut expected_mint_data).unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                account,
+                create_token_account(&mint, &owner, 100, AccountState::Frozen),
+            ),
+            (mint, mint_account),
+            (
+                clawback_config,
+                create_clawback_config_account(&mint, &authority),
+            ),
+            (authority, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&account)
+                .data(&create_token_account(&mint, &owner, 40, AccountState::Frozen).data)
+                .build(),
+            Check::account(&mint).data(&expected_mint_data).build(),
+        ],
+    );
+}
@@ -0,0 +1,122 @@
+mod setup;
+
+use {
+    assert_matches::assert_matches,
+    setup::{mint, TOKEN_PROGRAM_ID},
+    solana_instruction::error::InstructionError,
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, BanksClientError, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+    spl_token_interface::state::{Account, AccountState},
+};
+
+#[tokio::test]
+async fn create_and_initialize_account() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let mint = mint::initialize(&mut context, mint_authority, None, &TOKEN_PROGRAM_ID)
+        .await
+        .unwrap();
+
+    // When a token account is created and initialized in a single instruction.
+
+    let owner = Pubkey::new_unique();
+    let account = Keypair::new();
+
+    let instruction = spl_token_interface::instruction::create_and_initialize_account(
+        &spl_token_interface::ID,
+        &account.pubkey(),
+        &mint,
+        &owner,
+        &context.payer.pubkey(),
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account is rent-exempt, owned by the token program and
+    // initialized.
+
+    let account = context
+        .banks_client
+        .get_account(account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+    assert_eq!(account.lamports, rent.minimum_balance(Account::LEN));
+
+    let account = Account::unpack(&account.data).unwrap();
+
+    assert_eq!(account.state, AccountState::Initialized);
+    assert_eq!(account.mint, mint);
+    assert_eq!(account.owner, owner);
+    assert_eq!(account.amount, 0);
+}
+
+#[tokio::test]
+async fn fail_create_and_initialize_account_without_account_signature() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let mint = mint::initialize(&mut context, mint_authority, None, &TOKEN_PROGRAM_ID)
+        .await
+        .unwrap();
+
+    // When the account does not sign the instruction.
+
+    let account = Pubkey::new_unique();
+
+    let mut instruction = spl_token_interface::instruction::create_and_initialize_account(
+        &spl_token_interface::ID,
+        &account,
+        &mint,
+        &Pubkey::new_unique(),
+        &context.payer.pubkey(),
+    )
+    .unwrap();
+    instruction.accounts[0].is_signer = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the system program rejects the account creation.
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::PrivilegeEscalation
+        ))
+    );
+}
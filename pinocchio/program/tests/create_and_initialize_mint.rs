@@ -0,0 +1,126 @@
+mod setup;
+
+use {
+    setup::TOKEN_PROGRAM_ID,
+    solana_keypair::Keypair,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+    spl_token_interface::state::Mint,
+};
+
+#[tokio::test]
+async fn create_and_initialize_mint() {
+    let context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint authority, freeze authority and a mint keypair.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+    let mint = Keypair::new();
+
+    // When the mint is created and initialized in a single instruction.
+
+    let instruction = spl_token_interface::instruction::create_and_initialize_mint(
+        &spl_token_interface::ID,
+        &mint.pubkey(),
+        &context.payer.pubkey(),
+        &mint_authority,
+        Some(&freeze_authority),
+        6,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the mint is rent-exempt, owned by the token program and initialized.
+
+    let account = context
+        .banks_client
+        .get_account(mint.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+    assert_eq!(account.lamports, rent.minimum_balance(Mint::LEN));
+
+    let mint = Mint::unpack(&account.data).unwrap();
+
+    assert!(mint.is_initialized);
+    assert_eq!(mint.mint_authority, COption::Some(mint_authority));
+    assert_eq!(mint.freeze_authority, COption::Some(freeze_authority));
+    assert_eq!(mint.decimals, 6);
+}
+
+#[tokio::test]
+async fn create_and_initialize_mint_with_existing_lamports() {
+    let context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint address that already holds some lamports.
+
+    let mint_authority = Pubkey::new_unique();
+    let mint = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer(&context.payer.pubkey(), &mint.pubkey(), 1_000)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the mint is created and initialized in a single instruction.
+
+    let instruction = spl_token_interface::instruction::create_and_initialize_mint(
+        &spl_token_interface::ID,
+        &mint.pubkey(),
+        &context.payer.pubkey(),
+        &mint_authority,
+        None,
+        0,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the mint is topped up to the rent-exempt minimum balance.
+
+    let account = context
+        .banks_client
+        .get_account(mint.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+    assert_eq!(account.lamports, rent.minimum_balance(Mint::LEN));
+
+    let mint = Mint::unpack(&account.data).unwrap();
+
+    assert!(mint.is_initialized);
+    assert_eq!(mint.mint_authority, COption::Some(mint_authority));
+    assert_eq!(mint.freeze_authority, COption::None);
+}
@@ -0,0 +1,110 @@
+mod setup;
+
+use {
+    crate::setup::mollusk::mollusk,
+    mollusk_svm::result::Check,
+    solana_instruction::Instruction,
+    solana_program_pack::Pack,
+    spl_token_interface::{
+        error::TokenError,
+        instruction::{
+            get_account_data_size_for_kind, AccountKind, IMMUTABLE_OWNER_EXTENSION_TYPE,
+        },
+        state::{Account, Mint, Multisig},
+    },
+};
+
+#[test]
+fn get_account_data_size_for_each_kind() {
+    // When we request the size of each kind of account, the transaction should
+    // succeed and return the size of the account.
+
+    for (kind, len) in [
+        (AccountKind::Account, Account::LEN),
+        (AccountKind::Mint, Mint::LEN),
+        (AccountKind::Multisig, Multisig::LEN),
+    ] {
+        let instruction =
+            get_account_data_size_for_kind(&spl_token_interface::ID, kind, &[]).unwrap();
+
+        mollusk().process_and_validate_instruction(
+            &instruction,
+            &[],
+            &[
+                Check::success(),
+                Check::return_data(&(len as u64).to_le_bytes()),
+            ],
+        );
+    }
+}
+
+#[test]
+fn get_account_data_size_with_immutable_owner() {
+    // When we request the size of a token account with the immutable owner
+    // extension, the transaction should succeed and return the size of a
+    // token account.
+
+    let instruction = get_account_data_size_for_kind(
+        &spl_token_interface::ID,
+        AccountKind::Account,
+        &[IMMUTABLE_OWNER_EXTENSION_TYPE],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[],
+        &[
+            Check::success(),
+            Check::return_data(&(Account::LEN as u64).to_le_bytes()),
+        ],
+    );
+}
+
+#[test]
+fn fail_get_account_data_size_with_unsupported_extension() {
+    // When we request the size of a token account with an extension other
+    // than the immutable owner, or of a mint with any extension, the
+    // transaction should fail.
+
+    for (kind, extension_types) in [
+        (
+            AccountKind::Account,
+            [IMMUTABLE_OWNER_EXTENSION_TYPE, 1].as_slice(),
+        ),
+        (
+            AccountKind::Mint,
+            [IMMUTABLE_OWNER_EXTENSION_TYPE].as_slice(),
+        ),
+    ] {
+        let instruction =
+            get_account_data_size_for_kind(&spl_token_interface::ID, kind, extension_types)
+                .unwrap();
+
+        mollusk().process_and_validate_instruction(
+            &instruction,
+            &[],
+            &[Check::err(TokenError::ExtensionNotSupported.into())],
+        );
+    }
+}
+
+#[test]
+fn fail_get_account_data_size_with_invalid_data() {
+    // When the kind is unknown or the extension types are truncated, the
+    // transaction should fail.
+
+    for data in [vec![63], vec![63, 3], vec![63, 0, 7]] {
+        let instruction = Instruction {
+            program_id: spl_token_interface::ID,
+            accounts: vec![],
+            data,
+        };
+
+        mollusk().process_and_validate_instruction(
+            &instruction,
+            &[],
+            &[Check::err(TokenError::InvalidInstruction.into())],
+        );
+    }
+}
@@ -0,0 +1,88 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::result::Check,
+    solana_instruction::Instruction,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_interface::{
+        error::TokenError,
+        instruction::{batch, get_account_data_size, get_minimum_balance, AccountKind},
+        state::{Account, Mint, Multisig},
+    },
+};
+
+#[test]
+fn get_minimum_balance_for_each_kind() {
+    // When we request the minimum balance of each kind of account, the
+    // transaction should succeed and return the rent-exempt minimum balance.
+
+    for (kind, len) in [
+        (AccountKind::Account, Account::LEN),
+        (AccountKind::Mint, Mint::LEN),
+        (AccountKind::Multisig, Multisig::LEN),
+    ] {
+        let instruction = get_minimum_balance(&spl_token_interface::ID, kind).unwrap();
+
+        mollusk().process_and_validate_instruction(
+            &instruction,
+            &[],
+            &[
+                Check::success(),
+                Check::return_data(&Rent::default().minimum_balance(len).to_le_bytes()),
+            ],
+        );
+    }
+}
+
+#[test]
+fn fail_get_minimum_balance_with_invalid_kind() {
+    // When we request the minimum balance of an unknown kind of account, the
+    // transaction should fail.
+
+    let instruction = Instruction {
+        program_id: spl_token_interface::ID,
+        accounts: vec![],
+        data: vec![55, 3],
+    };
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[],
+        &[Check::err(TokenError::InvalidInstruction.into())],
+    );
+}
+
+#[test]
+fn batch_get_account_data_size_and_minimum_balance() {
+    // Given a mint account.
+
+    let mint = Pubkey::new_unique();
+    let mint_account = create_mint_account(Pubkey::new_unique(), None, 2, &TOKEN_PROGRAM_ID);
+
+    // When we batch the account size and minimum balance queries, the
+    // transaction should succeed and return the data of the last query.
+
+    let instruction = batch(
+        &spl_token_interface::ID,
+        &[
+            get_account_data_size(&spl_token_interface::ID, &mint).unwrap(),
+            get_minimum_balance(&spl_token_interface::ID, AccountKind::Account).unwrap(),
+        ],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[(mint, mint_account)],
+        &[
+            Check::success(),
+            Check::return_data(&Rent::default().minimum_balance(Account::LEN).to_le_bytes()),
+        ],
+    );
+}
@@ -0,0 +1,162 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::result::Check,
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_interface::{
+        error::TokenError,
+        instruction::initialize_account_idempotent,
+        state::{Account as TokenAccount, AccountState},
+    },
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, state: AccountState) -> Account {
+    let mut data = vec![0u8; TokenAccount::LEN];
+
+    if state != AccountState::Uninitialized {
+        TokenAccount::pack(
+            TokenAccount {
+                mint: *mint,
+                owner: *owner,
+                amount: 0,
+                delegate: COption::None,
+                state,
+                is_native: COption::None,
+                delegated_amount: 0,
+                close_authority: COption::None,
+            },
+            &mut data,
+        )
+        .unwrap();
+    }
+
+    Account {
+        lamports: Rent::default().minimum_balance(TokenAccount::LEN),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn initialize_account_idempotent_twice() {
+    // Given a mint and an uninitialized token account.
+
+    let mint = Pubkey::new_unique();
+    let mint_account = create_mint_account(Pubkey::new_unique(), None, 0, &TOKEN_PROGRAM_ID);
+    let owner = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    let instruction =
+        initialize_account_idempotent(&spl_token_interface::ID, &account, &mint, &owner).unwrap();
+
+    // When the instruction is executed, the account should be initialized.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                account,
+                create_token_account(&mint, &owner, AccountState::Uninitialized),
+            ),
+            (mint, mint_account.clone()),
+        ],
+        &[Check::success()],
+    );
+
+    let initialized = result.get_account(&account).unwrap().clone();
+    let token_account = TokenAccount::unpack(&initialized.data).unwrap();
+    assert_eq!(token_account.mint, mint);
+    assert_eq!(token_account.owner, owner);
+
+    // Then executing it again should succeed without changing the account.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[(account, initialized.clone()), (mint, mint_account)],
+        &[
+            Check::success(),
+            Check::account(&account).data(&initialized.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_account_idempotent_with_conflicting_account() {
+    // Given a mint and an initialized token account.
+
+    let mint = Pubkey::new_unique();
+    let mint_account = create_mint_account(Pubkey::new_unique(), None, 0, &TOKEN_PROGRAM_ID);
+    let owner = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    // When the requested owner differs, the instruction should fail.
+
+    let instruction = initialize_account_idempotent(
+        &spl_token_interface::ID,
+        &account,
+        &mint,
+        &Pubkey::new_unique(),
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                account,
+                create_token_account(&mint, &owner, AccountState::Initialized),
+            ),
+            (mint, mint_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::OwnerMismatch as u32,
+        ))],
+    );
+
+    // When the account was initialized for a different mint, the instruction
+    // should fail.
+
+    let instruction =
+        initialize_account_idempotent(&spl_token_interface::ID, &account, &mint, &owner).unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                account,
+                create_token_account(&Pubkey::new_unique(), &owner, AccountState::Initialized),
+            ),
+            (mint, mint_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintMismatch as u32,
+        ))],
+    );
+
+    // Then a frozen account should also be rejected.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                account,
+                create_token_account(&mint, &owner, AccountState::Frozen),
+            ),
+            (mint, mint_account),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::AccountFrozen as u32,
+        ))],
+    );
+}
@@ -0,0 +1,168 @@
+mod setup;
+
+use {
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+#[tokio::test]
+async fn sweep_and_close() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a destination token account.
+
+    let destination_owner = Pubkey::new_unique();
+
+    let destination =
+        account::initialize(&mut context, &mint, &destination_owner, &TOKEN_PROGRAM_ID).await;
+
+    // When we sweep the tokens and close the account.
+
+    let sweep_and_close_ix = spl_token_interface::instruction::sweep_and_close(
+        &spl_token_interface::ID,
+        &account,
+        &destination,
+        &owner.pubkey(),
+        &owner.pubkey(),
+        &[],
+        false,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_and_close_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the source account must not exist.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+
+    // And the destination account should have 100 tokens.
+
+    let destination = context.banks_client.get_account(destination).await.unwrap();
+    assert!(destination.is_some());
+
+    let destination = destination.unwrap();
+    let destination = spl_token_interface::state::Account::unpack(&destination.data).unwrap();
+
+    assert!(destination.amount == 100);
+}
+
+#[tokio::test]
+async fn sweep_and_close_with_burn() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // When we burn the tokens and close the account.
+
+    let sweep_and_close_ix = spl_token_interface::instruction::sweep_and_close(
+        &spl_token_interface::ID,
+        &account,
+        &mint,
+        &owner.pubkey(),
+        &owner.pubkey(),
+        &[],
+        true,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_and_close_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the source account must not exist.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+
+    // And the mint supply should be 0.
+
+    let mint = context.banks_client.get_account(mint).await.unwrap();
+    assert!(mint.is_some());
+
+    let mint = mint.unwrap();
+    let mint = spl_token_interface::state::Mint::unpack(&mint.data).unwrap();
+
+    assert!(mint.supply == 0);
+}
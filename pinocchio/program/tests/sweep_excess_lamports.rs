@@ -0,0 +1,181 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::result::Check,
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_interface::{
+        error::TokenError,
+        instruction::sweep_excess_lamports,
+        state::{Account as TokenAccount, AccountState},
+    },
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, excess_lamports: u64) -> Account {
+    let mut data = vec![0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            mint: *mint,
+            owner: *owner,
+            amount: 0,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    Account {
+        lamports: Rent::default()
+            .minimum_balance(TokenAccount::LEN)
+            .saturating_add(excess_lamports),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn sweep_excess_lamports_from_accounts() {
+    // Given three token accounts with excess lamports owned by the same
+    // authority.
+
+    let mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let sources = [
+        (Pubkey::new_unique(), 1_000),
+        (Pubkey::new_unique(), 2_000),
+        (Pubkey::new_unique(), 0),
+    ];
+
+    let instruction = sweep_excess_lamports(
+        &spl_token_interface::ID,
+        &sources.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+        &destination,
+        &authority,
+        &[],
+    )
+    .unwrap();
+
+    let mut accounts = vec![
+        (destination, Account::default()),
+        (authority, Account::default()),
+    ];
+    accounts.extend(
+        sources
+            .iter()
+            .map(|(key, excess)| (*key, create_token_account(&mint, &authority, *excess))),
+    );
+
+    // When the excess lamports are swept, the destination should receive the
+    // excess of every source, which are left with their rent-exempt minimum.
+
+    let rent_exempt_reserve = Rent::default().minimum_balance(TokenAccount::LEN);
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&destination).lamports(3_000).build(),
+            Check::account(&sources[0].0)
+                .lamports(rent_exempt_reserve)
+                .build(),
+            Check::account(&sources[1].0)
+                .lamports(rent_exempt_reserve)
+                .build(),
+            Check::account(&sources[2].0)
+                .lamports(rent_exempt_reserve)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_sweep_excess_lamports_with_different_owner() {
+    // Given two token accounts with excess lamports, where the second one has
+    // a different owner.
+
+    let mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let [source1, source2] = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let instruction = sweep_excess_lamports(
+        &spl_token_interface::ID,
+        &[&source1, &source2],
+        &destination,
+        &authority,
+        &[],
+    )
+    .unwrap();
+
+    // When the excess lamports are swept, the instruction should fail.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (destination, Account::default()),
+            (authority, Account::default()),
+            (source1, create_token_account(&mint, &authority, 1_000)),
+            (
+                source2,
+                create_token_account(&mint, &Pubkey::new_unique(), 1_000),
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::OwnerMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_sweep_excess_lamports_from_different_kinds() {
+    // Given a token account and a mint sharing the same authority.
+
+    let mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    let instruction = sweep_excess_lamports(
+        &spl_token_interface::ID,
+        &[&source, &mint],
+        &destination,
+        &authority,
+        &[],
+    )
+    .unwrap();
+
+    // When the excess lamports are swept from both, the instruction should
+    // fail since the sources are not of the same kind.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (destination, Account::default()),
+            (authority, Account::default()),
+            (source, create_token_account(&mint, &authority, 1_000)),
+            (
+                mint,
+                create_mint_account(authority, None, 0, &TOKEN_PROGRAM_ID),
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::InvalidState as u32,
+        ))],
+    );
+}
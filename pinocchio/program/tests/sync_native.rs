@@ -4,7 +4,7 @@ use {
     crate::setup::TOKEN_PROGRAM_ID,
     mollusk_svm::{result::Check, sysvar::Sysvars, Mollusk},
     pinocchio_token_interface::{
-        native_mint,
+        native_mint::{self, NativeAccount},
         state::{
             account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
             load_unchecked,
@@ -126,6 +126,16 @@ fn sync_native_with_rent_change() {
     let old_rent_exempt_reserve = source_account.lamports - lamports;
     let rent_difference = new_rent_exempt_reserve - old_rent_exempt_reserve;
 
+    let token_account = unsafe { load_unchecked::<TokenAccount>(&source_account.data).unwrap() };
+    let native_account = NativeAccount::new(token_account, source_account.lamports).unwrap();
+    assert_eq!(
+        native_account.rent_exempt_reserve(),
+        old_rent_exempt_reserve
+    );
+    let expected_amount = native_account
+        .expected_amount_after_sync(new_rent_exempt_reserve)
+        .unwrap();
+
     let mut test_mollusk = mollusk();
     test_mollusk.sysvars.rent = rent;
     let result = test_mollusk.process_and_validate_instruction_chain(
@@ -137,6 +147,7 @@ fn sync_native_with_rent_change() {
         if *key == source_account_key {
             let token_account = unsafe { load_unchecked::<TokenAccount>(&account.data).unwrap() };
             assert_eq!(token_account.amount(), lamports - rent_difference);
+            assert_eq!(token_account.amount(), expected_amount);
             assert_eq!(token_account.native_amount(), Some(new_rent_exempt_reserve));
         }
     });
@@ -128,3 +128,44 @@ fn fail_ui_amount_to_amount_with_invalid_ui_amount() {
         &[Check::err(ProgramError::InvalidArgument)],
     );
 }
+
+#[test]
+fn ui_amount_to_amount_with_leading_zeros() {
+    // Given a mint account with `u8::MAX` as decimals.
+
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint_account = create_mint_account(
+        mint_authority,
+        Some(freeze_authority),
+        u8::MAX,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    // String representing the ui value `0.000....002`, with more leading zeros
+    // than the longest formatted amount.
+    let mut ui_amount = [b'0'; u8::MAX as usize + 65];
+    ui_amount[64] = b'.';
+    ui_amount[ui_amount.len() - 1] = b'2';
+
+    let input = from_utf8(&ui_amount).unwrap();
+
+    // When we convert the ui amount using the mint, the transaction should
+    // succeed and return 2 as the amount, as the leading zeros do not change
+    // the value.
+
+    let instruction = spl_token_interface::instruction::ui_amount_to_amount(
+        &spl_token_interface::ID,
+        &mint,
+        input,
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[(mint, mint_account)],
+        &[Check::success(), Check::return_data(&2u64.to_le_bytes())],
+    );
+}
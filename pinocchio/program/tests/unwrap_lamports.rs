@@ -6,10 +6,10 @@ use {
     pinocchio_token_interface::{
         error::TokenError,
         instruction::TokenInstruction,
-        native_mint,
+        native_mint::{self, NativeAccount},
         state::{
             account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
-            multisig::Multisig,
+            load_unchecked, multisig::Multisig,
         },
     },
     solana_account::Account,
@@ -1060,3 +1060,82 @@ fn fail_unwrap_lamports_from_multisig_with_missing_signer() {
         &[Check::err(ProgramError::MissingRequiredSignature)],
     );
 }
+
+#[test]
+fn unwrap_lamports_matches_unwrap_plan() {
+    let native_mint = Pubkey::new_from_array(native_mint::ID);
+    let authority_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+
+    // (authority, amount) pairs covering the owner and delegate paths.
+    let cases = [
+        (authority_key, None),
+        (authority_key, Some(1_500_000_000)),
+        (authority_key, Some(2_000_000_001)),
+        (delegate_key, Some(400_000_000)),
+        (delegate_key, Some(1_000_000_000)),
+        (delegate_key, None),
+    ];
+
+    for (authority, amount) in cases {
+        let destination_account_key = Pubkey::new_unique();
+        let source_account_key = Pubkey::new_unique();
+        let source_account = create_token_account(
+            &native_mint,
+            &authority_key,
+            true,
+            2_000_000_000,
+            &TOKEN_PROGRAM_ID,
+            Some((&delegate_key, 1_000_000_000)),
+        );
+
+        let token_account =
+            unsafe { load_unchecked::<TokenAccount>(&source_account.data).unwrap() };
+        let plan = NativeAccount::new(token_account, source_account.lamports)
+            .unwrap()
+            .unwrap_plan(authority.as_array(), amount);
+
+        let instruction = unwrap_lamports_instruction(
+            &source_account_key,
+            &destination_account_key,
+            &authority,
+            amount,
+            &[],
+        )
+        .unwrap();
+
+        let checks = match &plan {
+            Ok(plan) => vec![
+                Check::success(),
+                Check::account(&destination_account_key)
+                    .lamports(plan.amount)
+                    .build(),
+                Check::account(&source_account_key)
+                    .lamports(plan.remaining_lamports)
+                    .build(),
+            ],
+            Err(error) => vec![Check::err(ProgramError::Custom(error.clone() as u32))],
+        };
+
+        let result = mollusk().process_and_validate_instruction(
+            &instruction,
+            &[
+                (source_account_key, source_account),
+                (destination_account_key, Account::default()),
+                (authority, Account::default()),
+            ],
+            &checks,
+        );
+
+        if let Ok(plan) = plan {
+            let account = result.get_account(&source_account_key).unwrap();
+            let token_account = unsafe { load_unchecked::<TokenAccount>(&account.data).unwrap() };
+            assert_eq!(token_account.amount(), plan.remaining_amount);
+            assert_eq!(
+                token_account.delegated_amount(),
+                plan.remaining_delegated_amount
+            );
+            assert_eq!(token_account.delegate().is_none(), plan.clears_delegate);
+        }
+    }
+}
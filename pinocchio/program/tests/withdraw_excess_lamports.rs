@@ -12,7 +12,7 @@ use {
     solana_program_test::{tokio, BanksClientError, ProgramTest},
     solana_pubkey::Pubkey,
     solana_signer::Signer,
-    solana_system_interface::instruction::create_account,
+    solana_system_interface::instruction::{create_account, transfer},
     solana_transaction::Transaction,
     solana_transaction_error::TransactionError,
     std::mem::size_of,
@@ -1099,3 +1099,96 @@ async fn fail_withdraw_excess_lamports_from_mint_with_no_authority_and_authority
         ))
     );
 }
+
+#[tokio::test]
+async fn fail_withdraw_excess_lamports_from_native_account() {
+    let context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    let wrapped_lamports = 1_000_000_000;
+    let excess_lamports = 4_000_000_000;
+
+    // Given a native account wrapping lamports.
+
+    let owner = Keypair::new();
+    let account = Keypair::new();
+    let account_pubkey = account.pubkey();
+
+    let account_size = size_of::<Account>();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &account_pubkey,
+            rent.minimum_balance(account_size) + wrapped_lamports,
+            account_size as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        spl_token_interface::instruction::initialize_account(
+            &spl_token_interface::ID,
+            &account_pubkey,
+            &spl_token_interface::native_mint::ID,
+            &owner.pubkey(),
+        )
+        .unwrap(),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And lamports sent to the account without being synced.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer(
+            &context.payer.pubkey(),
+            &account_pubkey,
+            excess_lamports,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we try to withdraw the excess lamports.
+
+    let destination = Pubkey::new_unique();
+
+    let withdraw_ix = spl_token_interface::instruction::withdraw_excess_lamports(
+        &spl_token_interface::ID,
+        &account_pubkey,
+        &destination,
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(10) // TokenError::NativeNotSupported
+        ))
+    );
+}
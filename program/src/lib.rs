@@ -35,18 +35,31 @@ pub mod solana_program {
         pub use solana_pubkey::{Pubkey, PUBKEY_BYTES};
     }
 }
-use solana_program_error::ProgramError;
+use {
+    solana_program_error::ProgramError,
+    spl_token_ui_amount::{
+        format_ui_amount, format_ui_amount_trimmed, parse_ui_amount, MAX_UI_AMOUNT_LEN,
+    },
+};
 // Re-export spl_token_interface items
 pub use spl_token_interface::{check_id, check_program_account, id, ID};
 
 /// Convert the UI representation of a token amount (using the decimals field
 /// defined in its mint) to the raw amount
+#[deprecated(
+    since = "9.1.0",
+    note = "Lossy for large amounts, use `spl_token_interface::amount::TokenAmount` instead"
+)]
 pub fn ui_amount_to_amount(ui_amount: f64, decimals: u8) -> u64 {
     (ui_amount * 10_usize.pow(decimals as u32) as f64) as u64
 }
 
 /// Convert a raw amount to its UI representation (using the decimals field
 /// defined in its mint)
+#[deprecated(
+    since = "9.1.0",
+    note = "Lossy for large amounts, use `spl_token_interface::amount::TokenAmount` instead"
+)]
 pub fn amount_to_ui_amount(amount: u64, decimals: u8) -> f64 {
     amount as f64 / 10_usize.pow(decimals as u32) as f64
 }
@@ -54,51 +67,19 @@ pub fn amount_to_ui_amount(amount: u64, decimals: u8) -> f64 {
 /// Convert a raw amount to its UI representation (using the decimals field
 /// defined in its mint)
 pub fn amount_to_ui_amount_string(amount: u64, decimals: u8) -> String {
-    let decimals = decimals as usize;
-    if decimals > 0 {
-        // Left-pad zeros to decimals + 1, so we at least have an integer zero
-        let mut s = format!("{:01$}", amount, decimals + 1);
-        // Add the decimal point (Sorry, "," locales!)
-        s.insert(s.len() - decimals, '.');
-        s
-    } else {
-        amount.to_string()
-    }
+    let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+    format_ui_amount(amount, decimals, &mut buffer).to_string()
 }
 
 /// Convert a raw amount to its UI representation using the given decimals field
 /// Excess zeroes or unneeded decimal point are trimmed.
 pub fn amount_to_ui_amount_string_trimmed(amount: u64, decimals: u8) -> String {
-    let mut s = amount_to_ui_amount_string(amount, decimals);
-    if decimals > 0 {
-        let zeros_trimmed = s.trim_end_matches('0');
-        s = zeros_trimmed.trim_end_matches('.').to_string();
-    }
-    s
+    let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+    format_ui_amount_trimmed(amount, decimals, &mut buffer).to_string()
 }
 
 /// Try to convert a UI representation of a token amount to its raw amount using
 /// the given decimals field
 pub fn try_ui_amount_into_amount(ui_amount: String, decimals: u8) -> Result<u64, ProgramError> {
-    let decimals = decimals as usize;
-    let mut parts = ui_amount.split('.');
-    // splitting a string, even an empty one, will always yield an iterator of
-    // at least length == 1
-    let mut amount_str = parts.next().unwrap().to_string();
-    let after_decimal = parts.next().unwrap_or("");
-    let after_decimal = after_decimal.trim_end_matches('0');
-    if (amount_str.is_empty() && after_decimal.is_empty())
-        || parts.next().is_some()
-        || after_decimal.len() > decimals
-    {
-        return Err(ProgramError::InvalidArgument);
-    }
-
-    amount_str.push_str(after_decimal);
-    for _ in 0..decimals.saturating_sub(after_decimal.len()) {
-        amount_str.push('0');
-    }
-    amount_str
-        .parse::<u64>()
-        .map_err(|_| ProgramError::InvalidArgument)
+    parse_ui_amount(&ui_amount, decimals).ok_or(ProgramError::InvalidArgument)
 }
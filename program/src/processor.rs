@@ -2,14 +2,20 @@
 
 use {
     crate::{
-        amount_to_ui_amount_string_trimmed,
         error::TokenError,
-        instruction::{is_valid_signer_index, AuthorityType, TokenInstruction, MAX_SIGNERS},
-        state::{Account, AccountState, Mint, Multisig},
-        try_ui_amount_into_amount,
+        instruction::{
+            is_valid_signer_index, AccountKind, AuthorityType, TokenInstruction,
+            IMMUTABLE_OWNER_EXTENSION_TYPE, MAX_SIGNERS,
+        },
+        state::{
+            find_clawback_config_address, Account, AccountState, ClawbackConfig, Mint, Multisig,
+            PermitNonce, CLAWBACK_CONFIG_SEED,
+        },
     },
     solana_account_info::{next_account_info, AccountInfo},
-    solana_cpi::set_return_data,
+    solana_clock::Clock,
+    solana_cpi::{invoke, invoke_signed, set_return_data},
+    solana_instructions_sysvar::get_instruction_relative,
     solana_msg::msg,
     solana_program_error::{ProgramError, ProgramResult},
     solana_program_memory::sol_memcmp,
@@ -17,8 +23,14 @@ use {
     solana_program_pack::{IsInitialized, Pack},
     solana_pubkey::{Pubkey, PUBKEY_BYTES},
     solana_rent::Rent,
-    solana_sdk_ids::system_program,
+    solana_sdk_ids::{ed25519_program, system_program},
+    solana_system_interface::instruction as system_instruction,
     solana_sysvar::{Sysvar, SysvarSerialize},
+    spl_token_interface::{
+        native_mint::NativeAccount,
+        permit::{unpack_ed25519_instruction_data, Permit},
+    },
+    spl_token_ui_amount::{format_ui_amount_trimmed, parse_ui_amount, MAX_UI_AMOUNT_LEN},
 };
 
 /// Program state handler.
@@ -85,6 +97,7 @@ impl Processor {
         accounts: &[AccountInfo],
         owner: Option<&Pubkey>,
         rent_sysvar_account: bool,
+        idempotent: bool,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let new_account_info = next_account_info(account_info_iter)?;
@@ -103,7 +116,21 @@ impl Processor {
 
         let mut account = Account::unpack_unchecked(&new_account_info.data.borrow())?;
         if account.is_initialized() {
-            return Err(TokenError::AlreadyInUse.into());
+            if !idempotent {
+                return Err(TokenError::AlreadyInUse.into());
+            }
+            // an existing account is left unchanged if it matches the request
+            Self::check_account_owner(program_id, new_account_info)?;
+            if !Self::cmp_pubkeys(&account.mint, mint_info.key) {
+                return Err(TokenError::MintMismatch.into());
+            }
+            if !Self::cmp_pubkeys(&account.owner, owner) {
+                return Err(TokenError::OwnerMismatch.into());
+            }
+            if account.is_frozen() {
+                return Err(TokenError::AccountFrozen.into());
+            }
+            return Ok(());
         }
 
         if !rent.is_exempt(new_account_info.lamports(), new_account_info_data_len) {
@@ -146,7 +173,7 @@ impl Processor {
         program_id: &Pubkey,
         accounts: &[AccountInfo],
     ) -> ProgramResult {
-        Self::_process_initialize_account(program_id, accounts, None, true)
+        Self::_process_initialize_account(program_id, accounts, None, true, false)
     }
 
     /// Processes an [`InitializeAccount2`](enum.TokenInstruction.html)
@@ -156,7 +183,7 @@ impl Processor {
         accounts: &[AccountInfo],
         owner: Pubkey,
     ) -> ProgramResult {
-        Self::_process_initialize_account(program_id, accounts, Some(&owner), true)
+        Self::_process_initialize_account(program_id, accounts, Some(&owner), true, false)
     }
 
     /// Processes an [`InitializeAccount3`](enum.TokenInstruction.html)
@@ -166,7 +193,18 @@ impl Processor {
         accounts: &[AccountInfo],
         owner: Pubkey,
     ) -> ProgramResult {
-        Self::_process_initialize_account(program_id, accounts, Some(&owner), false)
+        Self::_process_initialize_account(program_id, accounts, Some(&owner), false, false)
+    }
+
+    /// Processes an
+    /// [`InitializeAccountIdempotent`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_initialize_account_idempotent(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        owner: Pubkey,
+    ) -> ProgramResult {
+        Self::_process_initialize_account(program_id, accounts, Some(&owner), false, true)
     }
 
     fn _process_initialize_multisig(
@@ -391,6 +429,210 @@ impl Processor {
         Ok(())
     }
 
+    /// Processes an [`ApproveIfCurrent`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_approve_if_current(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        expected_delegate: COption<Pubkey>,
+        expected_amount: u64,
+        new_amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let delegate_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        if source_account.delegate != expected_delegate
+            || source_account.delegated_amount != expected_amount
+        {
+            return Err(TokenError::DelegationMismatch.into());
+        }
+
+        source_account.delegate = COption::Some(*delegate_info.key);
+        source_account.delegated_amount = new_amount;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes an [`IncreaseAllowance`](enum.TokenInstruction.html) or a
+    /// [`DecreaseAllowance`](enum.TokenInstruction.html) instruction.
+    pub fn process_update_allowance(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+        increase: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let delegate_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        match source_account.delegate {
+            COption::Some(ref delegate) if Self::cmp_pubkeys(delegate_info.key, delegate) => {}
+            _ => return Err(TokenError::DelegationMismatch.into()),
+        }
+
+        source_account.delegated_amount = if increase {
+            source_account
+                .delegated_amount
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?
+        } else {
+            source_account
+                .delegated_amount
+                .checked_sub(amount)
+                .ok_or(TokenError::InsufficientFunds)?
+        };
+
+        if source_account.delegated_amount == 0 {
+            source_account.delegate = COption::None;
+        }
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes an [`InitializePermitNonce`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_initialize_permit_nonce(accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let permit_nonce_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+        let permit_nonce_data_len = permit_nonce_info.data_len();
+
+        if !owner_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let mut permit_nonce = PermitNonce::unpack_unchecked(&permit_nonce_info.data.borrow())?;
+        if permit_nonce.is_initialized {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+
+        if !Rent::get()?.is_exempt(permit_nonce_info.lamports(), permit_nonce_data_len) {
+            return Err(TokenError::NotRentExempt.into());
+        }
+
+        permit_nonce.owner = *owner_info.key;
+        permit_nonce.nonce = 0;
+        permit_nonce.is_initialized = true;
+
+        PermitNonce::pack(permit_nonce, &mut permit_nonce_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes an [`ApproveWithPermit`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_approve_with_permit(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+        nonce: u64,
+        expiry: i64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let delegate_info = next_account_info(account_info_iter)?;
+        let permit_nonce_info = next_account_info(account_info_iter)?;
+        let instructions_sysvar_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        if Clock::get()?.unix_timestamp > expiry {
+            return Err(TokenError::PermitExpired.into());
+        }
+
+        // Validates the nonce, which also prevents the permit from being replayed.
+
+        Self::check_account_owner(program_id, permit_nonce_info)?;
+        let mut permit_nonce = PermitNonce::unpack(&permit_nonce_info.data.borrow())?;
+        if !Self::cmp_pubkeys(&permit_nonce.owner, &source_account.owner) {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+        if permit_nonce.nonce != nonce {
+            return Err(TokenError::InvalidPermit.into());
+        }
+
+        // Validates the owner signature of the permit through the preceding
+        // ed25519 program instruction.
+
+        let verify_instruction = get_instruction_relative(-1, instructions_sysvar_info).map_err(
+            |error| match error {
+                ProgramError::UnsupportedSysvar => error,
+                _ => TokenError::InvalidPermit.into(),
+            },
+        )?;
+        if !Self::cmp_pubkeys(&verify_instruction.program_id, &ed25519_program::id()) {
+            return Err(TokenError::InvalidPermit.into());
+        }
+
+        let expected_message = Permit {
+            account: *source_account_info.key,
+            mint: source_account.mint,
+            delegate: *delegate_info.key,
+            permit_nonce: *permit_nonce_info.key,
+            amount,
+            nonce,
+            expiry,
+        }
+        .pack();
+
+        match unpack_ed25519_instruction_data(&verify_instruction.data) {
+            Some((signer, message))
+                if Self::cmp_pubkeys(&signer, &source_account.owner)
+                    && message == expected_message => {}
+            _ => return Err(TokenError::InvalidPermit.into()),
+        }
+
+        permit_nonce.nonce = permit_nonce
+            .nonce
+            .checked_add(1)
+            .ok_or(TokenError::Overflow)?;
+
+        source_account.delegate = COption::Some(*delegate_info.key);
+        source_account.delegated_amount = amount;
+
+        PermitNonce::pack(permit_nonce, &mut permit_nonce_info.data.borrow_mut())?;
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
     /// Processes an [`Revoke`](enum.TokenInstruction.html) instruction.
     pub fn process_revoke(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
@@ -708,6 +950,133 @@ impl Processor {
         Ok(())
     }
 
+    /// Processes a [`TransferAll`](enum.TokenInstruction.html) or a
+    /// [`TransferAllChecked`](enum.TokenInstruction.html) instruction.
+    pub fn process_transfer_all(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        expected_decimals: Option<u8>,
+    ) -> ProgramResult {
+        let source_account_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let authority_info = accounts
+            .get(if expected_decimals.is_some() { 3 } else { 2 })
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let source_account = Account::unpack(&source_account_info.data.borrow())?;
+        let amount = match source_account.delegate {
+            COption::Some(ref delegate) if Self::cmp_pubkeys(authority_info.key, delegate) => {
+                source_account.amount.min(source_account.delegated_amount)
+            }
+            _ => source_account.amount,
+        };
+
+        Self::process_transfer(program_id, accounts, amount, expected_decimals)
+    }
+
+    /// Processes a [`BurnAll`](enum.TokenInstruction.html) instruction.
+    pub fn process_burn_all(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let source_account_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let authority_info = accounts.get(2).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let source_account = Account::unpack(&source_account_info.data.borrow())?;
+        let amount = match source_account.delegate {
+            COption::Some(ref delegate) if Self::cmp_pubkeys(authority_info.key, delegate) => {
+                source_account.amount.min(source_account.delegated_amount)
+            }
+            _ => source_account.amount,
+        };
+
+        Self::process_burn(program_id, accounts, amount, None)
+    }
+
+    /// Processes a [`SweepAndClose`](enum.TokenInstruction.html) instruction.
+    pub fn process_sweep_and_close(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        burn: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_info = next_account_info(account_info_iter)?;
+        let lamports_destination_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        if Self::cmp_pubkeys(source_account_info.key, destination_info.key)
+            || Self::cmp_pubkeys(source_account_info.key, lamports_destination_info.key)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let source_account = Account::unpack(&source_account_info.data.borrow())?;
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        if source_account.is_native() {
+            return Err(TokenError::NativeNotSupported.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            authority_info,
+            account_info_iter.as_slice(),
+        )?;
+        if let COption::Some(close_authority) = source_account.close_authority {
+            if !Self::cmp_pubkeys(&close_authority, &source_account.owner) {
+                return Err(TokenError::OwnerMismatch.into());
+            }
+        }
+
+        let amount = source_account.amount;
+
+        if burn {
+            if !Self::cmp_pubkeys(destination_info.key, &source_account.mint) {
+                return Err(TokenError::MintMismatch.into());
+            }
+
+            let mut mint = Mint::unpack(&destination_info.data.borrow())?;
+            mint.supply = mint
+                .supply
+                .checked_sub(amount)
+                .ok_or(TokenError::Overflow)?;
+
+            if amount == 0 {
+                Self::check_account_owner(program_id, destination_info)?;
+            }
+
+            Mint::pack(mint, &mut destination_info.data.borrow_mut())?;
+        } else {
+            let mut destination_account = Account::unpack(&destination_info.data.borrow())?;
+            if destination_account.is_frozen() {
+                return Err(TokenError::AccountFrozen.into());
+            }
+            if !Self::cmp_pubkeys(&source_account.mint, &destination_account.mint) {
+                return Err(TokenError::MintMismatch.into());
+            }
+
+            destination_account.amount = destination_account
+                .amount
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+
+            if amount == 0 {
+                Self::check_account_owner(program_id, destination_info)?;
+            }
+
+            Account::pack(destination_account, &mut destination_info.data.borrow_mut())?;
+        }
+
+        let destination_starting_lamports = lamports_destination_info.lamports();
+        **lamports_destination_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(source_account_info.lamports())
+            .ok_or(TokenError::Overflow)?;
+
+        **source_account_info.lamports.borrow_mut() = 0;
+        delete_account(source_account_info)?;
+
+        Ok(())
+    }
+
     /// Processes a [`FreezeAccount`](enum.TokenInstruction.html) or a
     /// [`ThawAccount`](enum.TokenInstruction.html) instruction.
     pub fn process_toggle_freeze_account(
@@ -800,6 +1169,32 @@ impl Processor {
         Ok(())
     }
 
+    /// Processes a [`GetAccountDataSizeForKind`](enum.TokenInstruction.html)
+    /// instruction
+    pub fn process_get_account_data_size_for_kind(
+        kind: AccountKind,
+        extension_types: &[u16],
+    ) -> ProgramResult {
+        // Immutable ownership is built into token accounts, so the extension
+        // is accepted without changing their size.
+        if extension_types.iter().any(|extension_type| {
+            kind != AccountKind::Account || *extension_type != IMMUTABLE_OWNER_EXTENSION_TYPE
+        }) {
+            return Err(TokenError::ExtensionNotSupported.into());
+        }
+
+        set_return_data(&(kind.data_len() as u64).to_le_bytes());
+        Ok(())
+    }
+
+    /// Processes a [`GetMinimumBalance`](enum.TokenInstruction.html)
+    /// instruction
+    pub fn process_get_minimum_balance(kind: AccountKind) -> ProgramResult {
+        let minimum_balance = Rent::get()?.minimum_balance(kind.data_len());
+        set_return_data(&minimum_balance.to_le_bytes());
+        Ok(())
+    }
+
     /// Processes an [`InitializeImmutableOwner`](enum.TokenInstruction.html)
     /// instruction
     pub fn process_initialize_immutable_owner(accounts: &[AccountInfo]) -> ProgramResult {
@@ -826,9 +1221,10 @@ impl Processor {
 
         let mint = Mint::unpack(&mint_info.data.borrow_mut())
             .map_err(|_| Into::<ProgramError>::into(TokenError::InvalidMint))?;
-        let ui_amount = amount_to_ui_amount_string_trimmed(amount, mint.decimals);
+        let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+        let ui_amount = format_ui_amount_trimmed(amount, mint.decimals, &mut buffer);
 
-        set_return_data(&ui_amount.into_bytes());
+        set_return_data(ui_amount.as_bytes());
         Ok(())
     }
 
@@ -845,12 +1241,500 @@ impl Processor {
 
         let mint = Mint::unpack(&mint_info.data.borrow_mut())
             .map_err(|_| Into::<ProgramError>::into(TokenError::InvalidMint))?;
-        let amount = try_ui_amount_into_amount(ui_amount.to_string(), mint.decimals)?;
+        let amount =
+            parse_ui_amount(ui_amount, mint.decimals).ok_or(ProgramError::InvalidArgument)?;
 
         set_return_data(&amount.to_le_bytes());
         Ok(())
     }
 
+    /// Processes a [`WithdrawExcessLamports`](enum.TokenInstruction.html)
+    /// instruction
+    pub fn process_withdraw_excess_lamports(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        Self::check_account_owner(program_id, source_account_info)?;
+
+        let (authority, retained_lamports) =
+            Self::withdraw_authority_and_reserve(source_account_info)?;
+        match authority {
+            COption::Some(expected_owner) => Self::validate_owner(
+                program_id,
+                &expected_owner,
+                authority_info,
+                account_info_iter.as_slice(),
+            )?,
+            COption::None if source_account_info.key == authority_info.key => {
+                // Without a mint authority, the mint itself needs to sign.
+                if !authority_info.is_signer {
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+            }
+            COption::None => return Err(TokenError::AuthorityTypeNotSupported.into()),
+        }
+
+        let transfer_amount =
+            Self::withdraw_excess_lamports(source_account_info, retained_lamports)?;
+
+        let destination_starting_lamports = destination_info.lamports();
+        **destination_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(transfer_amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Processes a [`SweepExcessLamports`](enum.TokenInstruction.html)
+    /// instruction
+    pub fn process_sweep_excess_lamports(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        source_count: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let destination_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let remaining = account_info_iter.as_slice();
+        let source_count = source_count as usize;
+        if source_count == 0 || remaining.len() < source_count {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        let (sources, signers) = remaining.split_at(source_count);
+        let source_data_len = sources[0].data_len();
+
+        let mut authority_validated = false;
+        let mut transfer_amount = 0u64;
+        for source_account_info in sources {
+            Self::check_account_owner(program_id, source_account_info)?;
+            // all sources must be of the same kind
+            if source_account_info.data_len() != source_data_len {
+                return Err(TokenError::InvalidState.into());
+            }
+
+            let (authority, retained_lamports) =
+                Self::withdraw_authority_and_reserve(source_account_info)?;
+            match authority {
+                COption::Some(expected_owner) if authority_validated => {
+                    // the signatures were already validated, so only the
+                    // expected owner needs to match
+                    if !Self::cmp_pubkeys(&expected_owner, authority_info.key) {
+                        return Err(TokenError::OwnerMismatch.into());
+                    }
+                }
+                COption::Some(expected_owner) => {
+                    Self::validate_owner(program_id, &expected_owner, authority_info, signers)?;
+                    authority_validated = true;
+                }
+                COption::None if source_account_info.key == authority_info.key => {
+                    // Without a mint authority, the mint itself needs to sign.
+                    if !authority_info.is_signer {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                }
+                COption::None => return Err(TokenError::AuthorityTypeNotSupported.into()),
+            }
+
+            transfer_amount = transfer_amount
+                .checked_add(Self::withdraw_excess_lamports(
+                    source_account_info,
+                    retained_lamports,
+                )?)
+                .ok_or(TokenError::Overflow)?;
+        }
+
+        let destination_starting_lamports = destination_info.lamports();
+        **destination_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(transfer_amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Processes a [`WithdrawExcessNativeLamports`](enum.TokenInstruction.html)
+    /// instruction
+    pub fn process_withdraw_excess_native_lamports(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+        Self::check_account_owner(program_id, source_account_info)?;
+
+        let source_account = Account::unpack(&source_account_info.data.borrow())?;
+        // the excess of a native account is what `SyncNative` would add to
+        // its amount
+        let native_account = NativeAccount::new(&source_account, source_account_info.lamports())?;
+        let retained_lamports = source_account_info
+            .lamports()
+            .checked_sub(native_account.wrappable_lamports())
+            .ok_or(TokenError::Overflow)?;
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        let transfer_amount =
+            Self::withdraw_excess_lamports(source_account_info, retained_lamports)?;
+
+        let destination_starting_lamports = destination_info.lamports();
+        **destination_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(transfer_amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Returns the owner expected to authorize withdrawing the excess lamports
+    /// of the source account, `None` for a mint without a mint authority,
+    /// together with the lamports that must remain in the account. Native
+    /// accounts are not supported.
+    fn withdraw_authority_and_reserve(
+        source_account_info: &AccountInfo,
+    ) -> Result<(COption<Pubkey>, u64), ProgramError> {
+        let source_data_len = source_account_info.data_len();
+        match source_data_len {
+            Account::LEN => {
+                let account = Account::unpack(&source_account_info.data.borrow())?;
+                if account.is_native() {
+                    return Err(TokenError::NativeNotSupported.into());
+                }
+                Ok((
+                    COption::Some(account.owner),
+                    Rent::get()?.minimum_balance(source_data_len),
+                ))
+            }
+            Mint::LEN => {
+                let mint = Mint::unpack(&source_account_info.data.borrow())?;
+                Ok((
+                    mint.mint_authority,
+                    Rent::get()?.minimum_balance(source_data_len),
+                ))
+            }
+            Multisig::LEN => Ok((
+                COption::Some(*source_account_info.key),
+                Rent::get()?.minimum_balance(source_data_len),
+            )),
+            _ => Err(TokenError::InvalidState.into()),
+        }
+    }
+
+    /// Moves the lamports above `retained_lamports` out of the source account,
+    /// returning the amount withdrawn.
+    fn withdraw_excess_lamports(
+        source_account_info: &AccountInfo,
+        retained_lamports: u64,
+    ) -> Result<u64, ProgramError> {
+        let transfer_amount = source_account_info
+            .lamports()
+            .checked_sub(retained_lamports)
+            .ok_or(TokenError::NotRentExempt)?;
+
+        let source_starting_lamports = source_account_info.lamports();
+        **source_account_info.lamports.borrow_mut() = source_starting_lamports
+            .checked_sub(transfer_amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Ok(transfer_amount)
+    }
+
+    /// Processes an [`InitializeClawbackConfig`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_initialize_clawback_config(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        authority: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let clawback_config_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let payer_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !mint_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Self::check_account_owner(program_id, mint_info)?;
+        if mint_info.data_len() != Mint::LEN {
+            return Err(TokenError::InvalidState.into());
+        }
+        let mint = Mint::unpack_unchecked(&mint_info.data.borrow())?;
+        if mint.is_initialized {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+
+        let (clawback_config_address, bump) =
+            find_clawback_config_address(mint_info.key, program_id);
+        if !Self::cmp_pubkeys(&clawback_config_address, clawback_config_info.key) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if !clawback_config_info.data_is_empty() {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+
+        Self::create_program_account(
+            program_id,
+            clawback_config_info,
+            payer_info,
+            system_program_info,
+            ClawbackConfig::LEN,
+            &[CLAWBACK_CONFIG_SEED, mint_info.key.as_ref(), &[bump]],
+        )?;
+
+        let clawback_config = ClawbackConfig {
+            mint: *mint_info.key,
+            authority,
+            is_initialized: true,
+            bump,
+        };
+        ClawbackConfig::pack(clawback_config, &mut clawback_config_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`ClawbackTransfer`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_clawback_transfer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let clawback_config_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let clawback_config = Self::unpack_clawback_config(program_id, clawback_config_info)?;
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+        let mut destination_account = Account::unpack(&destination_account_info.data.borrow())?;
+
+        if !Self::cmp_pubkeys(&clawback_config.mint, &source_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+        if source_account.is_native() {
+            return Err(TokenError::NativeNotSupported.into());
+        }
+        if source_account.amount < amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+
+        // The source account may be frozen, but tokens cannot be moved into a
+        // frozen account.
+        let self_transfer =
+            Self::cmp_pubkeys(source_account_info.key, destination_account_info.key);
+        if !self_transfer {
+            if destination_account.is_frozen() {
+                return Err(TokenError::AccountFrozen.into());
+            }
+            if !Self::cmp_pubkeys(&source_account.mint, &destination_account.mint) {
+                return Err(TokenError::MintMismatch.into());
+            }
+        }
+
+        Self::validate_owner(
+            program_id,
+            &clawback_config.authority,
+            authority_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        if self_transfer || amount == 0 {
+            Self::check_account_owner(program_id, source_account_info)?;
+            Self::check_account_owner(program_id, destination_account_info)?;
+        }
+
+        log_data(&[
+            b"clawback_transfer",
+            source_account_info.key.as_ref(),
+            destination_account_info.key.as_ref(),
+            &amount.to_le_bytes(),
+        ]);
+
+        if self_transfer {
+            return Ok(());
+        }
+
+        // The delegation of the source account is left unchanged.
+        source_account.amount = source_account
+            .amount
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+        destination_account.amount = destination_account
+            .amount
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+        Account::pack(
+            destination_account,
+            &mut destination_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Processes a [`ClawbackBurn`](enum.TokenInstruction.html) instruction.
+    pub fn process_clawback_burn(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let clawback_config_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let clawback_config = Self::unpack_clawback_config(program_id, clawback_config_info)?;
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+        let mut mint = Mint::unpack(&mint_info.data.borrow())?;
+
+        // The source account may be frozen.
+        if source_account.is_native() {
+            return Err(TokenError::NativeNotSupported.into());
+        }
+        if !Self::cmp_pubkeys(mint_info.key, &source_account.mint)
+            || !Self::cmp_pubkeys(&clawback_config.mint, &source_account.mint)
+        {
+            return Err(TokenError::MintMismatch.into());
+        }
+        if source_account.amount < amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &clawback_config.authority,
+            authority_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        if amount == 0 {
+            Self::check_account_owner(program_id, source_account_info)?;
+            Self::check_account_owner(program_id, mint_info)?;
+        }
+
+        log_data(&[
+            b"clawback_burn",
+            source_account_info.key.as_ref(),
+            mint_info.key.as_ref(),
+            &amount.to_le_bytes(),
+        ]);
+
+        // The delegation of the source account is left unchanged.
+        source_account.amount = source_account
+            .amount
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+        mint.supply = mint
+            .supply
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+        Mint::pack(mint, &mut mint_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Unpacks a clawback configuration, checking that the account is at the
+    /// program derived address of its mint.
+    fn unpack_clawback_config(
+        program_id: &Pubkey,
+        clawback_config_info: &AccountInfo,
+    ) -> Result<ClawbackConfig, ProgramError> {
+        Self::check_account_owner(program_id, clawback_config_info)?;
+        let clawback_config = ClawbackConfig::unpack(&clawback_config_info.data.borrow())?;
+
+        let clawback_config_address = Pubkey::create_program_address(
+            &[
+                CLAWBACK_CONFIG_SEED,
+                clawback_config.mint.as_ref(),
+                &[clawback_config.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+        if !Self::cmp_pubkeys(&clawback_config_address, clawback_config_info.key) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(clawback_config)
+    }
+
+    /// Creates an account of `space` bytes owned by the program at a program
+    /// derived address, funded by the payer with the rent-exempt minimum
+    /// balance.
+    ///
+    /// Lamports already held by the account are kept, so that the account
+    /// cannot be prevented from being created by transferring lamports to it
+    /// beforehand.
+    fn create_program_account<'a>(
+        program_id: &Pubkey,
+        account_info: &AccountInfo<'a>,
+        payer_info: &AccountInfo<'a>,
+        system_program_info: &AccountInfo<'a>,
+        space: usize,
+        signer_seeds: &[&[u8]],
+    ) -> ProgramResult {
+        let minimum_balance = Rent::get()?.minimum_balance(space);
+
+        if account_info.lamports() == 0 {
+            return invoke_signed(
+                &system_instruction::create_account(
+                    payer_info.key,
+                    account_info.key,
+                    minimum_balance,
+                    space as u64,
+                    program_id,
+                ),
+                &[
+                    payer_info.clone(),
+                    account_info.clone(),
+                    system_program_info.clone(),
+                ],
+                &[signer_seeds],
+            );
+        }
+
+        let required_lamports = minimum_balance.saturating_sub(account_info.lamports());
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(payer_info.key, account_info.key, required_lamports),
+                &[
+                    payer_info.clone(),
+                    account_info.clone(),
+                    system_program_info.clone(),
+                ],
+            )?;
+        }
+
+        invoke_signed(
+            &system_instruction::allocate(account_info.key, space as u64),
+            &[account_info.clone(), system_program_info.clone()],
+            &[signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(account_info.key, program_id),
+            &[account_info.clone(), system_program_info.clone()],
+            &[signer_seeds],
+        )
+    }
+
     /// Processes an [`Instruction`](enum.Instruction.html).
     pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
         let instruction = TokenInstruction::unpack(input)?;
@@ -884,6 +1768,10 @@ impl Processor {
                 msg!("Instruction: InitializeAccount3");
                 Self::process_initialize_account3(program_id, accounts, owner)
             }
+            TokenInstruction::InitializeAccountIdempotent { owner } => {
+                msg!("Instruction: InitializeAccountIdempotent");
+                Self::process_initialize_account_idempotent(program_id, accounts, owner)
+            }
             TokenInstruction::InitializeMultisig { m } => {
                 msg!("Instruction: InitializeMultisig");
                 Self::process_initialize_multisig(accounts, m)
@@ -967,6 +1855,95 @@ impl Processor {
                 msg!("Instruction: UiAmountToAmount");
                 Self::process_ui_amount_to_amount(program_id, accounts, ui_amount)
             }
+            TokenInstruction::SweepAndClose { burn } => {
+                msg!("Instruction: SweepAndClose");
+                Self::process_sweep_and_close(program_id, accounts, burn)
+            }
+            TokenInstruction::BurnAll => {
+                msg!("Instruction: BurnAll");
+                Self::process_burn_all(program_id, accounts)
+            }
+            TokenInstruction::TransferAll => {
+                msg!("Instruction: TransferAll");
+                Self::process_transfer_all(program_id, accounts, None)
+            }
+            TokenInstruction::TransferAllChecked { decimals } => {
+                msg!("Instruction: TransferAllChecked");
+                Self::process_transfer_all(program_id, accounts, Some(decimals))
+            }
+            TokenInstruction::ApproveIfCurrent {
+                expected_delegate,
+                expected_amount,
+                new_amount,
+            } => {
+                msg!("Instruction: ApproveIfCurrent");
+                Self::process_approve_if_current(
+                    program_id,
+                    accounts,
+                    expected_delegate,
+                    expected_amount,
+                    new_amount,
+                )
+            }
+            TokenInstruction::IncreaseAllowance { amount } => {
+                msg!("Instruction: IncreaseAllowance");
+                Self::process_update_allowance(program_id, accounts, amount, true)
+            }
+            TokenInstruction::DecreaseAllowance { amount } => {
+                msg!("Instruction: DecreaseAllowance");
+                Self::process_update_allowance(program_id, accounts, amount, false)
+            }
+            TokenInstruction::InitializePermitNonce => {
+                msg!("Instruction: InitializePermitNonce");
+                Self::process_initialize_permit_nonce(accounts)
+            }
+            TokenInstruction::ApproveWithPermit {
+                amount,
+                nonce,
+                expiry,
+            } => {
+                msg!("Instruction: ApproveWithPermit");
+                Self::process_approve_with_permit(program_id, accounts, amount, nonce, expiry)
+            }
+            TokenInstruction::GetMinimumBalance { kind } => {
+                msg!("Instruction: GetMinimumBalance");
+                Self::process_get_minimum_balance(kind)
+            }
+            TokenInstruction::GetAccountDataSizeForKind {
+                kind,
+                extension_types,
+            } => {
+                msg!("Instruction: GetAccountDataSizeForKind");
+                Self::process_get_account_data_size_for_kind(kind, &extension_types)
+            }
+            TokenInstruction::WithdrawExcessNativeLamports => {
+                msg!("Instruction: WithdrawExcessNativeLamports");
+                Self::process_withdraw_excess_native_lamports(program_id, accounts)
+            }
+            TokenInstruction::WithdrawExcessLamports => {
+                msg!("Instruction: WithdrawExcessLamports");
+                Self::process_withdraw_excess_lamports(program_id, accounts)
+            }
+            TokenInstruction::SweepExcessLamports { source_count } => {
+                msg!("Instruction: SweepExcessLamports");
+                Self::process_sweep_excess_lamports(program_id, accounts, source_count)
+            }
+            TokenInstruction::InitializeClawbackConfig { authority } => {
+                msg!("Instruction: InitializeClawbackConfig");
+                Self::process_initialize_clawback_config(program_id, accounts, authority)
+            }
+            TokenInstruction::ClawbackTransfer { amount } => {
+                msg!("Instruction: ClawbackTransfer");
+                Self::process_clawback_transfer(program_id, accounts, amount)
+            }
+            TokenInstruction::ClawbackBurn { amount } => {
+                msg!("Instruction: ClawbackBurn");
+                Self::process_clawback_burn(program_id, accounts, amount)
+            }
+            TokenInstruction::UnwrapLamports { .. }
+            | TokenInstruction::CreateAndInitializeMint { .. }
+            | TokenInstruction::CreateAndInitializeAccount { .. }
+            | TokenInstruction::Batch => Err(TokenError::InvalidInstruction.into()),
         }
     }
 
@@ -1044,6 +2021,18 @@ fn delete_account(account_info: &AccountInfo) -> Result<(), ProgramError> {
     account_info.resize(0)
 }
 
+/// Helper function to log the fields of an event in a test environment
+#[cfg(not(target_os = "solana"))]
+fn log_data(data: &[&[u8]]) {
+    solana_sysvar::program_stubs::sol_log_data(data);
+}
+
+/// Helper function to log the fields of an event on-chain
+#[cfg(target_os = "solana")]
+fn log_data(data: &[&[u8]]) {
+    unsafe { solana_msg::syscalls::sol_log_data(data as *const _ as *const u8, data.len() as u64) };
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -1,7 +1,7 @@
 //! Program state processor tests
 
 use {
-    mollusk_svm::{result::Check, Mollusk},
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check, Mollusk},
     serial_test::serial,
     solana_account::{create_account_for_test, Account as SolanaAccount, ReadableAccount},
     solana_account_info::{AccountInfo, IntoAccountInfo},
@@ -11,18 +11,29 @@ use {
     solana_program_pack::Pack,
     solana_pubkey::Pubkey,
     solana_rent::Rent,
-    solana_sdk_ids::sysvar::rent,
+    solana_sdk_ids::{system_program, sysvar::rent},
     spl_token_interface::{
+        amount::TokenAmount,
         error::TokenError,
         instruction::{
-            amount_to_ui_amount, approve, approve_checked, burn, burn_checked, close_account,
-            freeze_account, get_account_data_size, initialize_account, initialize_account2,
-            initialize_account3, initialize_immutable_owner, initialize_mint, initialize_mint2,
-            initialize_multisig, initialize_multisig2, mint_to, mint_to_checked, revoke,
-            set_authority, sync_native, thaw_account, transfer, transfer_checked,
-            ui_amount_to_amount, AuthorityType, MAX_SIGNERS,
+            amount_to_ui_amount, approve, approve_checked, approve_if_current, approve_with_permit,
+            burn, burn_all, burn_checked, clawback_burn, clawback_transfer, close_account,
+            decrease_allowance, freeze_account, get_account_data_size,
+            get_account_data_size_for_kind, get_minimum_balance, increase_allowance,
+            initialize_account, initialize_account2, initialize_account3,
+            initialize_account_idempotent, initialize_clawback_config, initialize_immutable_owner,
+            initialize_mint, initialize_mint2, initialize_multisig, initialize_multisig2,
+            initialize_permit_nonce, mint_to, mint_to_checked, revoke, set_authority,
+            sweep_and_close, sweep_excess_lamports, sync_native, thaw_account, transfer,
+            transfer_all, transfer_all_checked, transfer_checked, ui_amount_to_amount,
+            withdraw_excess_lamports, withdraw_excess_native_lamports, AccountKind, AuthorityType,
+            IMMUTABLE_OWNER_EXTENSION_TYPE, MAX_SIGNERS,
+        },
+        native_mint::NativeAccount,
+        state::{
+            find_clawback_config_address, Account, AccountState, ClawbackConfig, Mint, Multisig,
+            PermitNonce,
         },
-        state::{Account, AccountState, Mint, Multisig},
     },
     std::collections::HashMap,
 };
@@ -5828,6 +5839,124 @@ fn test_initialize_account2_and_3() {
     assert_eq!(account_account, account3_account);
 }
 
+#[test]
+fn test_initialize_account_idempotent() {
+    let program_id = spl_token_interface::id();
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let mint2_key = Pubkey::new_unique();
+    let mut mint2_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mints
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, Some(&owner_key), 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_mint(&program_id, &mint2_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint2_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // initialize a new account
+    do_process_instruction(
+        initialize_account_idempotent(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![&mut account_account, &mut mint_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack(&account_account.data).unwrap();
+    assert_eq!(account.mint, mint_key);
+    assert_eq!(account.owner, owner_key);
+    assert_eq!(account.state, AccountState::Initialized);
+
+    // retrying leaves the account unchanged, even with a balance
+    let mut account = account;
+    account.amount = 42;
+    Account::pack(account, &mut account_account.data).unwrap();
+    let expected = account_account.clone();
+    do_process_instruction(
+        initialize_account_idempotent(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![&mut account_account, &mut mint_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(account_account, expected);
+
+    // the non-idempotent variant still fails
+    assert_eq!(
+        Err(TokenError::AlreadyInUse.into()),
+        do_process_instruction(
+            initialize_account3(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+            vec![&mut account_account, &mut mint_account],
+            &[Check::err(TokenError::AlreadyInUse.into())],
+        )
+    );
+
+    // fail with a different mint
+    assert_eq!(
+        Err(TokenError::MintMismatch.into()),
+        do_process_instruction(
+            initialize_account_idempotent(&program_id, &account_key, &mint2_key, &owner_key)
+                .unwrap(),
+            vec![&mut account_account, &mut mint2_account],
+            &[Check::err(TokenError::MintMismatch.into())],
+        )
+    );
+
+    // fail with a different owner
+    let other_owner_key = Pubkey::new_unique();
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            initialize_account_idempotent(&program_id, &account_key, &mint_key, &other_owner_key)
+                .unwrap(),
+            vec![&mut account_account, &mut mint_account],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // fail if the account is frozen
+    let mut account = Account::unpack(&account_account.data).unwrap();
+    account.state = AccountState::Frozen;
+    Account::pack(account, &mut account_account.data).unwrap();
+    assert_eq!(
+        Err(TokenError::AccountFrozen.into()),
+        do_process_instruction(
+            initialize_account_idempotent(&program_id, &account_key, &mint_key, &owner_key)
+                .unwrap(),
+            vec![&mut account_account, &mut mint_account],
+            &[Check::err(TokenError::AccountFrozen.into())],
+        )
+    );
+
+    // fail if the existing account is not owned by the token program
+    let mut foreign_account = expected.clone();
+    foreign_account.owner = Pubkey::new_unique();
+    assert_eq!(
+        Err(ProgramError::IncorrectProgramId),
+        do_process_instruction(
+            initialize_account_idempotent(&program_id, &account_key, &mint_key, &owner_key)
+                .unwrap(),
+            vec![&mut foreign_account, &mut mint_account],
+            &[Check::err(ProgramError::IncorrectProgramId)],
+        )
+    );
+}
+
 #[test]
 fn test_sync_native() {
     let program_id = spl_token_interface::id();
@@ -5967,6 +6096,12 @@ fn test_sync_native() {
     // transfer sol
     let new_lamports = lamports + 50;
     native_account.lamports = account_minimum_balance() + new_lamports;
+    let native = NativeAccount::new(&account, native_account.lamports).unwrap();
+    assert_eq!(native.wrappable_lamports(), 50);
+    assert_eq!(
+        native.expected_amount_after_sync(account_minimum_balance()),
+        Ok(new_lamports)
+    );
 
     // success sync
     do_process_instruction(
@@ -6041,93 +6176,516 @@ fn test_get_account_data_size() {
 }
 
 #[test]
-fn test_initialize_immutable_owner() {
+#[serial]
+fn test_get_account_data_size_for_kind() {
+    let program_id = spl_token_interface::id();
+
+    // immutable owner is accepted for token accounts without changing the size
+    for extension_types in [&[][..], &[IMMUTABLE_OWNER_EXTENSION_TYPE]] {
+        do_process_instruction(
+            get_account_data_size_for_kind(&program_id, AccountKind::Account, extension_types)
+                .unwrap(),
+            vec![],
+            &[
+                Check::success(),
+                Check::return_data(&(Account::LEN as u64).to_le_bytes()),
+            ],
+        )
+        .unwrap();
+    }
+
+    // fail if another extension is requested
+    assert_eq!(
+        Err(TokenError::ExtensionNotSupported.into()),
+        do_process_instruction(
+            get_account_data_size_for_kind(
+                &program_id,
+                AccountKind::Account,
+                &[IMMUTABLE_OWNER_EXTENSION_TYPE, 1],
+            )
+            .unwrap(),
+            vec![],
+            &[Check::err(TokenError::ExtensionNotSupported.into())],
+        )
+    );
+
+    for (kind, len) in [
+        (AccountKind::Mint, Mint::LEN),
+        (AccountKind::Multisig, Multisig::LEN),
+    ] {
+        do_process_instruction(
+            get_account_data_size_for_kind(&program_id, kind, &[]).unwrap(),
+            vec![],
+            &[
+                Check::success(),
+                Check::return_data(&(len as u64).to_le_bytes()),
+            ],
+        )
+        .unwrap();
+
+        // no extension is supported for mints and multisigs
+        assert_eq!(
+            Err(TokenError::ExtensionNotSupported.into()),
+            do_process_instruction(
+                get_account_data_size_for_kind(
+                    &program_id,
+                    kind,
+                    &[IMMUTABLE_OWNER_EXTENSION_TYPE],
+                )
+                .unwrap(),
+                vec![],
+                &[Check::err(TokenError::ExtensionNotSupported.into())],
+            )
+        );
+    }
+}
+
+#[test]
+fn test_get_minimum_balance() {
     let program_id = spl_token_interface::id();
+
+    for (kind, minimum_balance) in [
+        (AccountKind::Account, account_minimum_balance()),
+        (AccountKind::Mint, mint_minimum_balance()),
+        (AccountKind::Multisig, multisig_minimum_balance()),
+    ] {
+        do_process_instruction(
+            get_minimum_balance(&program_id, kind).unwrap(),
+            vec![],
+            &[
+                Check::success(),
+                Check::return_data(&minimum_balance.to_le_bytes()),
+            ],
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn test_withdraw_excess_lamports() {
+    let program_id = spl_token_interface::id();
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
     let account_key = Pubkey::new_unique();
     let mut account_account = SolanaAccount::new(
-        account_minimum_balance(),
+        account_minimum_balance() + 42,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let native_account_key = Pubkey::new_unique();
+    let mut native_account_account = SolanaAccount::new(
+        account_minimum_balance() + 100 + 42,
         Account::get_packed_len(),
         &program_id,
     );
-    let owner_key = Pubkey::new_unique();
-    let mut owner_account = SolanaAccount::default();
-    let mint_key = Pubkey::new_unique();
-    let mut mint_account =
-        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
-    let mut rent_sysvar = rent_sysvar();
 
-    // create mint
-    do_process_instruction(
-        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
-        vec![&mut mint_account, &mut rent_sysvar],
-        &[Check::success()],
+    Account::pack(
+        Account {
+            mint: Pubkey::new_unique(),
+            owner: owner_key,
+            state: AccountState::Initialized,
+            ..Account::default()
+        },
+        &mut account_account.data,
     )
     .unwrap();
-
-    // success initialize immutable
-    do_process_instruction(
-        initialize_immutable_owner(&program_id, &account_key).unwrap(),
-        vec![&mut account_account],
-        &[Check::success()],
+    Account::pack(
+        Account {
+            mint: spl_token_interface::native_mint::id(),
+            owner: owner_key,
+            amount: 100,
+            state: AccountState::Initialized,
+            is_native: COption::Some(account_minimum_balance()),
+            ..Account::default()
+        },
+        &mut native_account_account.data,
     )
     .unwrap();
 
-    // create account
+    // fail with a different owner
+    let other_owner_key = Pubkey::new_unique();
+    let mut other_owner_account = SolanaAccount::default();
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            withdraw_excess_lamports(
+                &program_id,
+                &account_key,
+                &destination_key,
+                &other_owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut destination_account,
+                &mut other_owner_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // withdraw the lamports above the rent-exempt reserve
     do_process_instruction(
-        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        withdraw_excess_lamports(&program_id, &account_key, &destination_key, &owner_key, &[])
+            .unwrap(),
         vec![
             &mut account_account,
-            &mut mint_account,
+            &mut destination_account,
             &mut owner_account,
-            &mut rent_sysvar,
         ],
-        &[Check::success()],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .lamports(account_minimum_balance())
+                .build(),
+            Check::account(&destination_key).lamports(42).build(),
+        ],
     )
     .unwrap();
 
-    // fail post-init
+    // fail with a native account
     assert_eq!(
-        Err(TokenError::AlreadyInUse.into()),
+        Err(TokenError::NativeNotSupported.into()),
         do_process_instruction(
-            initialize_immutable_owner(&program_id, &account_key).unwrap(),
-            vec![&mut account_account],
-            &[Check::err(TokenError::AlreadyInUse.into())],
+            withdraw_excess_lamports(
+                &program_id,
+                &native_account_key,
+                &destination_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut native_account_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::NativeNotSupported.into())],
         )
     );
-}
 
-#[test]
-#[serial]
-fn test_amount_to_ui_amount() {
-    let program_id = spl_token_interface::id();
-    let owner_key = Pubkey::new_unique();
-    let mint_key = Pubkey::new_unique();
-    let mut mint_account =
-        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
-    let mut rent_sysvar = rent_sysvar();
+    // fail to withdraw the native excess of a non-native account
+    assert_eq!(
+        Err(TokenError::NonNativeNotSupported.into()),
+        do_process_instruction(
+            withdraw_excess_native_lamports(
+                &program_id,
+                &account_key,
+                &destination_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::NonNativeNotSupported.into())],
+        )
+    );
 
-    // fail if an invalid mint is passed in
+    // fail to withdraw the native excess with a different owner
     assert_eq!(
-        Err(TokenError::InvalidMint.into()),
+        Err(TokenError::OwnerMismatch.into()),
         do_process_instruction(
-            amount_to_ui_amount(&program_id, &mint_key, 110).unwrap(),
-            vec![&mut mint_account],
-            &[Check::err(TokenError::InvalidMint.into())],
+            withdraw_excess_native_lamports(
+                &program_id,
+                &native_account_key,
+                &destination_key,
+                &other_owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut native_account_account,
+                &mut destination_account,
+                &mut other_owner_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
         )
     );
 
-    // create mint
+    // withdraw the lamports of a native account above its reserve and amount
     do_process_instruction(
-        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
-        vec![&mut mint_account, &mut rent_sysvar],
-        &[Check::success()],
+        withdraw_excess_native_lamports(
+            &program_id,
+            &native_account_key,
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut native_account_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&native_account_key)
+                .lamports(account_minimum_balance() + 100)
+                .build(),
+            Check::account(&destination_key).lamports(84).build(),
+        ],
     )
     .unwrap();
+    let native_account = Account::unpack(&native_account_account.data).unwrap();
+    assert_eq!(native_account.amount, 100);
+    assert_eq!(
+        NativeAccount::new(&native_account, native_account_account.lamports)
+            .unwrap()
+            .wrappable_lamports(),
+        0
+    );
+}
 
-    do_process_instruction(
-        amount_to_ui_amount(&program_id, &mint_key, 23).unwrap(),
-        vec![&mut mint_account],
-        &[Check::success(), Check::return_data("0.23".as_bytes())],
+#[test]
+fn test_sweep_excess_lamports() {
+    let program_id = spl_token_interface::id();
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    let mint_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance() + 42,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance() + 58,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let other_account_key = Pubkey::new_unique();
+    let mut other_account_account = SolanaAccount::new(
+        account_minimum_balance() + 1,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let mut mint_account = SolanaAccount::new(
+        mint_minimum_balance() + 1,
+        Mint::get_packed_len(),
+        &program_id,
+    );
+
+    for (owner, data) in [
+        (owner_key, &mut account_account.data),
+        (owner_key, &mut account2_account.data),
+        (Pubkey::new_unique(), &mut other_account_account.data),
+    ] {
+        Account::pack(
+            Account {
+                mint: mint_key,
+                owner,
+                state: AccountState::Initialized,
+                ..Account::default()
+            },
+            data,
+        )
+        .unwrap();
+    }
+    Mint::pack(
+        Mint {
+            mint_authority: COption::Some(owner_key),
+            is_initialized: true,
+            ..Mint::default()
+        },
+        &mut mint_account.data,
+    )
+    .unwrap();
+
+    // fail if a source expects a different owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            sweep_excess_lamports(
+                &program_id,
+                &[&account_key, &other_account_key],
+                &destination_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut destination_account,
+                &mut owner_account,
+                &mut account_account,
+                &mut other_account_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // fail if the sources are of different kinds
+    assert_eq!(
+        Err(TokenError::InvalidState.into()),
+        do_process_instruction(
+            sweep_excess_lamports(
+                &program_id,
+                &[&account_key, &mint_key],
+                &destination_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut destination_account,
+                &mut owner_account,
+                &mut account_account,
+                &mut mint_account,
+            ],
+            &[Check::err(TokenError::InvalidState.into())],
+        )
+    );
+
+    // fail without sources
+    let mut instruction = sweep_excess_lamports(
+        &program_id,
+        &[&account_key],
+        &destination_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+    instruction.data[1] = 0;
+    assert_eq!(
+        Err(ProgramError::NotEnoughAccountKeys),
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut destination_account,
+                &mut owner_account,
+                &mut account_account,
+            ],
+            &[Check::err(ProgramError::NotEnoughAccountKeys)],
+        )
+    );
+
+    // sweep the lamports above the rent-exempt reserve of every source
+    do_process_instruction(
+        sweep_excess_lamports(
+            &program_id,
+            &[&account_key, &account2_key],
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut destination_account,
+            &mut owner_account,
+            &mut account_account,
+            &mut account2_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .lamports(account_minimum_balance())
+                .build(),
+            Check::account(&account2_key)
+                .lamports(account_minimum_balance())
+                .build(),
+            Check::account(&destination_key).lamports(100).build(),
+        ],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_initialize_immutable_owner() {
+    let program_id = spl_token_interface::id();
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mint
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // success initialize immutable
+    do_process_instruction(
+        initialize_immutable_owner(&program_id, &account_key).unwrap(),
+        vec![&mut account_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // create account
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // fail post-init
+    assert_eq!(
+        Err(TokenError::AlreadyInUse.into()),
+        do_process_instruction(
+            initialize_immutable_owner(&program_id, &account_key).unwrap(),
+            vec![&mut account_account],
+            &[Check::err(TokenError::AlreadyInUse.into())],
+        )
+    );
+}
+
+#[test]
+#[serial]
+fn test_amount_to_ui_amount() {
+    let program_id = spl_token_interface::id();
+    let owner_key = Pubkey::new_unique();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let mut rent_sysvar = rent_sysvar();
+
+    // fail if an invalid mint is passed in
+    assert_eq!(
+        Err(TokenError::InvalidMint.into()),
+        do_process_instruction(
+            amount_to_ui_amount(&program_id, &mint_key, 110).unwrap(),
+            vec![&mut mint_account],
+            &[Check::err(TokenError::InvalidMint.into())],
+        )
+    );
+
+    // create mint
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    do_process_instruction(
+        amount_to_ui_amount(&program_id, &mint_key, 23).unwrap(),
+        vec![&mut mint_account],
+        &[Check::success(), Check::return_data("0.23".as_bytes())],
     )
     .unwrap();
 
@@ -6278,3 +6836,1400 @@ fn test_ui_amount_to_amount() {
         )
     );
 }
+
+/// Reference conversion of a raw amount to its trimmed UI representation, as
+/// originally implemented with allocations by the Token program.
+fn reference_amount_to_ui_amount(amount: u64, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    if decimals > 0 {
+        let mut s = format!("{:01$}", amount, decimals.saturating_add(1));
+        s.insert(s.len().saturating_sub(decimals), '.');
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        amount.to_string()
+    }
+}
+
+/// Reference conversion of a UI amount to its raw value, as originally
+/// implemented with allocations by the Token program.
+fn reference_ui_amount_to_amount(ui_amount: &str, decimals: u8) -> Option<u64> {
+    let decimals = decimals as usize;
+    let mut parts = ui_amount.split('.');
+    let mut amount_str = parts.next().unwrap().to_string();
+    let after_decimal = parts.next().unwrap_or("").trim_end_matches('0');
+    if (amount_str.is_empty() && after_decimal.is_empty())
+        || parts.next().is_some()
+        || after_decimal.len() > decimals
+    {
+        return None;
+    }
+    amount_str.push_str(after_decimal);
+    for _ in 0..decimals.saturating_sub(after_decimal.len()) {
+        amount_str.push('0');
+    }
+    amount_str.parse::<u64>().ok()
+}
+
+proptest::proptest! {
+    #![proptest_config(proptest::prelude::ProptestConfig::with_cases(64))]
+
+    #[test]
+    #[serial]
+    fn test_token_amount_matches_processor(
+        raw in proptest::prelude::any::<u64>(),
+        decimals in proptest::prelude::any::<u8>(),
+        ui_amount in "\\+?[0-9]{0,21}(\\.[0-9]{0,21}0*)?(\\.[0-9]{0,2})?",
+    ) {
+        let program_id = spl_token_interface::id();
+        let mint_key = Pubkey::new_unique();
+        let mut mint_account =
+            SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+        Mint::pack(
+            Mint {
+                decimals,
+                is_initialized: true,
+                ..Mint::default()
+            },
+            &mut mint_account.data,
+        )
+        .unwrap();
+
+        let expected_ui_amount = reference_amount_to_ui_amount(raw, decimals);
+        do_process_instruction(
+            amount_to_ui_amount(&program_id, &mint_key, raw).unwrap(),
+            vec![&mut mint_account],
+            &[Check::success(), Check::return_data(expected_ui_amount.as_bytes())],
+        )
+        .unwrap();
+        proptest::prop_assert_eq!(
+            TokenAmount::new(raw, decimals).to_ui_amount_string_trimmed(),
+            expected_ui_amount
+        );
+
+        let expected_amount = reference_ui_amount_to_amount(&ui_amount, decimals);
+        match expected_amount {
+            Some(amount) => do_process_instruction(
+                ui_amount_to_amount(&program_id, &mint_key, &ui_amount).unwrap(),
+                vec![&mut mint_account],
+                &[Check::success(), Check::return_data(&amount.to_le_bytes())],
+            )
+            .unwrap(),
+            None => proptest::prop_assert_eq!(
+                Err(ProgramError::InvalidArgument),
+                do_process_instruction(
+                    ui_amount_to_amount(&program_id, &mint_key, &ui_amount).unwrap(),
+                    vec![&mut mint_account],
+                    &[Check::err(ProgramError::InvalidArgument)],
+                )
+            ),
+        }
+        proptest::prop_assert_eq!(
+            TokenAmount::from_ui_amount_str(&ui_amount, decimals)
+                .ok()
+                .map(|amount| amount.raw),
+            expected_amount
+        );
+    }
+}
+
+#[test]
+fn test_sweep_and_close() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account3_key = Pubkey::new_unique();
+    let mut account3_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let lamports_destination_key = Pubkey::new_unique();
+    let mut lamports_destination_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+        (&account3_key, &mut account3_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 42).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // wrong owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            sweep_and_close(
+                &program_id,
+                &account_key,
+                &account2_key,
+                &lamports_destination_key,
+                &owner2_key,
+                &[],
+                false,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut lamports_destination_account,
+                &mut owner2_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // burning requires the mint as destination
+    assert_eq!(
+        Err(TokenError::MintMismatch.into()),
+        do_process_instruction(
+            sweep_and_close(
+                &program_id,
+                &account_key,
+                &account2_key,
+                &lamports_destination_key,
+                &owner_key,
+                &[],
+                true,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut lamports_destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::MintMismatch.into())],
+        )
+    );
+
+    // sweep into another account
+    do_process_instruction(
+        sweep_and_close(
+            &program_id,
+            &account_key,
+            &account2_key,
+            &lamports_destination_key,
+            &owner_key,
+            &[],
+            false,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut lamports_destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key).lamports(0).build(),
+            Check::account(&account2_key)
+                .data_slice(64, &42u64.to_le_bytes())
+                .build(),
+            Check::account(&lamports_destination_key)
+                .lamports(account_minimum_balance())
+                .build(),
+        ],
+    )
+    .unwrap();
+    assert_eq!(account_account.lamports, 0);
+    let account = Account::unpack_unchecked(&account2_account.data).unwrap();
+    assert_eq!(account.amount, 42);
+
+    // burn the balance
+    do_process_instruction(
+        sweep_and_close(
+            &program_id,
+            &account2_key,
+            &mint_key,
+            &lamports_destination_key,
+            &owner_key,
+            &[],
+            true,
+        )
+        .unwrap(),
+        vec![
+            &mut account2_account,
+            &mut mint_account,
+            &mut lamports_destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account2_key).lamports(0).build(),
+            Check::account(&mint_key)
+                .data_slice(36, &0u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let mint = Mint::unpack_unchecked(&mint_account.data).unwrap();
+    assert_eq!(mint.supply, 0);
+    assert_eq!(
+        lamports_destination_account.lamports,
+        2 * account_minimum_balance()
+    );
+
+    // empty account can be closed with a burn
+    do_process_instruction(
+        sweep_and_close(
+            &program_id,
+            &account3_key,
+            &mint_key,
+            &lamports_destination_key,
+            &owner_key,
+            &[],
+            true,
+        )
+        .unwrap(),
+        vec![
+            &mut account3_account,
+            &mut mint_account,
+            &mut lamports_destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account3_key).lamports(0).build(),
+        ],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_burn_all() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mint and account
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // delegate allowance does not cover the balance
+    do_process_instruction(
+        approve(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // the delegate burns the delegated amount
+    do_process_instruction(
+        burn_all(&program_id, &account_key, &mint_key, &delegate_key, &[]).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut delegate_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .data_slice(64, &900u64.to_le_bytes())
+                .build(),
+            Check::account(&mint_key)
+                .data_slice(36, &900u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.amount, 900);
+    assert_eq!(account.delegate, COption::None);
+    assert_eq!(account.delegated_amount, 0);
+
+    // burn the whole balance
+    do_process_instruction(
+        burn_all(&program_id, &account_key, &mint_key, &owner_key, &[]).unwrap(),
+        vec![&mut account_account, &mut mint_account, &mut owner_account],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .data_slice(64, &0u64.to_le_bytes())
+                .build(),
+            Check::account(&mint_key)
+                .data_slice(36, &0u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.amount, 0);
+    let mint = Mint::unpack_unchecked(&mint_account.data).unwrap();
+    assert_eq!(mint.supply, 0);
+
+    // burning an empty account succeeds
+    do_process_instruction(
+        burn_all(&program_id, &account_key, &mint_key, &owner_key, &[]).unwrap(),
+        vec![&mut account_account, &mut mint_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_transfer_all() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // delegate transfers its full allowance
+    do_process_instruction(
+        approve(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        transfer_all(&program_id, &account_key, &account2_key, &delegate_key, &[]).unwrap(),
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut delegate_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .data_slice(64, &900u64.to_le_bytes())
+                .build(),
+            Check::account(&account2_key)
+                .data_slice(64, &100u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::None);
+    assert_eq!(account.delegated_amount, 0);
+
+    // wrong decimals
+    assert_eq!(
+        Err(TokenError::MintDecimalsMismatch.into()),
+        do_process_instruction(
+            transfer_all_checked(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &account2_key,
+                &owner_key,
+                &[],
+                10,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mint_account,
+                &mut account2_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::MintDecimalsMismatch.into())],
+        )
+    );
+
+    // owner transfers the full balance
+    do_process_instruction(
+        transfer_all_checked(
+            &program_id,
+            &account_key,
+            &mint_key,
+            &account2_key,
+            &owner_key,
+            &[],
+            2,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut account2_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .data_slice(64, &0u64.to_le_bytes())
+                .build(),
+            Check::account(&account2_key)
+                .data_slice(64, &1000u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account2_account.data).unwrap();
+    assert_eq!(account.amount, 1000);
+}
+
+#[test]
+fn test_approve_if_current() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mint and account
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // no delegate is set
+    assert_eq!(
+        Err(TokenError::DelegationMismatch.into()),
+        do_process_instruction(
+            approve_if_current(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                Some(&delegate_key),
+                0,
+                100,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::DelegationMismatch.into())],
+        )
+    );
+
+    // approve when no delegate is set
+    do_process_instruction(
+        approve_if_current(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            None,
+            0,
+            100,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::Some(delegate_key));
+    assert_eq!(account.delegated_amount, 100);
+
+    // stale expected amount
+    assert_eq!(
+        Err(TokenError::DelegationMismatch.into()),
+        do_process_instruction(
+            approve_if_current(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                Some(&delegate_key),
+                90,
+                50,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::DelegationMismatch.into())],
+        )
+    );
+
+    // wrong owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            approve_if_current(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &owner2_key,
+                &[],
+                Some(&delegate_key),
+                100,
+                50,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut owner2_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // change the allowance
+    do_process_instruction(
+        approve_if_current(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            Some(&delegate_key),
+            100,
+            50,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .data_slice(121, &50u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegated_amount, 50);
+}
+
+#[test]
+fn test_update_allowance() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let delegate2_key = Pubkey::new_unique();
+    let mut delegate2_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mint and account
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // no delegate to increase the allowance of
+    assert_eq!(
+        Err(TokenError::DelegationMismatch.into()),
+        do_process_instruction(
+            increase_allowance(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                100,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::DelegationMismatch.into())],
+        )
+    );
+
+    do_process_instruction(
+        approve(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // not the current delegate
+    assert_eq!(
+        Err(TokenError::DelegationMismatch.into()),
+        do_process_instruction(
+            increase_allowance(
+                &program_id,
+                &account_key,
+                &delegate2_key,
+                &owner_key,
+                &[],
+                100,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate2_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::DelegationMismatch.into())],
+        )
+    );
+
+    // increase the allowance
+    do_process_instruction(
+        increase_allowance(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            50,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .data_slice(121, &150u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+
+    // overflow
+    assert_eq!(
+        Err(TokenError::Overflow.into()),
+        do_process_instruction(
+            increase_allowance(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                u64::MAX,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::Overflow.into())],
+        )
+    );
+
+    // decrease more than the allowance
+    assert_eq!(
+        Err(TokenError::InsufficientFunds.into()),
+        do_process_instruction(
+            decrease_allowance(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                151,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::InsufficientFunds.into())],
+        )
+    );
+
+    // decrease the allowance
+    do_process_instruction(
+        decrease_allowance(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::Some(delegate_key));
+    assert_eq!(account.delegated_amount, 50);
+
+    // reaching zero revokes the delegate
+    do_process_instruction(
+        decrease_allowance(
+            &program_id,
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            50,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::None);
+    assert_eq!(account.delegated_amount, 0);
+}
+
+#[test]
+fn test_approve_with_permit() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let permit_nonce_key = Pubkey::new_unique();
+    let mut permit_nonce_account = SolanaAccount::new(
+        Rent::default().minimum_balance(PermitNonce::get_packed_len()),
+        PermitNonce::get_packed_len(),
+        &program_id,
+    );
+    let permit_nonce2_key = Pubkey::new_unique();
+    let mut permit_nonce2_account = SolanaAccount::new(
+        Rent::default().minimum_balance(PermitNonce::get_packed_len()),
+        PermitNonce::get_packed_len(),
+        &program_id,
+    );
+    let mut instructions_sysvar = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // create mint and account
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // owner does not sign
+    let mut instruction =
+        initialize_permit_nonce(&program_id, &permit_nonce_key, &owner_key).unwrap();
+    instruction.accounts[1].is_signer = false;
+    assert_eq!(
+        Err(ProgramError::MissingRequiredSignature),
+        do_process_instruction(
+            instruction,
+            vec![&mut permit_nonce_account, &mut owner_account],
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        )
+    );
+
+    // create permit nonce accounts
+    do_process_instruction(
+        initialize_permit_nonce(&program_id, &permit_nonce_key, &owner_key).unwrap(),
+        vec![&mut permit_nonce_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    let permit_nonce = PermitNonce::unpack(&permit_nonce_account.data).unwrap();
+    assert_eq!(permit_nonce.owner, owner_key);
+    assert_eq!(permit_nonce.nonce, 0);
+    do_process_instruction(
+        initialize_permit_nonce(&program_id, &permit_nonce2_key, &owner2_key).unwrap(),
+        vec![&mut permit_nonce2_account, &mut owner2_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // permit nonce account already initialized
+    assert_eq!(
+        Err(TokenError::AlreadyInUse.into()),
+        do_process_instruction(
+            initialize_permit_nonce(&program_id, &permit_nonce_key, &owner_key).unwrap(),
+            vec![&mut permit_nonce_account, &mut owner_account],
+            &[Check::err(TokenError::AlreadyInUse.into())],
+        )
+    );
+
+    // expired permit
+    assert_eq!(
+        Err(TokenError::PermitExpired.into()),
+        do_process_instruction(
+            approve_with_permit(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &permit_nonce_key,
+                100,
+                0,
+                -1,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut permit_nonce_account,
+                &mut instructions_sysvar,
+            ],
+            &[Check::err(TokenError::PermitExpired.into())],
+        )
+    );
+
+    // permit nonce account of another owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            approve_with_permit(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &permit_nonce2_key,
+                100,
+                0,
+                i64::MAX,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut permit_nonce2_account,
+                &mut instructions_sysvar,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // unexpected nonce
+    assert_eq!(
+        Err(TokenError::InvalidPermit.into()),
+        do_process_instruction(
+            approve_with_permit(
+                &program_id,
+                &account_key,
+                &delegate_key,
+                &permit_nonce_key,
+                100,
+                1,
+                i64::MAX,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegate_account,
+                &mut permit_nonce_account,
+                &mut instructions_sysvar,
+            ],
+            &[Check::err(TokenError::InvalidPermit.into())],
+        )
+    );
+}
+
+#[test]
+fn test_clawback() {
+    let program_id = spl_token_interface::id();
+    let authority_key = Pubkey::new_unique();
+    let mut authority_account = SolanaAccount::default();
+    let owner_key = Pubkey::new_unique();
+    let payer_key = Pubkey::new_unique();
+    let mut payer_account = SolanaAccount::new(1_000_000_000, 0, &system_program::id());
+    let (_, mut system_program_account) = keyed_account_for_system_program();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let (clawback_config_key, bump) = find_clawback_config_address(&mint_key, &program_id);
+    let mut clawback_config_account = SolanaAccount::default();
+    let other_config_key = Pubkey::new_unique();
+    let mut other_config_account = SolanaAccount::default();
+
+    // fail if the configuration is not at the address derived from the mint
+    assert_eq!(
+        Err(ProgramError::InvalidSeeds),
+        do_process_instruction(
+            initialize_clawback_config(
+                &program_id,
+                &other_config_key,
+                &mint_key,
+                &payer_key,
+                &authority_key,
+            )
+            .unwrap(),
+            vec![
+                &mut other_config_account,
+                &mut mint_account,
+                &mut payer_account,
+                &mut system_program_account,
+            ],
+            &[Check::err(ProgramError::InvalidSeeds)],
+        )
+    );
+
+    // create the configuration
+    do_process_instruction(
+        initialize_clawback_config(
+            &program_id,
+            &clawback_config_key,
+            &mint_key,
+            &payer_key,
+            &authority_key,
+        )
+        .unwrap(),
+        vec![
+            &mut clawback_config_account,
+            &mut mint_account,
+            &mut payer_account,
+            &mut system_program_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(clawback_config_account.owner, program_id);
+    assert_eq!(
+        ClawbackConfig::unpack(&clawback_config_account.data).unwrap(),
+        ClawbackConfig {
+            mint: mint_key,
+            authority: authority_key,
+            is_initialized: true,
+            bump,
+        }
+    );
+
+    // fail if the configuration already exists
+    assert_eq!(
+        Err(TokenError::AlreadyInUse.into()),
+        do_process_instruction(
+            initialize_clawback_config(
+                &program_id,
+                &clawback_config_key,
+                &mint_key,
+                &payer_key,
+                &authority_key,
+            )
+            .unwrap(),
+            vec![
+                &mut clawback_config_account,
+                &mut mint_account,
+                &mut payer_account,
+                &mut system_program_account,
+            ],
+            &[Check::err(TokenError::AlreadyInUse.into())],
+        )
+    );
+
+    Mint::pack(
+        Mint {
+            mint_authority: COption::Some(owner_key),
+            supply: 100,
+            is_initialized: true,
+            ..Mint::default()
+        },
+        &mut mint_account.data,
+    )
+    .unwrap();
+
+    // a copy of the configuration at another address is not accepted
+    other_config_account = clawback_config_account.clone();
+
+    let source_key = Pubkey::new_unique();
+    let mut source_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    for (amount, state, data) in [
+        (100, AccountState::Frozen, &mut source_account.data),
+        (0, AccountState::Initialized, &mut destination_account.data),
+    ] {
+        Account::pack(
+            Account {
+                mint: mint_key,
+                owner: owner_key,
+                amount,
+                state,
+                ..Account::default()
+            },
+            data,
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        Err(ProgramError::InvalidSeeds),
+        do_process_instruction(
+            clawback_transfer(
+                &program_id,
+                &source_key,
+                &destination_key,
+                &other_config_key,
+                &authority_key,
+                &[],
+                40,
+            )
+            .unwrap(),
+            vec![
+                &mut source_account,
+                &mut destination_account,
+                &mut other_config_account,
+                &mut authority_account,
+            ],
+            &[Check::err(ProgramError::InvalidSeeds)],
+        )
+    );
+
+    // fail if not signed by the clawback authority
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            clawback_transfer(
+                &program_id,
+                &source_key,
+                &destination_key,
+                &clawback_config_key,
+                &owner_key,
+                &[],
+                40,
+            )
+            .unwrap(),
+            vec![
+                &mut source_account,
+                &mut destination_account,
+                &mut clawback_config_account,
+                &mut authority_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // transfer from a frozen account
+    do_process_instruction(
+        clawback_transfer(
+            &program_id,
+            &source_key,
+            &destination_key,
+            &clawback_config_key,
+            &authority_key,
+            &[],
+            40,
+        )
+        .unwrap(),
+        vec![
+            &mut source_account,
+            &mut destination_account,
+            &mut clawback_config_account,
+            &mut authority_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(Account::unpack(&source_account.data).unwrap().amount, 60);
+    assert_eq!(
+        Account::unpack(&destination_account.data).unwrap().amount,
+        40
+    );
+
+    // tokens cannot be moved into a frozen account
+    assert_eq!(
+        Err(TokenError::AccountFrozen.into()),
+        do_process_instruction(
+            clawback_transfer(
+                &program_id,
+                &destination_key,
+                &source_key,
+                &clawback_config_key,
+                &authority_key,
+                &[],
+                40,
+            )
+            .unwrap(),
+            vec![
+                &mut destination_account,
+                &mut source_account,
+                &mut clawback_config_account,
+                &mut authority_account,
+            ],
+            &[Check::err(TokenError::AccountFrozen.into())],
+        )
+    );
+
+    assert_eq!(
+        Err(ProgramError::InvalidSeeds),
+        do_process_instruction(
+            clawback_burn(
+                &program_id,
+                &source_key,
+                &mint_key,
+                &other_config_key,
+                &authority_key,
+                &[],
+                60,
+            )
+            .unwrap(),
+            vec![
+                &mut source_account,
+                &mut mint_account,
+                &mut other_config_account,
+                &mut authority_account,
+            ],
+            &[Check::err(ProgramError::InvalidSeeds)],
+        )
+    );
+
+    // burn from a frozen account
+    do_process_instruction(
+        clawback_burn(
+            &program_id,
+            &source_key,
+            &mint_key,
+            &clawback_config_key,
+            &authority_key,
+            &[],
+            60,
+        )
+        .unwrap(),
+        vec![
+            &mut source_account,
+            &mut mint_account,
+            &mut clawback_config_account,
+            &mut authority_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(Account::unpack(&source_account.data).unwrap().amount, 0);
+    assert_eq!(Mint::unpack(&mint_account.data).unwrap().supply, 40);
+}